@@ -31,12 +31,17 @@ pub async fn add_metadata_to_selected_components(
             .component_final_linked_wasm(component_name, ctx.profile());
 
         let root_package_name = ctx.wit.root_package_name(component_name)?;
+        let strip_sections = &ctx
+            .application
+            .component_properties(component_name, ctx.profile())
+            .strip_sections;
 
         let task_result_marker = TaskResultMarker::new(
             &ctx.application.task_result_marker_dir(),
             AddMetadataMarkerHash {
                 component_name,
                 root_package_name: root_package_name.clone(),
+                strip_sections,
             },
         )?;
 
@@ -16,6 +16,7 @@ use crate::app::build::add_metadata::add_metadata_to_selected_components;
 use crate::app::build::componentize::componentize;
 use crate::app::build::gen_rpc::gen_rpc;
 use crate::app::build::link::link;
+use crate::app::build::optimize::optimize;
 use crate::app::context::ApplicationContext;
 use crate::fs;
 use crate::log::{log_warn_action, LogColorize};
@@ -36,6 +37,7 @@ pub mod componentize;
 pub mod external_command;
 pub mod gen_rpc;
 pub mod link;
+pub mod optimize;
 pub mod task_result_marker;
 
 pub async fn build_app(ctx: &mut ApplicationContext) -> anyhow::Result<()> {
@@ -51,6 +53,9 @@ pub async fn build_app(ctx: &mut ApplicationContext) -> anyhow::Result<()> {
     if ctx.config.should_run_step(AppBuildStep::AddMetadata) {
         add_metadata_to_selected_components(ctx).await?;
     }
+    if ctx.config.should_run_step(AppBuildStep::Optimize) {
+        optimize(ctx)?;
+    }
 
     Ok(())
 }
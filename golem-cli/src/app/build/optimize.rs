@@ -0,0 +1,374 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::app::context::ApplicationContext;
+use crate::fs;
+use crate::log::{log_action, log_warn_action, logln, LogColorize, LogIndent};
+use crate::model::app::AppComponentName;
+use crate::model::app_raw::StripSection;
+use crate::model::text::fmt::format_binary_size;
+use anyhow::{anyhow, bail, Context};
+use itertools::Itertools;
+use std::collections::BTreeMap;
+use wasm_encoder::{ComponentSectionId, Encode, RawSection, Section};
+use wasmparser::{Encoding, Parser, Payload};
+
+struct ComponentSize {
+    component_name: AppComponentName,
+    size: u64,
+    previous_size: Option<u64>,
+    max_size: Option<u64>,
+}
+
+impl ComponentSize {
+    fn is_over_budget(&self) -> bool {
+        self.max_size.is_some_and(|max_size| self.size > max_size)
+    }
+}
+
+pub fn optimize(ctx: &ApplicationContext) -> anyhow::Result<()> {
+    // Only components with stripped sections or a size budget are optimized
+    let component_names = ctx
+        .selected_component_names()
+        .iter()
+        .filter(|component_name| {
+            let properties = ctx
+                .application
+                .component_properties(component_name, ctx.profile());
+            !properties.strip_sections.is_empty() || properties.max_size.is_some()
+        })
+        .collect::<Vec<_>>();
+    if component_names.is_empty() {
+        return Ok(());
+    }
+
+    log_action("Optimizing", "components");
+    let _indent = LogIndent::new();
+
+    // Sizes of the previous build are kept for showing the size changes
+    let sizes_file = ctx.application.component_sizes_file();
+    let previous_sizes = if sizes_file.exists() {
+        serde_json::from_str::<BTreeMap<String, u64>>(&fs::read_to_string(&sizes_file)?)
+            .unwrap_or_default()
+    } else {
+        BTreeMap::new()
+    };
+
+    let mut sizes = previous_sizes.clone();
+    let mut report = Vec::new();
+
+    for component_name in component_names {
+        let properties = ctx
+            .application
+            .component_properties(component_name, ctx.profile());
+        let final_linked_wasm = ctx
+            .application
+            .component_final_linked_wasm(component_name, ctx.profile());
+
+        if !final_linked_wasm.exists() {
+            log_warn_action(
+                "Skipping",
+                format!(
+                    "optimizing {}, missing final linked WASM",
+                    component_name.as_str().log_color_highlight()
+                ),
+            );
+            continue;
+        }
+
+        let mut wasm = fs::read(&final_linked_wasm)?;
+
+        if !properties.strip_sections.is_empty() {
+            let stripped_wasm = strip_custom_sections(&wasm, &properties.strip_sections)
+                .with_context(|| {
+                    anyhow!(
+                        "Failed to strip custom sections from {}",
+                        final_linked_wasm.log_color_highlight()
+                    )
+                })?;
+
+            // Already stripped components are not written again, to keep them up-to-date
+            if stripped_wasm.len() != wasm.len() {
+                log_action(
+                    "Stripping",
+                    format!(
+                        "{} sections from {}, removed {}",
+                        properties
+                            .strip_sections
+                            .iter()
+                            .map(|section| format!("{:?}", section).to_lowercase())
+                            .join(", ")
+                            .log_color_highlight(),
+                        component_name.as_str().log_color_highlight(),
+                        format_binary_size(&((wasm.len() - stripped_wasm.len()) as u64))
+                            .log_color_highlight()
+                    ),
+                );
+                fs::write(&final_linked_wasm, &stripped_wasm)?;
+                wasm = stripped_wasm;
+            }
+        }
+
+        let size = wasm.len() as u64;
+        sizes.insert(component_name.to_string(), size);
+        report.push(ComponentSize {
+            component_name: component_name.clone(),
+            size,
+            previous_size: previous_sizes.get(component_name.as_str()).copied(),
+            max_size: properties.max_size,
+        });
+    }
+
+    fs::write_str(&sizes_file, serde_json::to_string_pretty(&sizes)?)?;
+
+    log_size_report(&report);
+
+    let over_budget = report
+        .iter()
+        .filter(|size| size.is_over_budget())
+        .map(|size| size.component_name.as_str())
+        .collect::<Vec<_>>();
+    if !over_budget.is_empty() {
+        bail!(
+            "Components exceeding their size budget (maxSize): {}",
+            over_budget.join(", ")
+        );
+    }
+
+    Ok(())
+}
+
+fn log_size_report(report: &[ComponentSize]) {
+    if report.is_empty() {
+        return;
+    }
+
+    logln("Component sizes:");
+    let _indent = LogIndent::new();
+    for component in report {
+        let size = if component.is_over_budget() {
+            format_binary_size(&component.size).log_color_error_highlight()
+        } else {
+            format_binary_size(&component.size).log_color_highlight()
+        };
+
+        let change = match component.previous_size {
+            Some(previous_size) if previous_size < component.size => {
+                format!(
+                    " (+{})",
+                    format_binary_size(&(component.size - previous_size))
+                )
+            }
+            Some(previous_size) if previous_size > component.size => {
+                format!(
+                    " (-{})",
+                    format_binary_size(&(previous_size - component.size))
+                )
+            }
+            _ => "".to_string(),
+        };
+
+        let budget = match component.max_size {
+            Some(max_size) => format!(", budget: {}", format_binary_size(&max_size)),
+            None => "".to_string(),
+        };
+
+        logln(format!(
+            "{}: {}{}{}",
+            component.component_name.as_str().log_color_highlight(),
+            size,
+            change,
+            budget
+        ));
+    }
+}
+
+/// Removes the selected custom sections from the component, including its nested modules and components
+pub fn strip_custom_sections(wasm: &[u8], sections: &[StripSection]) -> anyhow::Result<Vec<u8>> {
+    // Nested modules and components are rewritten on their own, then encoded into their parents
+    let mut stack = Vec::new();
+    let mut output = Vec::new();
+
+    for payload in Parser::new(0).parse_all(wasm) {
+        let payload = payload?;
+
+        match &payload {
+            Payload::Version { encoding, .. } => {
+                output.extend_from_slice(match encoding {
+                    Encoding::Component => &wasm_encoder::Component::HEADER,
+                    Encoding::Module => &wasm_encoder::Module::HEADER,
+                });
+                continue;
+            }
+            Payload::ModuleSection { .. } | Payload::ComponentSection { .. } => {
+                stack.push(std::mem::take(&mut output));
+                continue;
+            }
+            Payload::End(_) => {
+                let Some(mut parent) = stack.pop() else {
+                    break;
+                };
+                if output.starts_with(&wasm_encoder::Component::HEADER) {
+                    parent.push(ComponentSectionId::Component as u8);
+                } else {
+                    parent.push(ComponentSectionId::CoreModule as u8);
+                }
+                output.encode(&mut parent);
+                output = parent;
+                continue;
+            }
+            Payload::CustomSection(reader)
+                if is_stripped(reader.name(), !stack.is_empty(), sections) =>
+            {
+                continue;
+            }
+            _ => {}
+        }
+
+        if let Some((id, range)) = payload.as_section() {
+            RawSection {
+                id,
+                data: &wasm[range],
+            }
+            .append_to(&mut output);
+        }
+    }
+
+    Ok(output)
+}
+
+fn is_stripped(section_name: &str, nested: bool, sections: &[StripSection]) -> bool {
+    sections.iter().any(|section| match section {
+        StripSection::Debug => {
+            section_name.starts_with(".debug_")
+                || section_name == "sourceMappingURL"
+                || section_name == "external_debug_info"
+        }
+        StripSection::Producers => section_name == "producers",
+        // The name of the root component is used as component metadata, so it is kept
+        StripSection::Names => {
+            section_name == "name" || (nested && section_name == "component-name")
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::app::build::optimize::strip_custom_sections;
+    use crate::model::app_raw::StripSection;
+    use assert2::check;
+    use std::borrow::Cow;
+    use wasm_encoder::{Component, CustomSection, Module, ModuleSection, NestedComponentSection};
+    use wasmparser::{Parser, Payload, Validator, WasmFeatures};
+
+    fn custom_section(name: &str) -> CustomSection<'_> {
+        CustomSection {
+            name: Cow::Borrowed(name),
+            data: Cow::Borrowed(b"data"),
+        }
+    }
+
+    fn test_component() -> Vec<u8> {
+        let mut module = Module::new();
+        module.section(&custom_section("name"));
+        module.section(&custom_section("producers"));
+        module.section(&custom_section(".debug_info"));
+
+        let mut nested_component = Component::new();
+        nested_component.section(&ModuleSection(&module));
+        nested_component.section(&custom_section("component-name"));
+
+        let mut component = Component::new();
+        component.section(&ModuleSection(&module));
+        component.section(&NestedComponentSection(&nested_component));
+        component.section(&custom_section("component-name"));
+        component.section(&custom_section("producers"));
+        component.section(&custom_section("sourceMappingURL"));
+        component.finish()
+    }
+
+    // Custom section names with the nesting depth of their module or component
+    fn custom_sections(wasm: &[u8]) -> Vec<(usize, String)> {
+        let mut depth: usize = 0;
+        let mut sections = Vec::new();
+        for payload in Parser::new(0).parse_all(wasm) {
+            match payload.unwrap() {
+                Payload::ModuleSection { .. } | Payload::ComponentSection { .. } => depth += 1,
+                Payload::End(_) => depth = depth.saturating_sub(1),
+                Payload::CustomSection(reader) => {
+                    sections.push((depth, reader.name().to_string()));
+                }
+                _ => {}
+            }
+        }
+        sections
+    }
+
+    fn validate(wasm: &[u8]) {
+        Validator::new_with_features(WasmFeatures::all())
+            .validate_all(wasm)
+            .unwrap();
+    }
+
+    #[test]
+    fn strip_nothing() {
+        let wasm = test_component();
+        validate(&wasm);
+
+        let stripped = strip_custom_sections(&wasm, &[]).unwrap();
+        check!(stripped == wasm);
+    }
+
+    #[test]
+    fn strip_debug_and_producers() {
+        let wasm = test_component();
+
+        let stripped =
+            strip_custom_sections(&wasm, &[StripSection::Debug, StripSection::Producers]).unwrap();
+        validate(&stripped);
+
+        check!(
+            custom_sections(&stripped)
+                == vec![
+                    (1, "name".to_string()),
+                    (2, "name".to_string()),
+                    (1, "component-name".to_string()),
+                    (0, "component-name".to_string()),
+                ]
+        );
+    }
+
+    #[test]
+    fn strip_names_keeps_root_component_name() {
+        let wasm = test_component();
+
+        let stripped = strip_custom_sections(&wasm, &[StripSection::Names]).unwrap();
+        validate(&stripped);
+
+        check!(
+            custom_sections(&stripped)
+                == vec![
+                    (1, "producers".to_string()),
+                    (1, ".debug_info".to_string()),
+                    (2, "producers".to_string()),
+                    (2, ".debug_info".to_string()),
+                    (0, "component-name".to_string()),
+                    (0, "producers".to_string()),
+                    (0, "sourceMappingURL".to_string()),
+                ]
+        );
+    }
+}
@@ -85,6 +85,9 @@ impl TaskResultMarkerHashInput for LinkRpcMarkerHash<'_> {
 pub struct AddMetadataMarkerHash<'a> {
     pub component_name: &'a AppComponentName,
     pub root_package_name: PackageName,
+    // The optimize step strips the final linked WASM in place, so changing the stripped
+    // sections requires adding the metadata again
+    pub strip_sections: &'a [app_raw::StripSection],
 }
 
 impl TaskResultMarkerHashInput for AddMetadataMarkerHash<'_> {
@@ -93,7 +96,16 @@ impl TaskResultMarkerHashInput for AddMetadataMarkerHash<'_> {
     }
 
     fn hash_input(&self) -> anyhow::Result<Vec<u8>> {
-        Ok(format!("{}#{}", self.component_name, self.root_package_name).into_bytes())
+        Ok(format!(
+            "{}#{}#{}",
+            self.component_name,
+            self.root_package_name,
+            self.strip_sections
+                .iter()
+                .map(|section| format!("{:?}", section))
+                .join(",")
+        )
+        .into_bytes())
     }
 }
 
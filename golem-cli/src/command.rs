@@ -21,6 +21,7 @@ use crate::command::profile::ProfileSubcommand;
 use crate::command::worker::WorkerSubcommand;
 use crate::config::{BuildProfileName, ProfileName};
 use crate::log::LogColorize;
+use crate::model::oplog_diff::OplogIndexRange;
 use crate::model::{Format, WorkerName};
 use crate::{command_name, version};
 use anyhow::{anyhow, bail, Context as AnyhowContext};
@@ -34,6 +35,7 @@ use lenient_bool::LenientBool;
 use std::collections::{BTreeSet, HashMap};
 use std::ffi::OsString;
 use std::path::PathBuf;
+use std::time::Duration;
 use uuid::Uuid;
 
 #[cfg(feature = "server-commands")]
@@ -537,14 +539,26 @@ pub enum GolemCliSubcommand {
         /// Selects shell
         shell: clap_complete::Shell,
     },
+    /// Print dynamic completion candidates, used by the generated shell completion scripts
+    #[command(name = "__complete", hide = true)]
+    Complete {
+        /// Command line words after the command name, the last one is the word being completed
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        words: Vec<String>,
+    },
 }
 
 pub mod shared_args {
     use crate::cloud::AccountId;
     use crate::model::app::AppBuildStep;
-    use crate::model::{ComponentName, ProjectName, WorkerName, WorkerUpdateMode};
+    use crate::model::{
+        ComponentName, ProjectName, StreamLogLevel, StreamOutputFileFormat, WorkerName,
+        WorkerUpdateMode,
+    };
     use clap::Args;
     use golem_templates::model::GuestLanguage;
+    use regex::Regex;
+    use std::path::PathBuf;
 
     pub type ComponentTemplateName = String;
     pub type NewWorkerArgument = String;
@@ -646,6 +660,52 @@ pub mod shared_args {
         /// Hide timestamp in stream output
         #[clap(long, short = 'T')]
         pub stream_no_timestamp: bool,
+        /// Minimum level of stream entries to show, standard output counts as info, standard error as error
+        #[clap(long, value_enum)]
+        pub stream_min_level: Option<StreamLogLevel>,
+        /// Only show log entries whose context matches the given regular expression
+        #[clap(long)]
+        pub stream_context: Option<Regex>,
+        /// Only show stream entries whose message matches the given regular expression
+        #[clap(long)]
+        pub stream_grep: Option<Regex>,
+        /// Also write the stream output to the given file
+        #[clap(long)]
+        pub stream_output_file: Option<PathBuf>,
+        /// Format of the stream output file
+        #[clap(
+            long,
+            value_enum,
+            default_value_t = StreamOutputFileFormat::Text,
+            requires = "stream_output_file"
+        )]
+        pub stream_output_format: StreamOutputFileFormat,
+        /// Rotate the stream output file when it reaches the given size in bytes, defaults to 10 MiB
+        #[clap(long, default_value_t = 10 * 1024 * 1024, requires = "stream_output_file")]
+        pub stream_output_max_size: u64,
+        /// Number of rotated stream output files to keep, defaults to 5
+        #[clap(long, default_value_t = 5, requires = "stream_output_file")]
+        pub stream_output_max_files: usize,
+        /// Interval of keep-alive pings in milliseconds, overrides the profile setting
+        #[clap(long)]
+        pub stream_ping_interval_ms: Option<u64>,
+        /// Delay before the first reconnect attempt in milliseconds, overrides the profile setting
+        #[clap(long)]
+        pub stream_reconnect_initial_delay_ms: Option<u64>,
+        /// Upper limit of the exponential reconnect delay in milliseconds, overrides the profile setting
+        #[clap(long)]
+        pub stream_reconnect_max_delay_ms: Option<u64>,
+        /// Give up after this many consecutive failed reconnect attempts, 0 means unlimited,
+        /// overrides the profile setting
+        #[clap(long)]
+        pub stream_reconnect_max_attempts: Option<u32>,
+        /// Disable random jitter of the reconnect delay
+        #[clap(long)]
+        pub stream_no_reconnect_jitter: bool,
+        /// Reconnect when no message arrives within the given milliseconds, 0 disables the timeout,
+        /// overrides the profile setting
+        #[clap(long)]
+        pub stream_idle_timeout_ms: Option<u64>,
     }
 
     #[derive(Debug, Args, Default)]
@@ -657,6 +717,11 @@ pub mod shared_args {
         /// Delete and recreate existing workers
         #[clap(long, short, conflicts_with_all = ["update_workers"])]
         pub redeploy_workers: bool,
+        /// Check the export compatibility of the workers' current component versions before
+        /// updating, and skip the updates which would break. Manual updates are also skipped
+        /// when the versions do not export the snapshot interfaces.
+        #[clap(long, requires = "update_workers")]
+        pub check_compatibility: bool,
     }
 
     #[derive(Debug, Args)]
@@ -743,6 +808,11 @@ pub mod app {
             /// Update mode - auto or manual, defaults to "auto"
             #[arg(long, short, default_value = "auto")]
             update_mode: WorkerUpdateMode,
+            /// Check the export compatibility of the workers' current component versions before
+            /// updating, and skip the updates which would break. Manual updates are also skipped
+            /// when the versions do not export the snapshot interfaces.
+            #[arg(long)]
+            check_compatibility: bool,
         },
         /// Redeploy all workers of the application using the latest version
         RedeployWorkers {
@@ -769,6 +839,7 @@ pub mod component {
     use crate::model::WorkerUpdateMode;
     use clap::Subcommand;
     use golem_templates::model::PackageName;
+    use std::path::PathBuf;
 
     #[derive(Debug, Subcommand)]
     pub enum ComponentSubcommand {
@@ -817,6 +888,64 @@ pub mod component {
             /// Optional component version to get
             version: Option<u64>,
         },
+        /// Download the WASM binary of a deployed component
+        ///
+        /// The download is only checked against the size reported by the server, verifying it
+        /// against a server-side content hash is not supported, as the server does not report one.
+        Download {
+            #[command(flatten)]
+            component_name: ComponentOptionalComponentName,
+            /// Component version to download, defaults to the latest version
+            #[arg(long)]
+            version: Option<u64>,
+            /// Output file, defaults to <COMPONENT_NAME>-<VERSION>.wasm in the current directory
+            #[arg(long, short)]
+            output: Option<PathBuf>,
+            /// Also save the manifest of the component's initial files (paths, permissions and keys)
+            /// next to the output file, with a .files.json extension. The contents of the files cannot
+            /// be downloaded.
+            #[arg(long)]
+            files_manifest: bool,
+            /// Also download the initial files (IFS archive) of the component. Not supported yet,
+            /// as the server has no endpoint for downloading them, the command fails when it is set.
+            #[arg(long)]
+            with_files: bool,
+        },
+        /// Roll back a component to a previous version, by publishing the selected version's WASM
+        /// as a new latest version, then optionally updating or redeploying its workers
+        ///
+        /// Rolling back to a version with different initial files than the latest version is not
+        /// supported, as the server cannot provide the content of the files for publishing them
+        /// again. Such versions can only be used with --retarget.
+        Rollback {
+            #[command(flatten)]
+            component_name: ComponentOptionalComponentName,
+            /// Component version to roll back to
+            #[arg(long)]
+            to: u64,
+            /// Do not publish a new version, only update the existing workers to the selected version
+            #[arg(long, requires = "update_workers")]
+            retarget: bool,
+            #[command(flatten)]
+            update_or_redeploy: WorkerUpdateOrRedeployArgs,
+        },
+        /// Compare the exported functions of two component versions, by default the latest deployed
+        /// version with the locally built one, exits with an error if breaking changes are found
+        Diff {
+            #[command(flatten)]
+            component_name: ComponentOptionalComponentName,
+            /// Base component version, defaults to the latest deployed version
+            version: Option<u64>,
+            /// Deployed component version to compare with, defaults to the locally built component
+            other_version: Option<u64>,
+        },
+        /// Analyse a local component WASM without deploying it, showing its exports, imports,
+        /// metadata and section sizes
+        Inspect {
+            /// Path of a WASM file, or name of an application component to inspect the final
+            /// linked WASM of
+            component: String,
+        },
         /// Try to automatically update all existing workers of the selected component to the latest version
         UpdateWorkers {
             #[command(flatten)]
@@ -824,6 +953,11 @@ pub mod component {
             /// Update mode - auto or manual, defaults to "auto"
             #[arg(long, short, default_value_t = WorkerUpdateMode::Automatic)]
             update_mode: WorkerUpdateMode,
+            /// Check the export compatibility of the workers' current component versions before
+            /// updating, and skip the updates which would break. Manual updates are also skipped
+            /// when the versions do not export the snapshot interfaces.
+            #[arg(long)]
+            check_compatibility: bool,
         },
         /// Redeploy all workers of the selected component using the latest version
         RedeployWorkers {
@@ -909,24 +1043,74 @@ pub mod worker {
         ComponentOptionalComponentName, NewWorkerArgument, StreamArgs, WorkerFunctionArgument,
         WorkerFunctionName, WorkerNameArg,
     };
-    use crate::model::{IdempotencyKey, WorkerUpdateMode};
+    use crate::command::worker::files::WorkerFilesSubcommand;
+    use crate::command::worker::scheduled::WorkerScheduledSubcommand;
+    use crate::command::{parse_duration, parse_instant, parse_oplog_index_range};
+    use crate::model::oplog_diff::OplogIndexRange;
+    use crate::model::{
+        ComponentName, IdempotencyKey, InvokeRequestFormat, InvokeResultFormat, WorkerName,
+        WorkerUpdateMode,
+    };
+    use chrono::{DateTime, Utc};
     use clap::Subcommand;
     use golem_client::model::ScanCursor;
+    use std::path::PathBuf;
+    use std::time::Duration;
 
     #[derive(Debug, Subcommand)]
     pub enum WorkerSubcommand {
-        /// Create new worker
+        /// Create new worker, or create multiple workers of a component using --from-csv or --name-pattern
+        ///
+        /// When creating multiple workers, already existing workers are skipped.
         New {
-            #[command(flatten)]
-            worker_name: WorkerNameArg,
+            // DO NOT ADD EMPTY LINES TO THE DOC COMMENT
+            /// Worker name, accepted formats:
+            ///   - <WORKER>
+            ///   - <COMPONENT>/<WORKER>
+            ///   - <PROJECT>/<COMPONENT>/<WORKER>
+            ///   - <ACCOUNT>/<PROJECT>/<COMPONENT>/<WORKER>
+            #[arg(
+                verbatim_doc_comment,
+                required_unless_present_any = ["from_csv", "name_pattern"],
+                conflicts_with_all = ["from_csv", "name_pattern"]
+            )]
+            worker_name: Option<WorkerName>,
             /// Worker arguments
+            #[arg(conflicts_with_all = ["from_csv", "name_pattern"])]
             arguments: Vec<NewWorkerArgument>,
             /// Worker environment variables
             #[arg(short, long, value_parser = parse_key_val, value_name = "ENV=VAL")]
             env: Vec<(String, String)>,
+            // DO NOT ADD EMPTY LINES TO THE DOC COMMENT
+            /// Component of the workers created using --from-csv or --name-pattern, if not specified
+            /// the component is selected based on the current directory. Accepted formats:
+            ///   - <COMPONENT>
+            ///   - <PROJECT>/<COMPONENT>
+            ///   - <ACCOUNT>/<PROJECT>/<COMPONENT>
+            #[arg(long, verbatim_doc_comment, requires = "fan_out")]
+            component: Option<ComponentName>,
+            /// Create the workers listed in a CSV file with name, args and env columns,
+            /// args are separated by spaces, env variables by semicolons (e.g. "A=1;B=2")
+            #[arg(long, value_name = "PATH", group = "fan_out")]
+            from_csv: Option<PathBuf>,
+            /// Create workers named by a pattern, "{n}" is replaced with the index of the worker
+            #[arg(long, requires = "count", group = "fan_out")]
+            name_pattern: Option<String>,
+            /// Number of workers to create using --name-pattern
+            #[arg(long, requires = "name_pattern", value_parser = clap::value_parser!(u64).range(1..))]
+            count: Option<u64>,
+            /// Worker argument of the workers created using --name-pattern, can be repeated
+            #[arg(long = "arg", value_name = "ARG", requires = "name_pattern")]
+            pattern_arguments: Vec<NewWorkerArgument>,
+            /// Maximum number of workers created concurrently using --from-csv or --name-pattern,
+            /// defaults to 10
+            #[arg(long, requires = "fan_out", value_parser = clap::value_parser!(u64).range(1..))]
+            concurrency: Option<u64>,
         },
         // TODO: json args
         /// Invoke (or enqueue invocation for) worker
+        ///
+        /// Exits with code 3 if the invoked function returns a result::err value.
         Invoke {
             #[command(flatten)]
             worker_name: WorkerNameArg,
@@ -946,12 +1130,72 @@ pub mod worker {
             stream: bool,
             #[command(flatten)]
             stream_args: StreamArgs,
+            /// Schedule the invocation to be enqueued at the given time (RFC 3339, e.g. 2025-01-01T12:00:00Z),
+            /// the invocation is scheduled locally, not on the server: the command keeps running
+            /// until then and enqueues it, use `worker scheduled` to list or cancel it, or to
+            /// enqueue it with `worker scheduled run` if the command was stopped
+            #[arg(long, value_parser = parse_instant, conflicts_with_all = ["after", "stream"])]
+            at: Option<DateTime<Utc>>,
+            /// Schedule the invocation to be enqueued after the given delay (e.g. 30s, 5m or 1h30m),
+            /// the invocation is scheduled locally, not on the server: the command keeps running
+            /// until then and enqueues it, use `worker scheduled` to list or cancel it, or to
+            /// enqueue it with `worker scheduled run` if the command was stopped
+            #[arg(long, value_parser = parse_duration, conflicts_with_all = ["at", "stream"])]
+            after: Option<Duration>,
+            /// Print only the invocation result to the standard output in the given format,
+            /// while logs are written to the standard error
+            #[arg(long, conflicts_with_all = ["enqueue", "at", "after"])]
+            result_format: Option<InvokeResultFormat>,
+            /// Do not invoke, but print the HTTP request of the worker service which would perform
+            /// the same invocation, including the authorization header of the profile. The component
+            /// is not built or deployed, it has to be deployed already.
+            #[arg(long, conflicts_with_all = ["stream", "at", "after", "result_format"])]
+            print_request: Option<InvokeRequestFormat>,
+            /// Also show the server side metrics of the invocation (execution time, consumed fuel,
+            /// host calls), which are read from the worker's oplog using additional requests.
+            /// By default only the client side wall time is shown.
+            #[arg(long, conflicts_with_all = ["enqueue", "at", "after", "result_format", "print_request"])]
+            metrics: bool,
+        },
+        /// Load test a worker function by invoking it concurrently on multiple workers
+        Bench {
+            /// Component name of the benchmarked workers
+            component_name: ComponentName,
+            /// Worker function name to invoke
+            function_name: WorkerFunctionName,
+            /// Worker function arguments in WAVE format
+            arguments: Vec<WorkerFunctionArgument>,
+            /// Number of workers to spread the invocations over, defaults to 1
+            #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u64).range(1..))]
+            workers: u64,
+            /// Worker name pattern, "{n}" is replaced with the index of the worker
+            #[arg(long, default_value = "bench-{n}")]
+            worker_name_pattern: String,
+            /// Target invocation rate (invocations per second), if not set, invocations are
+            /// started as soon as the concurrency limit allows
+            #[arg(long, value_parser = parse_rate)]
+            rate: Option<f64>,
+            /// Maximum number of concurrent invocations, defaults to 10
+            #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+            concurrency: u64,
+            /// Duration of the benchmark in seconds, defaults to 10
+            #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+            duration: u64,
         },
         /// Get worker metadata
         Get {
             #[command(flatten)]
             worker_name: WorkerNameArg,
         },
+        /// Describe a worker in one report: metadata, recent invocations, updates, owned resources
+        /// and component version
+        Describe {
+            #[command(flatten)]
+            worker_name: WorkerNameArg,
+            /// Number of recent invocations to show, defaults to 10
+            #[arg(long, default_value_t = 10)]
+            invocations: usize,
+        },
         /// Deletes a worker
         Delete {
             #[command(flatten)]
@@ -981,11 +1225,33 @@ pub mod worker {
             /// When set to true it queries for most up-to-date status for each worker, default is false
             #[arg(long, default_value_t = false)]
             precise: bool,
+            /// Keep refreshing the worker list until interrupted, highlighting status and version changes
+            #[arg(long, conflicts_with = "scan_cursor")]
+            watch: bool,
+            /// Refresh interval in seconds for --watch, defaults to 2
+            #[arg(long, default_value_t = 2, requires = "watch")]
+            interval: u64,
         },
-        /// Connect to a worker and live stream its standard output, error and log channels
+        /// Connect to one or more workers and live stream their standard output, error and log channels
         Stream {
-            #[command(flatten)]
-            worker_name: WorkerNameArg,
+            // DO NOT ADD EMPTY LINES TO THE DOC COMMENT
+            /// Worker names, multiple workers can be streamed at the same time, accepted formats:
+            ///   - <WORKER>
+            ///   - <COMPONENT>/<WORKER>
+            ///   - <PROJECT>/<COMPONENT>/<WORKER>
+            ///   - <ACCOUNT>/<PROJECT>/<COMPONENT>/<WORKER>
+            #[arg(
+                verbatim_doc_comment,
+                required_unless_present = "component",
+                conflicts_with = "component"
+            )]
+            worker_names: Vec<WorkerName>,
+            /// Stream all workers of the given component instead of explicitly named workers
+            #[arg(long)]
+            component: Option<ComponentName>,
+            /// Filter for selecting the workers of --component, uses the same syntax as `worker list`
+            #[arg(long, requires = "component")]
+            filter: Vec<String>,
             #[command(flatten)]
             stream_args: StreamArgs,
         },
@@ -1026,6 +1292,27 @@ pub mod worker {
             #[arg(long, conflicts_with = "from")]
             query: Option<String>,
         },
+        /// Compare the oplogs of two workers, or two oplog index ranges of the same worker
+        ///
+        /// Entries are aligned by their kind and invoked function, then their arguments,
+        /// responses and errors are compared.
+        OplogDiff {
+            #[command(flatten)]
+            worker_name: WorkerNameArg,
+            /// Worker to compare with, uses the same accepted formats as the first worker name,
+            /// if not specified, --range and --other-range of the first worker are compared
+            other_worker_name: Option<WorkerName>,
+            /// Oplog index range of the first worker to compare, e.g. 10..20, 10.. or ..20
+            #[arg(long, value_parser = parse_oplog_index_range)]
+            range: Option<OplogIndexRange>,
+            /// Oplog index range of the other worker to compare, defaults to --range when
+            /// comparing two workers
+            #[arg(long, value_parser = parse_oplog_index_range)]
+            other_range: Option<OplogIndexRange>,
+            /// Show all the aligned entries, not only the differences and their context
+            #[arg(long)]
+            full: bool,
+        },
         /// Reverts a worker by undoing its last recorded operations
         Revert {
             #[command(flatten)]
@@ -1036,6 +1323,9 @@ pub mod worker {
             /// Revert by number of invocations
             #[arg(long, conflicts_with = "last_oplog_index")]
             number_of_invocations: Option<u64>,
+            /// Only show the invocations and oplog entries that would be undone, without reverting
+            #[arg(long)]
+            preview: bool,
         },
         /// Cancels an enqueued invocation if it has not started yet
         CancelInvocation {
@@ -1044,6 +1334,90 @@ pub mod worker {
             /// Idempotency key of the invocation to be cancelled
             idempotency_key: IdempotencyKey,
         },
+        /// Inspect the file system of a worker
+        Files {
+            #[command(subcommand)]
+            subcommand: WorkerFilesSubcommand,
+        },
+        /// Browse the local invocation journal of the current profile
+        ///
+        /// Invocations are only recorded when the journal is enabled for the profile
+        /// with `profile config <PROFILE> set-invocation-journal true`.
+        History {
+            /// Only show invocations whose worker or function name contains the given text
+            filter: Option<String>,
+            /// Show the details of a single journal entry, including arguments and result
+            #[arg(long, conflicts_with = "filter")]
+            entry: Option<u64>,
+            /// Maximum number of entries to show, defaults to 20
+            #[arg(long, default_value_t = 20)]
+            limit: usize,
+        },
+        /// Re-send an invocation from the local invocation journal with the same idempotency key,
+        /// so the invocation is executed at most once even if the original one reached the worker
+        Retry {
+            /// Id of the journal entry, see `worker history`
+            entry: u64,
+        },
+        /// List or cancel invocations scheduled locally with `worker invoke --at` or `--after`
+        Scheduled {
+            #[command(subcommand)]
+            subcommand: WorkerScheduledSubcommand,
+        },
+    }
+
+    pub mod scheduled {
+        use crate::model::IdempotencyKey;
+        use clap::Subcommand;
+
+        #[derive(Debug, Subcommand)]
+        pub enum WorkerScheduledSubcommand {
+            /// List pending scheduled invocations of the current profile
+            List,
+            /// Enqueue the pending scheduled invocations of the current profile whose scheduled time
+            /// already passed, e.g. because the command waiting for them was stopped
+            Run,
+            /// Cancel a pending scheduled invocation
+            Cancel {
+                /// Idempotency key of the scheduled invocation
+                idempotency_key: IdempotencyKey,
+            },
+        }
+    }
+
+    pub mod files {
+        use crate::command::shared_args::WorkerNameArg;
+        use clap::Subcommand;
+        use std::path::PathBuf;
+
+        #[derive(Debug, Subcommand)]
+        pub enum WorkerFilesSubcommand {
+            /// List files and directories of a worker directory, showing permissions and sizes
+            Ls {
+                #[command(flatten)]
+                worker_name: WorkerNameArg,
+                /// Directory path in the worker's file system, defaults to "/"
+                #[arg(default_value = "/")]
+                path: String,
+            },
+            /// Print the content of a worker file to the standard output
+            Cat {
+                #[command(flatten)]
+                worker_name: WorkerNameArg,
+                /// File path in the worker's file system
+                path: String,
+            },
+            /// Download a file or a whole directory from the worker's file system
+            Download {
+                #[command(flatten)]
+                worker_name: WorkerNameArg,
+                /// File or directory path in the worker's file system
+                path: String,
+                /// Output directory, defaults to the current directory
+                #[arg(long, short, default_value = ".")]
+                output: PathBuf,
+            },
+        }
     }
 }
 
@@ -1433,6 +1807,33 @@ pub mod profile {
                 /// CLI output format
                 format: Format,
             },
+            /// Set worker stream connection settings for the requested profile, only the provided values are changed
+            SetStream {
+                /// Interval of keep-alive pings in milliseconds
+                #[arg(long)]
+                ping_interval_ms: Option<u64>,
+                /// Delay before the first reconnect attempt in milliseconds
+                #[arg(long)]
+                reconnect_initial_delay_ms: Option<u64>,
+                /// Upper limit of the exponential reconnect delay in milliseconds
+                #[arg(long)]
+                reconnect_max_delay_ms: Option<u64>,
+                /// Maximum number of consecutive failed reconnect attempts, 0 means unlimited
+                #[arg(long)]
+                reconnect_max_attempts: Option<u32>,
+                /// Enable or disable random jitter of the reconnect delay
+                #[arg(long)]
+                reconnect_jitter: Option<bool>,
+                /// Reconnect when no message arrives within the given milliseconds, 0 disables the timeout
+                #[arg(long)]
+                idle_timeout_ms: Option<u64>,
+            },
+            /// Enable or disable recording invocations in the local invocation journal for the requested profile
+            SetInvocationJournal {
+                /// Whether invocations should be recorded
+                #[arg(action = clap::ArgAction::Set)]
+                enabled: bool,
+            },
         }
     }
 }
@@ -1735,6 +2136,95 @@ pub fn builtin_app_subcommands() -> BTreeSet<String> {
         .collect()
 }
 
+/// Positional argument which is completed dynamically, based on the application manifest
+/// and the server
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DynamicCompletionTarget {
+    ComponentName,
+    WorkerName,
+    FunctionName { worker_name: String },
+}
+
+impl DynamicCompletionTarget {
+    /// Detects the completed argument based on the command line words, which exclude the command
+    /// name, but include the currently completed (possibly empty) word as the last one
+    pub fn from_words(words: &[String]) -> Option<Self> {
+        let (current, previous) = words.split_last()?;
+        if current.starts_with('-') {
+            return None;
+        }
+
+        let mut root_command = GolemCliCommand::command();
+        // Building propagates the global flags to the subcommands
+        root_command.build();
+
+        let takes_value = |command: &clap::Command, matches: &dyn Fn(&clap::Arg) -> bool| {
+            command
+                .get_arguments()
+                .any(|arg| matches(arg) && arg.get_action().takes_values())
+        };
+
+        let mut command = &root_command;
+        let mut positional_values = Vec::<&str>::new();
+        let mut words = previous.iter();
+        while let Some(word) = words.next() {
+            if let Some(long) = word.strip_prefix("--") {
+                if long.is_empty() {
+                    // Not tracking positional args after "--"
+                    return None;
+                }
+                if !long.contains('=') && takes_value(command, &|arg| arg.get_long() == Some(long))
+                {
+                    words.next();
+                }
+            } else if let Some(shorts) = word.strip_prefix('-').filter(|shorts| !shorts.is_empty())
+            {
+                // Only the first value taking flag in a group matters, the rest of the group
+                // is its value, otherwise the value is the next word
+                let mut shorts = shorts.chars();
+                while let Some(short) = shorts.next() {
+                    if takes_value(command, &|arg| arg.get_short() == Some(short)) {
+                        if shorts.as_str().is_empty() {
+                            words.next();
+                        }
+                        break;
+                    }
+                }
+            } else if let Some(subcommand) = positional_values
+                .is_empty()
+                .then(|| command.find_subcommand(word))
+                .flatten()
+            {
+                command = subcommand;
+            } else {
+                positional_values.push(word);
+            }
+        }
+
+        let positional_args = command.get_positionals().collect::<Vec<_>>();
+        let completed_arg = positional_args.get(positional_values.len()).or_else(|| {
+            positional_args
+                .last()
+                .filter(|arg| matches!(arg.get_action(), clap::ArgAction::Append))
+        })?;
+
+        match completed_arg.get_id().as_str() {
+            "component_name" => Some(Self::ComponentName),
+            "worker_name" | "worker_names" | "other_worker_name" => Some(Self::WorkerName),
+            "function_name" => positional_args
+                .iter()
+                .zip(positional_values)
+                .find_map(|(arg, value)| match arg.get_id().as_str() {
+                    "worker_name" => Some(value.to_string()),
+                    "component_name" => Some(format!("{value}/-")),
+                    _ => None,
+                })
+                .map(|worker_name| Self::FunctionName { worker_name }),
+            _ => None,
+        }
+    }
+}
+
 fn parse_key_val(key_and_val: &str) -> anyhow::Result<(String, String)> {
     let pos = key_and_val.find('=').ok_or_else(|| {
         anyhow!(
@@ -1771,14 +2261,115 @@ fn parse_instant(
     }
 }
 
+/// Parses durations like "90s", "5m", "1h30m" or "500ms", a plain number means seconds
+fn parse_duration(duration: &str) -> anyhow::Result<Duration> {
+    if let Ok(seconds) = duration.parse::<u64>() {
+        return Ok(Duration::from_secs(seconds));
+    }
+
+    let mut rest = duration.trim();
+    if rest.is_empty() {
+        bail!("Empty duration");
+    }
+
+    let mut result = Duration::ZERO;
+    while !rest.is_empty() {
+        let number_len = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        if number_len == 0 {
+            bail!("Invalid duration: {}, expected a number", duration);
+        }
+        let number: u64 = rest[..number_len].parse()?;
+        rest = &rest[number_len..];
+
+        let unit_len = rest
+            .find(|c: char| c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let unit_millis = match &rest[..unit_len] {
+            "ms" => 1,
+            "s" => 1000,
+            "m" => 60 * 1000,
+            "h" => 60 * 60 * 1000,
+            "d" => 24 * 60 * 60 * 1000,
+            unit => bail!(
+                "Invalid duration unit: {}, expected one of: ms, s, m, h, d",
+                unit
+            ),
+        };
+        rest = &rest[unit_len..];
+
+        result = number
+            .checked_mul(unit_millis)
+            .and_then(|millis| result.checked_add(Duration::from_millis(millis)))
+            .ok_or_else(|| anyhow!("Duration is too long: {}", duration))?;
+    }
+
+    Ok(result)
+}
+
+/// Parses a rate (events per second), which must result in a non-zero interval between the events
+fn parse_rate(rate: &str) -> anyhow::Result<f64> {
+    let rate = rate
+        .parse::<f64>()
+        .map_err(|_| anyhow!("Invalid rate: {}, expected a number", rate))?;
+    if !rate.is_finite() || rate <= 0.0 {
+        bail!("Invalid rate: {}, expected a positive number", rate);
+    }
+    match Duration::try_from_secs_f64(1.0 / rate) {
+        Ok(interval) if !interval.is_zero() => Ok(rate),
+        Ok(_) => bail!(
+            "Rate is too high: {}, the interval between events would be shorter than a nanosecond",
+            rate
+        ),
+        Err(_) => bail!(
+            "Rate is too low: {}, the interval between events would be too long",
+            rate
+        ),
+    }
+}
+
+fn parse_oplog_index_range(range: &str) -> anyhow::Result<OplogIndexRange> {
+    let Some((from, to)) = range.split_once("..") else {
+        bail!("Invalid oplog index range: {range}, expected FROM..TO, FROM.. or ..TO");
+    };
+    let parse_index = |index: &str| -> anyhow::Result<Option<u64>> {
+        let index = index.trim();
+        if index.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(index.parse::<u64>().with_context(|| {
+                anyhow!("Invalid oplog index in range {range}: {index}")
+            })?))
+        }
+    };
+
+    let range = OplogIndexRange {
+        from: parse_index(from)?,
+        to: parse_index(to)?,
+    };
+    if let (Some(from), Some(to)) = (range.from, range.to) {
+        if from > to {
+            bail!("Invalid oplog index range: {range}, the start is after the end");
+        }
+    }
+
+    Ok(range)
+}
+
 #[cfg(test)]
 mod test {
-    use crate::command::{builtin_app_subcommands, GolemCliCommand};
+    use crate::command::{
+        builtin_app_subcommands, parse_duration, parse_oplog_index_range, parse_rate,
+        DynamicCompletionTarget, GolemCliCommand,
+    };
+    use crate::model::oplog_diff::OplogIndexRange;
     use assert2::assert;
     use clap::builder::StyledStr;
-    use clap::{Command, CommandFactory};
+    use clap::{Command, CommandFactory, Parser};
     use itertools::Itertools;
     use std::collections::{BTreeMap, BTreeSet};
+    use std::time::Duration;
     use test_r::test;
 
     #[test]
@@ -1832,6 +2423,54 @@ mod test {
         );
     }
 
+    #[test]
+    fn dynamic_completion_target_detects_completed_positional_args() {
+        fn target(words: &[&str]) -> Option<DynamicCompletionTarget> {
+            DynamicCompletionTarget::from_words(
+                &words
+                    .iter()
+                    .map(|word| word.to_string())
+                    .collect::<Vec<_>>(),
+            )
+        }
+
+        assert!(target(&["worker", "invoke", ""]) == Some(DynamicCompletionTarget::WorkerName));
+        assert!(
+            target(&["worker", "invoke", "my-comp/w1", "api"])
+                == Some(DynamicCompletionTarget::FunctionName {
+                    worker_name: "my-comp/w1".to_string()
+                })
+        );
+        assert!(
+            target(&["-p", "local", "worker", "invoke", "-i", "key", "-e", "my-comp/"])
+                == Some(DynamicCompletionTarget::WorkerName)
+        );
+        assert!(
+            target(&["worker", "bench", "my-comp", ""])
+                == Some(DynamicCompletionTarget::FunctionName {
+                    worker_name: "my-comp/-".to_string()
+                })
+        );
+        assert!(target(&["worker", "stream", ""]) == Some(DynamicCompletionTarget::WorkerName));
+        assert!(
+            target(&["worker", "stream", "my-comp/w1", "my-comp/"])
+                == Some(DynamicCompletionTarget::WorkerName)
+        );
+        assert!(
+            target(&["worker", "oplog-diff", "my-comp/w1", ""])
+                == Some(DynamicCompletionTarget::WorkerName)
+        );
+        assert!(target(&["repl", "my"]) == Some(DynamicCompletionTarget::ComponentName));
+        assert!(
+            target(&["component", "deploy", "comp-a", ""])
+                == Some(DynamicCompletionTarget::ComponentName)
+        );
+        assert!(target(&["worker", "invoke", "my-comp/w1", "api", ""]).is_none());
+        assert!(target(&["worker", "invoke", "--"]).is_none());
+        assert!(target(&["profile", "get", ""]).is_none());
+        assert!(target(&[]).is_none());
+    }
+
     #[test]
     fn invalid_arg_matchers_are_using_valid_commands_and_args_names() {
         fn collect_positional_args(
@@ -1994,4 +2633,92 @@ mod test {
     fn builtin_app_subcommands_no_panic() {
         println!("{:?}", builtin_app_subcommands())
     }
+
+    #[test]
+    fn parse_oplog_index_range_accepts_open_ranges() {
+        let range = |from, to| OplogIndexRange { from, to };
+
+        assert!(parse_oplog_index_range("10..20").unwrap() == range(Some(10), Some(20)));
+        assert!(parse_oplog_index_range("10..").unwrap() == range(Some(10), None));
+        assert!(parse_oplog_index_range("..20").unwrap() == range(None, Some(20)));
+        assert!(parse_oplog_index_range("..").unwrap() == range(None, None));
+        assert!(parse_oplog_index_range("10").is_err());
+        assert!(parse_oplog_index_range("20..10").is_err());
+        assert!(parse_oplog_index_range("a..b").is_err());
+    }
+
+    #[test]
+    fn parse_duration_accepts_units_and_plain_seconds() {
+        assert!(parse_duration("90").unwrap() == Duration::from_secs(90));
+        assert!(parse_duration("500ms").unwrap() == Duration::from_millis(500));
+        assert!(parse_duration("5m").unwrap() == Duration::from_secs(300));
+        assert!(parse_duration("1h30m").unwrap() == Duration::from_secs(5400));
+        assert!(parse_duration("1d").unwrap() == Duration::from_secs(86400));
+        assert!(parse_duration("").is_err());
+        assert!(parse_duration("5x").is_err());
+        assert!(parse_duration("m5").is_err());
+        assert!(parse_duration("999999999999999d").is_err());
+        assert!(parse_duration("18446744073709551615s").is_err());
+        assert!(parse_duration("18446744073709551615ms1ms").is_ok());
+    }
+
+    #[test]
+    fn parse_rate_rejects_invalid_intervals() {
+        assert!(parse_rate("10").unwrap() == 10.0);
+        assert!(parse_rate("0.5").unwrap() == 0.5);
+        assert!(parse_rate("1e9").unwrap() == 1e9);
+        assert!(parse_rate("").is_err());
+        assert!(parse_rate("fast").is_err());
+        assert!(parse_rate("0").is_err());
+        assert!(parse_rate("-1").is_err());
+        assert!(parse_rate("NaN").is_err());
+        assert!(parse_rate("inf").is_err());
+        assert!(parse_rate("1e10").is_err());
+        assert!(parse_rate("1e-300").is_err());
+    }
+
+    #[test]
+    fn worker_new_fan_out_args() {
+        let parses = |args: &[&str]| {
+            <GolemCliCommand as Parser>::try_parse_from(
+                ["golem-cli", "worker", "new"].iter().chain(args),
+            )
+            .is_ok()
+        };
+
+        assert!(parses(&["comp/w1", "1", "2", "--env", "A=1"]));
+        assert!(parses(&["--from-csv", "workers.csv", "--concurrency", "2"]));
+        assert!(parses(&[
+            "--component",
+            "comp",
+            "--name-pattern",
+            "w-{n}",
+            "--count",
+            "3",
+            "--arg",
+            "1",
+            "--arg",
+            "2",
+        ]));
+
+        assert!(!parses(&[]));
+        assert!(!parses(&["comp/w1", "--concurrency", "2"]));
+        assert!(!parses(&["comp/w1", "--component", "comp"]));
+        assert!(!parses(&[
+            "comp/w1",
+            "--name-pattern",
+            "w-{n}",
+            "--count",
+            "3"
+        ]));
+        assert!(!parses(&["--from-csv", "workers.csv", "--arg", "1"]));
+        assert!(!parses(&[
+            "--from-csv",
+            "workers.csv",
+            "--name-pattern",
+            "w-{n}",
+            "--count",
+            "3"
+        ]));
+    }
 }
@@ -72,9 +72,14 @@ impl AppCommandHandler {
             AppSubcommand::UpdateWorkers {
                 component_name,
                 update_mode,
+                check_compatibility,
             } => {
-                self.cmd_update_workers(component_name.component_name, update_mode)
-                    .await
+                self.cmd_update_workers(
+                    component_name.component_name,
+                    update_mode,
+                    check_compatibility,
+                )
+                .await
             }
             AppSubcommand::RedeployWorkers { component_name } => {
                 self.cmd_redeploy_workers(component_name.component_name)
@@ -291,6 +296,7 @@ impl AppCommandHandler {
         &mut self,
         component_names: Vec<ComponentName>,
         update_mode: WorkerUpdateMode,
+        check_compatibility: bool,
     ) -> anyhow::Result<()> {
         self.must_select_components(component_names, &ApplicationComponentSelectMode::All)
             .await?;
@@ -298,7 +304,7 @@ impl AppCommandHandler {
         let components = self.components_for_update_or_redeploy().await?;
         self.ctx
             .component_handler()
-            .update_workers_by_components(components, update_mode)
+            .update_workers_by_components(components, update_mode, check_compatibility)
             .await?;
 
         Ok(())
@@ -505,6 +511,8 @@ impl AppCommandHandler {
         &self,
         requested_template_name: &str,
     ) -> anyhow::Result<(Option<&Template>, &Template)> {
+        self.log_template_repository_errors();
+
         let segments = requested_template_name.split("/").collect::<Vec<_>>();
         let (language, template_name): (String, Option<String>) = match segments.len() {
             1 => (segments[0].to_string(), None),
@@ -568,6 +576,12 @@ impl AppCommandHandler {
         }
     }
 
+    pub fn log_template_repository_errors(&self) {
+        for error in self.ctx.template_repository_errors() {
+            log_warn(error);
+        }
+    }
+
     pub fn log_templates_help(
         &self,
         language_filter: Option<GuestLanguage>,
@@ -632,18 +646,21 @@ impl AppCommandHandler {
 
             logln(format!("- {}", language.to_string().bold()));
             for (template_name, template) in templates {
+                let source = format!("[{}]", template.source).black();
                 if template_name.as_str() == "default" {
                     logln(format!(
-                        "  - {}: {}",
+                        "  - {}: {} {}",
                         language.id().bold(),
                         template.description,
+                        source,
                     ));
                 } else {
                     logln(format!(
-                        "  - {}/{}: {}",
+                        "  - {}/{}: {} {}",
                         language.id().bold(),
                         template.name.as_str().bold(),
                         template.description,
+                        source,
                     ));
                 }
             }
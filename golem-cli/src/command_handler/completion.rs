@@ -0,0 +1,292 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::command::{DynamicCompletionTarget, GolemCliCommand};
+use crate::command_handler::Handlers;
+use crate::command_name;
+use crate::context::{Context, GolemClients};
+use crate::error::service::AnyhowMapServiceError;
+use crate::error::NonSuccessfulExit;
+use crate::log::{set_log_output, Output};
+use crate::model::completion_cache::CompletionCache;
+use crate::model::component::{Component, ComponentSelection};
+use crate::model::worker::invocable_function_names;
+use anyhow::bail;
+use clap::CommandFactory;
+use clap_complete::Shell;
+use indoc::formatdoc;
+use itertools::Itertools;
+use std::future::Future;
+use std::sync::Arc;
+use tracing::debug;
+
+pub struct CompletionCommandHandler {
+    ctx: Arc<Context>,
+}
+
+impl CompletionCommandHandler {
+    pub fn new(ctx: Arc<Context>) -> Self {
+        Self { ctx }
+    }
+
+    pub fn cmd_completion(&self, shell: Shell) -> anyhow::Result<()> {
+        let mut command = GolemCliCommand::command();
+        let command_name = command_name();
+        debug!(command_name, shell=%shell, "completion");
+        clap_complete::generate(
+            shell,
+            &mut command,
+            command_name.clone(),
+            &mut std::io::stdout(),
+        );
+        if let Some(script) = dynamic_completion_script(shell, &command_name) {
+            println!("{}", script);
+        }
+        Ok(())
+    }
+
+    pub async fn cmd_complete(&self, words: Vec<String>) -> anyhow::Result<()> {
+        // Only the candidates can be written to the output
+        set_log_output(Output::None);
+        self.ctx.silence_app_context_init().await;
+
+        // The failing exit code signals the completion scripts to fall back to static completion
+        let Some(target) = DynamicCompletionTarget::from_words(&words) else {
+            bail!(NonSuccessfulExit);
+        };
+        let current = words.last().map(|word| word.as_str()).unwrap_or_default();
+
+        let candidates = match target {
+            DynamicCompletionTarget::ComponentName => self.component_names().await,
+            DynamicCompletionTarget::WorkerName => match current.rsplit_once('/') {
+                Some((component_name, _)) => self.worker_names(component_name).await,
+                None => self.component_names().await.map(|component_names| {
+                    component_names
+                        .into_iter()
+                        .map(|component_name| format!("{}/", component_name))
+                        .collect()
+                }),
+            },
+            DynamicCompletionTarget::FunctionName { worker_name } => {
+                self.function_names(&worker_name).await
+            }
+        };
+
+        // Unreachable servers or unknown components should not break the shell completion
+        let candidates = candidates.unwrap_or_else(|err| {
+            debug!(error = %err, "Failed to resolve completion candidates");
+            vec![]
+        });
+
+        for candidate in candidates
+            .iter()
+            .filter(|candidate| candidate.starts_with(current))
+        {
+            println!("{}", candidate);
+        }
+
+        Ok(())
+    }
+
+    async fn component_names(&self) -> anyhow::Result<Vec<String>> {
+        let app_component_names = {
+            let app_ctx = self.ctx.app_context_lock().await;
+            match app_ctx.opt() {
+                Ok(Some(app_ctx)) => app_ctx
+                    .application
+                    .component_names()
+                    .map(|component_name| component_name.to_string())
+                    .collect::<Vec<_>>(),
+                _ => vec![],
+            }
+        };
+
+        let server_component_names = self
+            .cached("components".to_string(), async {
+                let components = match self.ctx.golem_clients().await? {
+                    GolemClients::Oss(clients) => clients
+                        .component
+                        .get_components(None)
+                        .await
+                        .map_service_error()?
+                        .into_iter()
+                        .map(Component::from)
+                        .collect::<Vec<_>>(),
+                    GolemClients::Cloud(clients) => clients
+                        .component
+                        .get_components(None, None)
+                        .await
+                        .map_service_error()?
+                        .into_iter()
+                        .map(Component::from)
+                        .collect::<Vec<_>>(),
+                };
+                Ok(components
+                    .into_iter()
+                    .map(|component| component.component_name.0)
+                    .collect())
+            })
+            .await
+            .unwrap_or_else(|err| {
+                debug!(error = %err, "Failed to get component names from the server");
+                vec![]
+            });
+
+        Ok(app_component_names
+            .into_iter()
+            .chain(server_component_names)
+            .sorted()
+            .dedup()
+            .collect())
+    }
+
+    async fn worker_names(&self, component_name: &str) -> anyhow::Result<Vec<String>> {
+        self.cached(format!("workers/{}", component_name), async {
+            let mut worker_handler = self.ctx.worker_handler();
+            let worker_name_match = worker_handler
+                .match_worker_name(format!("{}/-", component_name).into())
+                .await?;
+            let Some(component) = self
+                .ctx
+                .component_handler()
+                .component(
+                    worker_name_match.project.as_ref(),
+                    ComponentSelection::Name(&worker_name_match.component_name),
+                    None,
+                )
+                .await?
+            else {
+                return Ok(vec![]);
+            };
+
+            let (workers, _) = worker_handler
+                .list_component_workers(
+                    &worker_name_match.component_name,
+                    component.versioned_component_id.component_id,
+                    None,
+                    None,
+                    None,
+                    false,
+                )
+                .await?;
+
+            Ok(workers
+                .into_iter()
+                .map(|worker| format!("{}/{}", component_name, worker.worker_id.worker_name))
+                .collect())
+        })
+        .await
+    }
+
+    async fn function_names(&self, worker_name: &str) -> anyhow::Result<Vec<String>> {
+        let component_name = worker_name
+            .rsplit_once('/')
+            .map(|(component_name, _)| component_name)
+            .unwrap_or(worker_name);
+
+        self.cached(format!("functions/{}", component_name), async {
+            let worker_name_match = self
+                .ctx
+                .worker_handler()
+                .match_worker_name(worker_name.to_string().into())
+                .await?;
+            let component = self
+                .ctx
+                .component_handler()
+                .component(
+                    worker_name_match.project.as_ref(),
+                    ComponentSelection::Name(&worker_name_match.component_name),
+                    None,
+                )
+                .await?;
+
+            Ok(component
+                .map(|component| invocable_function_names(&component.metadata.exports))
+                .unwrap_or_default())
+        })
+        .await
+    }
+
+    async fn cached(
+        &self,
+        key: String,
+        values: impl Future<Output = anyhow::Result<Vec<String>>>,
+    ) -> anyhow::Result<Vec<String>> {
+        let key = format!("{}/{}", self.ctx.profile_name().0, key);
+        let config_dir = self.ctx.config_dir();
+
+        if let Some(values) = CompletionCache::get(config_dir, &key) {
+            return Ok(values);
+        }
+
+        let values = values.await?;
+        CompletionCache::set(config_dir, key, values.clone())?;
+        Ok(values)
+    }
+}
+
+/// Shell functions which are installed on top of the static clap completion, asking the
+/// CLI for dynamic candidates first, then falling back to the static completion
+fn dynamic_completion_script(shell: Shell, command_name: &str) -> Option<String> {
+    let function_name = format!("_{}_dynamic", command_name.replace('-', "_"));
+    match shell {
+        Shell::Bash => Some(formatdoc! {r#"
+
+            {function_name}() {{
+                local candidates
+                if candidates="$("${{COMP_WORDS[0]}}" __complete -- "${{COMP_WORDS[@]:1:COMP_CWORD}}" 2>/dev/null)"; then
+                    local cur="${{COMP_WORDS[COMP_CWORD]}}"
+                    local IFS=$'\n'
+                    COMPREPLY=($(compgen -W "${{candidates}}" -- "${{cur}}"))
+                    if [[ ${{#COMPREPLY[@]}} -eq 1 && "${{COMPREPLY[0]}}" == */ ]]; then
+                        compopt -o nospace
+                    fi
+                    if declare -F __ltrim_colon_completions >/dev/null; then
+                        __ltrim_colon_completions "${{cur}}"
+                    fi
+                    return 0
+                fi
+                _{command_name} "$@"
+            }}
+
+            complete -F {function_name} -o bashdefault -o default {command_name}
+        "#}),
+        Shell::Zsh => Some(formatdoc! {r#"
+
+            {function_name}() {{
+                local output
+                if output="$(${{words[1]}} __complete -- "${{(@)words[2,CURRENT]}}" 2>/dev/null)"; then
+                    local -a candidates
+                    candidates=("${{(@f)output}}")
+                    compadd -S '' -- "${{(@M)candidates:#*/}}"
+                    compadd -- "${{(@)candidates:#*/}}"
+                    return 0
+                fi
+                _{command_name} "$@"
+            }}
+
+            compdef {function_name} {command_name}
+        "#}),
+        Shell::Fish => Some(formatdoc! {r#"
+
+            function {function_name}
+                set -l words (commandline -opc) (commandline -ct)
+                command $words[1] __complete -- $words[2..-1] 2>/dev/null
+            end
+
+            complete -c {command_name} -f -n '{function_name} >/dev/null' -a '({function_name})'
+        "#}),
+        _ => None,
+    }
+}
@@ -14,13 +14,16 @@
 
 use crate::log::{log_action, LogColorize, LogIndent};
 use crate::model::app::InitialComponentFile;
+use crate::model::component::ComponentFileHash;
+use crate::model::text::fmt::{format_binary_size, log_warn};
 use anyhow::{anyhow, bail, Context};
 use async_zip::tokio::write::ZipFileWriter;
 use async_zip::{Compression, ZipEntryBuilder};
 use golem_common::model::{
     ComponentFilePath, ComponentFilePathWithPermissions, ComponentFilePathWithPermissionsList,
 };
-use std::collections::{HashSet, VecDeque};
+use minijinja::UndefinedBehavior;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::path::PathBuf;
 use tempfile::TempDir;
 use tokio::fs::File;
@@ -30,9 +33,42 @@ use tokio_stream::StreamExt;
 #[derive(Debug, Clone)]
 pub struct LoadedFile {
     content: Vec<u8>,
+    hash: String,
     target: ComponentFilePathWithPermissions,
 }
 
+impl LoadedFile {
+    fn new(content: Vec<u8>, target: ComponentFilePathWithPermissions) -> Self {
+        Self {
+            hash: blake3::hash(&content).to_hex().to_string(),
+            content,
+            target,
+        }
+    }
+
+    fn render(
+        self,
+        template_env: &minijinja::Environment,
+        template_variables: &HashMap<String, String>,
+    ) -> anyhow::Result<Self> {
+        let path = self.target.path.to_string();
+        let template = String::from_utf8(self.content)
+            .with_context(|| anyhow!("Templated component file is not valid UTF-8: {}", path))?;
+        let content = template_env
+            .render_str(&template, template_variables)
+            .with_context(|| anyhow!("Failed to render templated component file: {}", path))?;
+        Ok(Self::new(content.into_bytes(), self.target))
+    }
+
+    pub fn file_hash(&self) -> ComponentFileHash {
+        ComponentFileHash {
+            path: self.target.path.to_string(),
+            permissions: self.target.permissions,
+            hash: Some(self.hash.clone()),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct ComponentFilesArchive {
     pub archive_path: PathBuf,
@@ -49,9 +85,64 @@ impl IfsArchiveBuilder {
         Self { client }
     }
 
-    pub async fn build_files_archive(
+    /// Loads the content of the component files and computes their hashes, without creating the archive
+    pub async fn load_files(
         &self,
         component_files: Vec<InitialComponentFile>,
+        template_variables: &HashMap<String, String>,
+    ) -> anyhow::Result<Vec<LoadedFile>> {
+        let mut template_env = minijinja::Environment::new();
+        template_env.set_undefined_behavior(UndefinedBehavior::Strict);
+
+        let mut seen_paths: HashSet<ComponentFilePath> = HashSet::new();
+        let mut loaded_files = vec![];
+
+        for component_file in component_files {
+            let source = component_file.source.as_url().to_string();
+            let template = component_file.template;
+            let size_limit = component_file.size_limit;
+
+            let mut files = self.load_file(component_file).await?;
+
+            if template {
+                files = files
+                    .into_iter()
+                    .map(|file| file.render(&template_env, template_variables))
+                    .collect::<anyhow::Result<Vec<_>>>()?;
+            }
+
+            if let Some(size_limit) = size_limit {
+                let size = files
+                    .iter()
+                    .map(|file| file.content.len() as u64)
+                    .sum::<u64>();
+                if size > size_limit {
+                    log_warn(format!(
+                        "Component files loaded from {} have a total size of {}, which exceeds the size limit of {}",
+                        source.log_color_highlight(),
+                        format_binary_size(&size).log_color_highlight(),
+                        format_binary_size(&size_limit).log_color_highlight(),
+                    ));
+                }
+            }
+
+            for loaded_file in files {
+                if !seen_paths.insert(loaded_file.target.path.clone()) {
+                    bail!(
+                        "Conflicting paths in component files: {}",
+                        loaded_file.target.path
+                    );
+                }
+                loaded_files.push(loaded_file);
+            }
+        }
+
+        Ok(loaded_files)
+    }
+
+    pub async fn build_files_archive(
+        &self,
+        loaded_files: Vec<LoadedFile>,
     ) -> anyhow::Result<ComponentFilesArchive> {
         log_action("Creating", "IFS archive");
         let _indent = LogIndent::new();
@@ -67,37 +158,32 @@ impl IfsArchiveBuilder {
 
         let mut zip_writer = ZipFileWriter::with_tokio(zip_file);
 
-        let mut seen_paths: HashSet<ComponentFilePath> = HashSet::new();
         let mut successfully_added: Vec<ComponentFilePathWithPermissions> = vec![];
 
-        for component_file in component_files {
-            for LoadedFile { content, target } in self.load_file(component_file).await? {
-                if !seen_paths.insert(target.path.clone()) {
-                    bail!("Conflicting paths in component files: {}", target.path);
-                }
+        for LoadedFile {
+            content, target, ..
+        } in loaded_files
+        {
+            // zip files do not like absolute paths. Convert the absolute component path to relative path from the root of the zip file
+            let zip_entry_name = target.path.to_string();
+            let builder = ZipEntryBuilder::new(zip_entry_name.clone().into(), Compression::Deflate);
 
-                // zip files do not like absolute paths. Convert the absolute component path to relative path from the root of the zip file
-                let zip_entry_name = target.path.to_string();
-                let builder =
-                    ZipEntryBuilder::new(zip_entry_name.clone().into(), Compression::Deflate);
-
-                log_action(
-                    "Adding",
-                    format!(
-                        "entry {} to IFS archive",
-                        zip_entry_name.log_color_highlight()
-                    ),
-                );
+            log_action(
+                "Adding",
+                format!(
+                    "entry {} to IFS archive",
+                    zip_entry_name.log_color_highlight()
+                ),
+            );
 
-                zip_writer
-                    .write_entry_whole(builder, &content)
-                    .await
-                    .with_context(|| {
-                        anyhow!("Error writing zip entry for IFS archive {}", zip_entry_name)
-                    })?;
+            zip_writer
+                .write_entry_whole(builder, &content)
+                .await
+                .with_context(|| {
+                    anyhow!("Error writing zip entry for IFS archive {}", zip_entry_name)
+                })?;
 
-                successfully_added.push(target);
-            }
+            successfully_added.push(target);
         }
         zip_writer.close().await.with_context(|| {
             anyhow!(
@@ -143,11 +229,22 @@ impl IfsArchiveBuilder {
         // if it's a directory, we need to recursively load all files and combine them with their target paths and permissions.
         let source_path = PathBuf::from(component_file.source.as_url().path());
 
+        // include, exclude and permission patterns are matched against the path relative to the source,
+        // for single file sources that is the file name
+        let relative_path = if source_path.is_dir() {
+            PathBuf::new()
+        } else {
+            source_path
+                .file_name()
+                .map(PathBuf::from)
+                .unwrap_or_default()
+        };
+
         let mut results: Vec<LoadedFile> = vec![];
-        let mut queue: VecDeque<(PathBuf, ComponentFilePathWithPermissions)> =
-            vec![(source_path, component_file.target)].into();
+        let mut queue: VecDeque<(PathBuf, PathBuf, ComponentFilePathWithPermissions)> =
+            vec![(source_path, relative_path, component_file.target.clone())].into();
 
-        while let Some((path, target)) = queue.pop_front() {
+        while let Some((path, relative_path, mut target)) = queue.pop_front() {
             if path.is_dir() {
                 let read_dir = tokio::fs::read_dir(&path)
                     .await
@@ -165,14 +262,24 @@ impl IfsArchiveBuilder {
                         )
                     })?;
 
+                    let next_relative_path = relative_path.join(&file_name);
+                    if component_file.is_excluded(&next_relative_path) {
+                        continue;
+                    }
+
                     let mut new_target = target.clone();
                     new_target
                         .extend_path(file_name.as_str())
                         .map_err(|err| anyhow!("Error extending path: {err}"))?;
 
-                    queue.push_back((next_path, new_target));
+                    queue.push_back((next_path, next_relative_path, new_target));
                 }
             } else {
+                if !component_file.is_included(&relative_path) {
+                    continue;
+                }
+                target.permissions = component_file.permissions(&relative_path);
+
                 log_action(
                     "Loading",
                     format!(
@@ -183,7 +290,7 @@ impl IfsArchiveBuilder {
                 let content = tokio::fs::read(&path)
                     .await
                     .with_context(|| anyhow!("Error reading component file: {}", path.display()))?;
-                results.push(LoadedFile { content, target });
+                results.push(LoadedFile::new(content, target));
             }
         }
         Ok(results)
@@ -207,9 +314,147 @@ impl IfsArchiveBuilder {
         let bytes = response.bytes().await?;
         let content = bytes.to_vec();
 
-        Ok(LoadedFile {
-            content,
-            target: component_file.target,
-        })
+        Ok(LoadedFile::new(content, component_file.target))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::command_handler::component::ifs::{IfsArchiveBuilder, LoadedFile};
+    use crate::model::app::InitialComponentFile;
+    use crate::model::app_raw;
+    use crate::validation::ValidationBuilder;
+    use assert2::check;
+    use golem_common::model::ComponentFilePermissions;
+    use std::collections::HashMap;
+    use std::fs;
+    use std::path::Path;
+
+    fn write_file(root: &Path, path: &str, content: &str) {
+        let path = root.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    fn initial_component_files(root: &Path, raw: &str) -> Vec<InitialComponentFile> {
+        let raw = serde_yaml::from_str::<Vec<app_raw::InitialComponentFile>>(raw).unwrap();
+        let mut validation = ValidationBuilder::new();
+        let files =
+            InitialComponentFile::from_raw_vec(&mut validation, &root.join("golem.yaml"), raw);
+        check!(!validation.has_any_errors());
+        files.unwrap()
+    }
+
+    fn loaded_files(files: &[LoadedFile]) -> Vec<(String, ComponentFilePermissions, String)> {
+        let mut files = files
+            .iter()
+            .map(|file| {
+                (
+                    file.target.path.to_string(),
+                    file.target.permissions,
+                    String::from_utf8(file.content.clone()).unwrap(),
+                )
+            })
+            .collect::<Vec<_>>();
+        files.sort_by(|a, b| a.0.cmp(&b.0));
+        files
+    }
+
+    #[test]
+    async fn load_files_with_glob_source_excludes_and_permission_rules() {
+        let root = tempfile::tempdir().unwrap();
+        write_file(root.path(), "assets/a.json", "a");
+        write_file(root.path(), "assets/b.txt", "b");
+        write_file(root.path(), "assets/config/c.json", "c");
+        write_file(root.path(), "assets/tmp/d.json", "d");
+
+        let files = initial_component_files(
+            root.path(),
+            r#"
+            - sourcePath: assets/**/*.json
+              targetPath: /data
+              exclude:
+                - tmp
+              permissionRules:
+                - pattern: config/**
+                  permissions: read-write
+            "#,
+        );
+
+        let loaded = IfsArchiveBuilder::new(reqwest::Client::new())
+            .load_files(files, &HashMap::new())
+            .await
+            .unwrap();
+
+        check!(
+            loaded_files(&loaded)
+                == vec![
+                    (
+                        "/data/a.json".to_string(),
+                        ComponentFilePermissions::ReadOnly,
+                        "a".to_string()
+                    ),
+                    (
+                        "/data/config/c.json".to_string(),
+                        ComponentFilePermissions::ReadWrite,
+                        "c".to_string()
+                    ),
+                ]
+        );
+    }
+
+    #[test]
+    async fn load_files_renders_templates() {
+        let root = tempfile::tempdir().unwrap();
+        write_file(root.path(), "config.toml", "url = \"{{ api_url }}\"");
+        write_file(root.path(), "raw.txt", "{{ api_url }}");
+
+        let files = initial_component_files(
+            root.path(),
+            r#"
+            - sourcePath: config.toml
+              targetPath: /config.toml
+              template: true
+            - sourcePath: raw.txt
+              targetPath: /raw.txt
+            "#,
+        );
+        let variables = HashMap::from([("api_url".to_string(), "http://localhost".to_string())]);
+
+        let loaded = IfsArchiveBuilder::new(reqwest::Client::new())
+            .load_files(files.clone(), &variables)
+            .await
+            .unwrap();
+
+        check!(
+            loaded_files(&loaded)
+                == vec![
+                    (
+                        "/config.toml".to_string(),
+                        ComponentFilePermissions::ReadOnly,
+                        "url = \"http://localhost\"".to_string()
+                    ),
+                    (
+                        "/raw.txt".to_string(),
+                        ComponentFilePermissions::ReadOnly,
+                        "{{ api_url }}".to_string()
+                    ),
+                ]
+        );
+        // The hash is computed from the rendered content
+        check!(
+            loaded[0].hash
+                == blake3::hash(b"url = \"http://localhost\"")
+                    .to_hex()
+                    .to_string()
+        );
+
+        // Undefined variables are reported as errors
+        let result = IfsArchiveBuilder::new(reqwest::Client::new())
+            .load_files(files, &HashMap::new())
+            .await;
+        check!(result.is_err());
     }
 }
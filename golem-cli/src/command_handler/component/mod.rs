@@ -20,42 +20,55 @@ use crate::command::shared_args::{
     BuildArgs, ComponentOptionalComponentNames, ComponentTemplatePositionalArg, ForceBuildArg,
     WorkerUpdateOrRedeployArgs,
 };
-use crate::command_handler::component::ifs::IfsArchiveBuilder;
+use crate::command_handler::component::ifs::{IfsArchiveBuilder, LoadedFile};
 use crate::command_handler::Handlers;
 use crate::context::{Context, GolemClients};
 use crate::error::service::AnyhowMapServiceError;
 use crate::error::NonSuccessfulExit;
+use crate::fs;
 use crate::log::{log_action, logln, LogColorize, LogIndent};
 use crate::model::app::{
     AppComponentName, ApplicationComponentSelectMode, BuildProfileName, DynamicHelpSections,
 };
 use crate::model::app::{DependencyType, InitialComponentFile};
-use crate::model::component::{Component, ComponentSelection, ComponentView};
-use crate::model::deploy::TryUpdateAllWorkersResult;
-use crate::model::text::component::{ComponentCreateView, ComponentGetView, ComponentUpdateView};
+use crate::model::component::{
+    Component, ComponentFileHash, ComponentFileKeys, ComponentFilesDiff, ComponentSelection,
+    ComponentView,
+};
+use crate::model::component_diff::ComponentDiffView;
+use crate::model::component_inspect::ComponentInspectView;
+use crate::model::deploy::{ComponentRollbackAction, TryUpdateAllWorkersResult};
+use crate::model::text::component::{
+    ComponentCreateView, ComponentDownloadView, ComponentGetView, ComponentUpdateView,
+};
 use crate::model::text::fmt::{log_error, log_text_view, log_warn};
 use crate::model::text::help::ComponentNameHelp;
 use crate::model::to_cloud::ToCloud;
 use crate::model::{
     ComponentName, ComponentNameMatchKind, ComponentVersionSelection, ProjectNameAndId,
-    SelectedComponents, WorkerUpdateMode,
+    SelectedComponents, WorkerMetadata, WorkerUpdateMode,
 };
 use anyhow::{anyhow, bail, Context as AnyhowContext};
+use futures_util::TryStreamExt;
 use golem_client::api::ComponentClient as ComponentClientOss;
 use golem_client::model::DynamicLinkedInstance as DynamicLinkedInstanceOss;
 use golem_client::model::DynamicLinkedWasmRpc as DynamicLinkedWasmRpcOss;
 use golem_client::model::DynamicLinking as DynamicLinkingOss;
 use golem_cloud_client::api::ComponentClient as ComponentClientCloud;
 use golem_cloud_client::model::ComponentQuery;
-use golem_common::model::component_metadata::WasmRpcTarget;
+use golem_common::model::component_metadata::{
+    ComponentMetadata, DynamicLinkedInstance, WasmRpcTarget,
+};
 use golem_common::model::{ComponentId, ComponentType};
 use golem_templates::add_component_by_template;
 use golem_templates::model::{GuestLanguage, PackageName};
+use golem_wasm_ast::analysis::AnalysedExport;
 use itertools::Itertools;
-use std::collections::HashMap;
-use std::path::PathBuf;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tokio::fs::File;
+use uuid::Uuid;
 
 pub mod ifs;
 pub mod plugin;
@@ -99,13 +112,57 @@ impl ComponentCommandHandler {
                 component_name,
                 version,
             } => self.cmd_get(component_name.component_name, version).await,
+            ComponentSubcommand::Download {
+                component_name,
+                version,
+                output,
+                files_manifest,
+                with_files,
+            } => {
+                self.cmd_download(
+                    component_name.component_name,
+                    version,
+                    output,
+                    files_manifest,
+                    with_files,
+                )
+                .await
+            }
+            ComponentSubcommand::Rollback {
+                component_name,
+                to,
+                retarget,
+                update_or_redeploy,
+            } => {
+                self.cmd_rollback(
+                    component_name.component_name,
+                    to,
+                    retarget,
+                    update_or_redeploy,
+                )
+                .await
+            }
+            ComponentSubcommand::Diff {
+                component_name,
+                version,
+                other_version,
+            } => {
+                self.cmd_diff(component_name.component_name, version, other_version)
+                    .await
+            }
+            ComponentSubcommand::Inspect { component } => self.cmd_inspect(component).await,
 
             ComponentSubcommand::UpdateWorkers {
                 component_name,
                 update_mode,
+                check_compatibility,
             } => {
-                self.cmd_update_workers(component_name.component_name, update_mode)
-                    .await
+                self.cmd_update_workers(
+                    component_name.component_name,
+                    update_mode,
+                    check_compatibility,
+                )
+                .await
             }
             ComponentSubcommand::RedeployWorkers { component_name } => {
                 self.cmd_redeploy_workers(component_name.component_name)
@@ -246,6 +303,7 @@ impl ComponentCommandHandler {
     }
 
     fn cmd_templates(&self, filter: Option<String>) {
+        self.ctx.app_handler().log_template_repository_errors();
         match filter {
             Some(filter) => {
                 if let Some(language) = GuestLanguage::from_string(filter.clone()) {
@@ -524,15 +582,579 @@ impl ComponentCommandHandler {
         Ok(())
     }
 
+    async fn cmd_diff(
+        &self,
+        component_name: Option<ComponentName>,
+        version: Option<u64>,
+        other_version: Option<u64>,
+    ) -> anyhow::Result<()> {
+        let (project, component_name) = self
+            .must_select_single_component(component_name.as_ref(), "comparing versions")
+            .await?;
+        let (project, component_name) = (project.as_ref(), &component_name);
+
+        let old_component = self
+            .deployed_component(project, component_name, version)
+            .await?;
+        let old_source = format!("version {}", old_component.versioned_component_id.version);
+
+        let (new_source, new_exports) = match other_version {
+            Some(other_version) => {
+                let new_component = self
+                    .deployed_component(project, component_name, Some(other_version))
+                    .await?;
+                (
+                    format!("version {}", new_component.versioned_component_id.version),
+                    new_component.metadata.exports,
+                )
+            }
+            None => (
+                "local build".to_string(),
+                self.local_exports(component_name).await?,
+            ),
+        };
+
+        log_action(
+            "Comparing",
+            format!(
+                "component {} {} with {}",
+                component_name.0.log_color_highlight(),
+                old_source.log_color_highlight(),
+                new_source.log_color_highlight()
+            ),
+        );
+
+        let diff = ComponentDiffView::new(
+            component_name.clone(),
+            old_source,
+            &old_component.metadata.exports,
+            new_source,
+            &new_exports,
+        );
+        self.ctx.log_handler().log_view(&diff);
+
+        if diff.has_breaking_changes() {
+            logln("");
+            log_error(format!(
+                "Found {} breaking change(s)",
+                diff.breaking_count().to_string().log_color_highlight()
+            ));
+            bail!(NonSuccessfulExit);
+        }
+
+        Ok(())
+    }
+
+    async fn cmd_download(
+        &self,
+        component_name: Option<ComponentName>,
+        version: Option<u64>,
+        output: Option<PathBuf>,
+        save_files_manifest: bool,
+        with_files: bool,
+    ) -> anyhow::Result<()> {
+        if with_files {
+            log_error(
+                "Downloading the initial files (IFS archive) of components is not supported by the server",
+            );
+            logln("");
+            logln(format!(
+                "Use {} to save the paths, permissions and keys of the initial files.",
+                "--files-manifest".log_color_highlight()
+            ));
+            bail!(NonSuccessfulExit);
+        }
+
+        let (project, component_name) = self
+            .must_select_single_component(component_name.as_ref(), "downloading")
+            .await?;
+
+        let component = self
+            .deployed_component(project.as_ref(), &component_name, version)
+            .await?;
+        let version = component.versioned_component_id.version;
+
+        let output = output.unwrap_or_else(|| {
+            PathBuf::from(format!(
+                "{}-{}.wasm",
+                component_name.0.replace([':', '/'], "_"),
+                version
+            ))
+        });
+
+        log_action(
+            "Downloading",
+            format!(
+                "component {} version {}",
+                component_name.0.log_color_highlight(),
+                version.to_string().log_color_highlight()
+            ),
+        );
+
+        let content = self
+            .download_component_wasm(component.versioned_component_id.component_id, version)
+            .await?;
+
+        // The server does not report a content hash for component binaries, so only the reported
+        // size is checked, and the hash is shown for comparing it with other copies
+        if content.len() as u64 != component.component_size {
+            log_error(format!(
+                "Downloaded component size ({} bytes) does not match the size reported by the server ({} bytes)",
+                content.len().to_string().log_color_highlight(),
+                component.component_size.to_string().log_color_highlight()
+            ));
+            bail!(NonSuccessfulExit);
+        }
+
+        fs::write(&output, &content)?;
+
+        let files_manifest = if save_files_manifest {
+            if component.files.is_empty() {
+                log_warn("The component has no initial files");
+                None
+            } else {
+                let files_manifest = output.with_extension("files.json");
+                fs::write_str(
+                    &files_manifest,
+                    serde_json::to_string_pretty(&component.files)?,
+                )?;
+                Some(files_manifest)
+            }
+        } else {
+            None
+        };
+
+        self.ctx.log_handler().log_view(&ComponentDownloadView {
+            component_name,
+            component_version: version,
+            path: output,
+            size: content.len() as u64,
+            hash: blake3::hash(&content).to_hex().to_string(),
+            verified: "size only, the server does not report a content hash".to_string(),
+            files_manifest,
+        });
+
+        Ok(())
+    }
+
+    async fn download_component_wasm(
+        &self,
+        component_id: Uuid,
+        version: u64,
+    ) -> anyhow::Result<Vec<u8>> {
+        let mut stream = match self.ctx.golem_clients().await? {
+            GolemClients::Oss(clients) => clients
+                .component
+                .download_component(&component_id, Some(version))
+                .await
+                .map_service_error()?,
+            GolemClients::Cloud(clients) => clients
+                .component
+                .download_component(&component_id, Some(version))
+                .await
+                .map_service_error()?,
+        };
+
+        let mut content = Vec::new();
+        while let Some(chunk) = stream
+            .try_next()
+            .await
+            .context("Failed to download component")?
+        {
+            content.extend_from_slice(&chunk);
+        }
+
+        Ok(content)
+    }
+
+    async fn cmd_rollback(
+        &self,
+        component_name: Option<ComponentName>,
+        to: u64,
+        retarget: bool,
+        update_or_redeploy: WorkerUpdateOrRedeployArgs,
+    ) -> anyhow::Result<()> {
+        let (project, component_name) = self
+            .must_select_single_component(component_name.as_ref(), "rollback")
+            .await?;
+
+        let latest_component = self
+            .deployed_component(project.as_ref(), &component_name, None)
+            .await?;
+        let target_component = self
+            .deployed_component(project.as_ref(), &component_name, Some(to))
+            .await?;
+
+        let files_diff = ComponentFilesDiff::new(
+            component_name.clone(),
+            latest_component.files.iter().map(ComponentFileHash::from),
+            target_component.files.iter().map(ComponentFileHash::from),
+        );
+
+        let component = match ComponentRollbackAction::new(
+            to,
+            latest_component.versioned_component_id.version,
+            retarget,
+            files_diff.has_changes(),
+        ) {
+            ComponentRollbackAction::UpToDate => {
+                log_warn(format!(
+                    "Version {} is the latest version of component {}, nothing to roll back",
+                    to.to_string().log_color_highlight(),
+                    component_name.0.log_color_highlight()
+                ));
+                return Ok(());
+            }
+            ComponentRollbackAction::Retarget => {
+                log_action(
+                    "Retargeting",
+                    format!(
+                        "workers of component {} to version {}",
+                        component_name.0.log_color_highlight(),
+                        to.to_string().log_color_highlight()
+                    ),
+                );
+                target_component
+            }
+            ComponentRollbackAction::Publish => {
+                self.publish_component_version(&target_component).await?
+            }
+            ComponentRollbackAction::RefuseChangedFiles => {
+                log_error(format!(
+                    "Rolling back component {} to version {} is not supported, as its initial files differ from the latest version, and the server cannot provide their content for publishing them again",
+                    component_name.0.log_color_highlight(),
+                    to.to_string().log_color_highlight()
+                ));
+                self.ctx.log_handler().log_view(&files_diff);
+                logln("");
+                logln(format!(
+                    "Use {} with {} to update the workers to version {} without publishing it again",
+                    "--retarget".log_color_highlight(),
+                    "--update-workers".log_color_highlight(),
+                    to.to_string().log_color_highlight()
+                ));
+                bail!(NonSuccessfulExit);
+            }
+        };
+
+        if let Some(update) = update_or_redeploy.update_workers {
+            self.update_workers_by_components(
+                vec![component],
+                update,
+                update_or_redeploy.check_compatibility,
+            )
+            .await?;
+        } else if update_or_redeploy.redeploy_workers {
+            self.redeploy_workers_by_components(vec![component]).await?;
+        } else {
+            let workers = self.component_workers(&component).await?;
+            let outdated_workers = workers
+                .iter()
+                .filter(|worker| {
+                    worker.component_version != component.versioned_component_id.version
+                })
+                .count();
+            if outdated_workers > 0 {
+                log_warn(format!(
+                    "{} worker(s) of component {} are not using version {}, use {} or {} to roll them back too",
+                    outdated_workers,
+                    component_name.0.log_color_highlight(),
+                    component.versioned_component_id.version.to_string().log_color_highlight(),
+                    "--update-workers".log_color_highlight(),
+                    "--redeploy-workers".log_color_highlight()
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    // Publishes the WASM of the target version as a new version of the component,
+    // the initial files are kept from the latest version, as their content cannot be downloaded
+    async fn publish_component_version(
+        &self,
+        target_component: &Component,
+    ) -> anyhow::Result<Component> {
+        let component_name = &target_component.component_name;
+        let component_id = target_component.versioned_component_id.component_id;
+        let version = target_component.versioned_component_id.version;
+
+        let Some(component_type) = target_component
+            .component_type
+            .as_deployable_component_type()
+        else {
+            log_error(format!(
+                "Component {} version {} is not deployable",
+                component_name.0.log_color_highlight(),
+                version.to_string().log_color_highlight()
+            ));
+            bail!(NonSuccessfulExit);
+        };
+
+        let dynamic_linking =
+            (!target_component.metadata.dynamic_linking.is_empty()).then(|| DynamicLinkingOss {
+                dynamic_linking: target_component
+                    .metadata
+                    .dynamic_linking
+                    .iter()
+                    .map(|(name, link)| {
+                        (
+                            name.clone(),
+                            match link {
+                                DynamicLinkedInstance::WasmRpc(links) => {
+                                    DynamicLinkedInstanceOss::WasmRpc(DynamicLinkedWasmRpcOss {
+                                        targets: links.targets.clone(),
+                                    })
+                                }
+                            },
+                        )
+                    })
+                    .collect(),
+            });
+
+        log_action(
+            "Rolling back",
+            format!(
+                "component {} to version {}",
+                component_name.0.log_color_highlight(),
+                version.to_string().log_color_highlight()
+            ),
+        );
+        let _indent = LogIndent::new();
+
+        let content = self.download_component_wasm(component_id, version).await?;
+        if content.len() as u64 != target_component.component_size {
+            log_error(format!(
+                "Downloaded component size ({} bytes) does not match the size reported by the server ({} bytes)",
+                content.len().to_string().log_color_highlight(),
+                target_component.component_size.to_string().log_color_highlight()
+            ));
+            bail!(NonSuccessfulExit);
+        }
+
+        let temp_dir = tempfile::Builder::new()
+            .prefix("golem-cli-rollback")
+            .tempdir()
+            .with_context(|| "Error creating temporary dir for component rollback")?;
+        let wasm_path = temp_dir.path().join("component.wasm");
+        fs::write(&wasm_path, &content)?;
+        let wasm = File::open(&wasm_path).await.with_context(|| {
+            anyhow!(
+                "Failed to open downloaded component WASM at {}",
+                wasm_path.display()
+            )
+        })?;
+
+        let component = match self.ctx.golem_clients().await? {
+            GolemClients::Oss(clients) => {
+                let component = clients
+                    .component
+                    .update_component(
+                        &component_id,
+                        Some(&component_type),
+                        wasm,
+                        None,
+                        None::<File>,
+                        dynamic_linking.as_ref(),
+                    )
+                    .await
+                    .map_service_error()?;
+                Component::from(component)
+            }
+            GolemClients::Cloud(clients) => {
+                let component = clients
+                    .component
+                    .update_component(
+                        &component_id,
+                        Some(&component_type),
+                        wasm,
+                        None,
+                        None::<File>,
+                        dynamic_linking.map(|dl| dl.to_cloud()).as_ref(),
+                    )
+                    .await
+                    .map_service_error()?;
+                Component::from(component)
+            }
+        };
+        self.ctx
+            .log_handler()
+            .log_view(&ComponentUpdateView(ComponentView::from(component.clone())));
+
+        Ok(component)
+    }
+
+    async fn component_workers(
+        &self,
+        component: &Component,
+    ) -> anyhow::Result<Vec<WorkerMetadata>> {
+        let (workers, _) = self
+            .ctx
+            .worker_handler()
+            .list_component_workers(
+                &component.component_name,
+                component.versioned_component_id.component_id,
+                None,
+                None,
+                None,
+                false,
+            )
+            .await?;
+        Ok(workers)
+    }
+
+    async fn cmd_inspect(&self, component: String) -> anyhow::Result<()> {
+        let wasm_path = PathBuf::from(&component);
+        let wasm_path = if wasm_path.is_file() {
+            wasm_path
+        } else {
+            let app_ctx = self.ctx.app_context_lock().await;
+            let app_component_name = AppComponentName::from(component.as_str());
+            match app_ctx.opt()? {
+                Some(app_ctx) if app_ctx.application.contains_component(&app_component_name) => {
+                    let linked_wasm_path = app_ctx
+                        .application
+                        .component_final_linked_wasm(&app_component_name, self.ctx.build_profile());
+                    ensure_linked_wasm_exists(app_component_name.as_str(), &linked_wasm_path)?;
+                    linked_wasm_path
+                }
+                _ => {
+                    log_error(format!(
+                        "{} is neither a WASM file nor a component of the current application",
+                        component.log_color_highlight()
+                    ));
+                    bail!(NonSuccessfulExit);
+                }
+            }
+        };
+
+        let view =
+            ComponentInspectView::new(wasm_path.display().to_string(), &fs::read(&wasm_path)?)?;
+        self.ctx.log_handler().log_view(&view);
+
+        Ok(())
+    }
+
+    async fn must_select_single_component(
+        &self,
+        component_name: Option<&ComponentName>,
+        action: &str,
+    ) -> anyhow::Result<(Option<ProjectNameAndId>, ComponentName)> {
+        let mut selected_components = self
+            .must_select_components_by_app_or_name(component_name)
+            .await?;
+
+        if selected_components.component_names.len() != 1 {
+            log_error(format!(
+                "Exactly one component must be selected for {}!",
+                action
+            ));
+            logln("");
+            logln(format!(
+                "Selected components: {}",
+                selected_components
+                    .component_names
+                    .iter()
+                    .map(|cn| cn.0.log_color_highlight())
+                    .join(", ")
+            ));
+            logln("");
+            logln("Specify the requested component name or switch to an application directory with exactly one component!");
+            logln("");
+            bail!(NonSuccessfulExit);
+        }
+
+        Ok((
+            selected_components.project,
+            selected_components.component_names.remove(0),
+        ))
+    }
+
+    async fn deployed_component(
+        &self,
+        project: Option<&ProjectNameAndId>,
+        component_name: &ComponentName,
+        version: Option<u64>,
+    ) -> anyhow::Result<Component> {
+        match self
+            .component(
+                project,
+                component_name.into(),
+                version.map(|version| version.into()),
+            )
+            .await?
+        {
+            Some(component) => Ok(component),
+            None => {
+                let latest_component = match version {
+                    Some(_) => self.component(project, component_name.into(), None).await?,
+                    None => None,
+                };
+                match (version, latest_component) {
+                    (Some(version), Some(latest_component)) => log_error(format!(
+                        "Version {} of component {} not found, the latest version is {}",
+                        version.to_string().log_color_highlight(),
+                        component_name.0.log_color_highlight(),
+                        latest_component
+                            .versioned_component_id
+                            .version
+                            .to_string()
+                            .log_color_highlight()
+                    )),
+                    _ => log_error(format!(
+                        "Component {} is not deployed",
+                        component_name.0.log_color_highlight()
+                    )),
+                }
+                bail!(NonSuccessfulExit)
+            }
+        }
+    }
+
+    async fn local_exports(
+        &self,
+        component_name: &ComponentName,
+    ) -> anyhow::Result<Vec<AnalysedExport>> {
+        let linked_wasm_path = {
+            let app_ctx = self.ctx.app_context_lock().await;
+            let app_ctx = app_ctx.some_or_err()?;
+            let app_component_name = AppComponentName::from(component_name.0.as_str());
+            if !app_ctx.application.contains_component(&app_component_name) {
+                log_error(format!(
+                    "Component {} is not part of the current application, specify a version to compare with",
+                    component_name.0.log_color_highlight()
+                ));
+                bail!(NonSuccessfulExit);
+            }
+            app_ctx
+                .application
+                .component_final_linked_wasm(&app_component_name, self.ctx.build_profile())
+        };
+
+        ensure_linked_wasm_exists(&component_name.0, &linked_wasm_path)?;
+
+        let metadata = ComponentMetadata::analyse_component(&fs::read(&linked_wasm_path)?)
+            .map_err(|err| {
+                anyhow!(
+                    "Failed to analyse component {}: {}",
+                    linked_wasm_path.display(),
+                    err
+                )
+            })?;
+
+        Ok(metadata.exports)
+    }
+
     async fn cmd_update_workers(
         &self,
         component_name: Option<ComponentName>,
         update_mode: WorkerUpdateMode,
+        check_compatibility: bool,
     ) -> anyhow::Result<()> {
         let components = self
             .components_for_update_or_redeploy(component_name)
             .await?;
-        self.update_workers_by_components(components, update_mode)
+        self.update_workers_by_components(components, update_mode, check_compatibility)
             .await?;
 
         Ok(())
@@ -621,8 +1243,12 @@ impl ComponentCommandHandler {
         };
 
         if let Some(update) = update_or_redeploy.update_workers {
-            self.update_workers_by_components(components, update)
-                .await?;
+            self.update_workers_by_components(
+                components,
+                update,
+                update_or_redeploy.check_compatibility,
+            )
+            .await?;
         } else if update_or_redeploy.redeploy_workers {
             self.redeploy_workers_by_components(components).await?;
         }
@@ -636,25 +1262,71 @@ impl ComponentCommandHandler {
         project: Option<&ProjectNameAndId>,
         component_name: &AppComponentName,
     ) -> anyhow::Result<Component> {
-        let component_id = self
-            .component_id_by_name(project, &component_name.as_str().into())
+        let server_component_name: ComponentName = component_name.as_str().into();
+        let deployed_component = self
+            .component(project, (&server_component_name).into(), None)
             .await?;
-        let deploy_properties = {
+        let component_id = deployed_component
+            .as_ref()
+            .map(|c| ComponentId(c.versioned_component_id.component_id));
+        let (deploy_properties, file_keys_file) = {
             let mut app_ctx = self.ctx.app_context_lock_mut().await;
             let app_ctx = app_ctx.some_or_err_mut()?;
-            component_deploy_properties(app_ctx, component_name, build_profile)?
+            (
+                component_deploy_properties(app_ctx, component_name, build_profile)?,
+                app_ctx.application.component_file_keys_file(),
+            )
+        };
+        let mut file_keys = if file_keys_file.exists() {
+            serde_json::from_str::<ComponentFileKeys>(&fs::read_to_string(&file_keys_file)?)
+                .unwrap_or_default()
+        } else {
+            ComponentFileKeys::default()
         };
 
-        let ifs_files = {
-            if !deploy_properties.files.is_empty() {
+        let (ifs_files, local_file_hashes) = {
+            let ifs_archive_builder =
+                IfsArchiveBuilder::new(self.ctx.file_download_client().await?);
+            let loaded_files = ifs_archive_builder
+                .load_files(deploy_properties.files, &deploy_properties.file_variables)
+                .await?;
+            let local_file_hashes = loaded_files
+                .iter()
+                .map(LoadedFile::file_hash)
+                .filter_map(|file| Some((file.path, file.hash?)))
+                .collect::<BTreeMap<_, _>>();
+
+            // Only the files of the latest version are compared, as updates start from that one
+            let files_diff = ComponentFilesDiff::new(
+                server_component_name,
+                deployed_component
+                    .iter()
+                    .flat_map(|component| component.files.iter())
+                    .map(|file| file_keys.deployed_file_hash(file)),
+                loaded_files.iter().map(LoadedFile::file_hash),
+            );
+
+            let ifs_files = if files_diff.has_changes() {
+                self.ctx.log_handler().log_view(&files_diff);
                 Some(
-                    IfsArchiveBuilder::new(self.ctx.file_download_client().await?)
-                        .build_files_archive(deploy_properties.files)
+                    ifs_archive_builder
+                        .build_files_archive(loaded_files)
                         .await?,
                 )
             } else {
+                if files_diff.unchanged > 0 {
+                    log_action(
+                        "Skipping",
+                        format!(
+                            "IFS archive upload, {} component file(s) are unchanged",
+                            files_diff.unchanged.to_string().log_color_highlight()
+                        ),
+                    );
+                }
                 None
-            }
+            };
+
+            (ifs_files, local_file_hashes)
         };
         let ifs_properties = ifs_files.as_ref().map(|f| &f.properties);
         let ifs_archive = {
@@ -685,7 +1357,6 @@ impl ComponentCommandHandler {
 
         let component = match &component_id {
             Some(component_id) => {
-                // TODO: use hashes for checking if component files has to be updated?
                 log_action(
                     "Updating",
                     format!(
@@ -787,6 +1458,12 @@ impl ComponentCommandHandler {
                 component
             }
         };
+
+        if ifs_files.is_some() {
+            file_keys.record(&component.files, &local_file_hashes);
+            fs::write_str(&file_keys_file, serde_json::to_string_pretty(&file_keys)?)?;
+        }
+
         Ok(component)
     }
 
@@ -826,6 +1503,7 @@ impl ComponentCommandHandler {
         &self,
         components: Vec<Component>,
         update: WorkerUpdateMode,
+        check_compatibility: bool,
     ) -> anyhow::Result<()> {
         if components.is_empty() {
             return Ok(());
@@ -847,6 +1525,7 @@ impl ComponentCommandHandler {
                     component.versioned_component_id.component_id,
                     update,
                     component.versioned_component_id.version,
+                    check_compatibility,
                 )
                 .await?;
             update_results.extend(result);
@@ -1230,10 +1909,28 @@ impl ComponentCommandHandler {
     }
 }
 
+fn ensure_linked_wasm_exists(component_name: &str, linked_wasm_path: &Path) -> anyhow::Result<()> {
+    if !linked_wasm_path.exists() {
+        log_error(format!(
+            "Component {} is not built yet, missing: {}",
+            component_name.log_color_highlight(),
+            linked_wasm_path.log_color_highlight()
+        ));
+        logln("");
+        logln(format!(
+            "Build the component with {}",
+            "component build".log_color_highlight()
+        ));
+        bail!(NonSuccessfulExit);
+    }
+    Ok(())
+}
+
 struct ComponentDeployProperties {
     component_type: ComponentType,
     linked_wasm_path: PathBuf,
     files: Vec<InitialComponentFile>,
+    file_variables: HashMap<String, String>,
     dynamic_linking: Option<DynamicLinkingOss>,
 }
 
@@ -1253,12 +1950,20 @@ fn component_deploy_properties(
         .as_deployable_component_type()
         .ok_or_else(|| anyhow!("Component {component_name} is not deployable"))?;
     let files = component_properties.files.clone();
+    // Same names as in the manifest templates, unless overridden by the component variables
+    let mut file_variables = component_properties.variables.clone();
+    for name in ["componentName", "component_name"] {
+        file_variables
+            .entry(name.to_string())
+            .or_insert_with(|| component_name.to_string());
+    }
     let dynamic_linking = app_component_dynamic_linking(app_ctx, component_name)?;
 
     Ok(ComponentDeployProperties {
         component_type,
         linked_wasm_path,
         files,
+        file_variables,
         dynamic_linking,
     })
 }
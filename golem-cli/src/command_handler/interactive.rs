@@ -20,7 +20,7 @@ use crate::context::Context;
 use crate::error::NonSuccessfulExit;
 use crate::log::{log_warn_action, LogColorize};
 use crate::model::text::fmt::log_warn;
-use crate::model::{ComponentName, Format, WorkerName};
+use crate::model::{ComponentName, Format, WorkerName, WorkerRevertPreviewView};
 use anyhow::{anyhow, bail};
 use colored::Colorize;
 use golem_cloud_client::model::Account;
@@ -66,6 +66,20 @@ impl InteractiveHandler {
         )
     }
 
+    pub fn confirm_revert_worker(&self, preview: &WorkerRevertPreviewView) -> anyhow::Result<bool> {
+        self.confirm(
+            false,
+            format!(
+                "Reverting worker {}/{} will {} {} oplog entries and {} invocation(s), do you want to continue?",
+                preview.component_name.0.log_color_highlight(),
+                preview.worker_name.0.log_color_highlight(),
+                "undo".log_color_warn(),
+                preview.entries.len().to_string().log_color_highlight(),
+                preview.invocations.len().to_string().log_color_highlight()
+            ),
+        )
+    }
+
     pub fn confirm_update_to_latest(
         &self,
         component_name: &ComponentName,
@@ -152,14 +166,20 @@ impl InteractiveHandler {
                 url: component_service_url,
                 worker_url: worker_service_url,
                 allow_insecure: false,
-                config: ProfileConfig { default_format },
+                config: ProfileConfig {
+                    default_format,
+                    ..ProfileConfig::default()
+                },
             }),
             ProfileKind::Cloud => Profile::GolemCloud(CloudProfile {
                 custom_url: Some(component_service_url),
                 custom_cloud_url: cloud_service_url,
                 custom_worker_url: worker_service_url,
                 allow_insecure: false,
-                config: ProfileConfig { default_format },
+                config: ProfileConfig {
+                    default_format,
+                    ..ProfileConfig::default()
+                },
                 auth: None,
             }),
         };
@@ -34,6 +34,7 @@ use crate::command_handler::cloud::project::policy::CloudProjectPolicyCommandHan
 use crate::command_handler::cloud::project::CloudProjectCommandHandler;
 use crate::command_handler::cloud::token::CloudTokenCommandHandler;
 use crate::command_handler::cloud::CloudCommandHandler;
+use crate::command_handler::completion::CompletionCommandHandler;
 use crate::command_handler::component::plugin::ComponentPluginCommandHandler;
 use crate::command_handler::component::ComponentCommandHandler;
 use crate::command_handler::interactive::InteractiveHandler;
@@ -43,16 +44,18 @@ use crate::command_handler::plugin::PluginCommandHandler;
 use crate::command_handler::profile::config::ProfileConfigCommandHandler;
 use crate::command_handler::profile::ProfileCommandHandler;
 use crate::command_handler::rib_repl::RibReplHandler;
+use crate::command_handler::worker::files::WorkerFilesCommandHandler;
+use crate::command_handler::worker::scheduled::WorkerScheduledCommandHandler;
 use crate::command_handler::worker::WorkerCommandHandler;
 use crate::config::{Config, ProfileName};
 use crate::context::Context;
-use crate::error::{ContextInitHintError, HintError, NonSuccessfulExit};
+use crate::error::{
+    ContextInitHintError, HintError, InvokedFunctionReturnedErr, NonSuccessfulExit,
+};
+use crate::init_tracing;
 use crate::log::{logln, set_log_output, Output};
 use crate::model::text::fmt::log_error;
-use crate::{command_name, init_tracing};
 use anyhow::anyhow;
-use clap::CommandFactory;
-use clap_complete::Shell;
 #[cfg(feature = "server-commands")]
 use clap_verbosity_flag::Verbosity;
 use std::ffi::OsString;
@@ -63,6 +66,7 @@ use tracing::{debug, Level};
 mod api;
 mod app;
 mod cloud;
+mod completion;
 mod component;
 mod interactive;
 mod log;
@@ -235,24 +239,7 @@ impl<Hooks: CommandHandlerHooks> CommandHandler<Hooks> {
             }
         };
 
-        result.unwrap_or_else(|error| {
-            if error.downcast_ref::<NonSuccessfulExit>().is_some() {
-                // NOP
-            } else if error
-                .downcast_ref::<Arc<anyhow::Error>>()
-                .and_then(|err| err.downcast_ref::<AppValidationError>())
-                .is_some()
-            {
-                // App validation errors are already formatted and usually contain multiple
-                // errors (and warns)
-                logln("");
-                logln(format!("{:#}", error));
-            } else {
-                logln("");
-                log_error(format!("{:#}", error));
-            }
-            ExitCode::FAILURE
-        })
+        result.unwrap_or_else(error_exit_code)
     }
 
     async fn handle_command(&mut self, command: GolemCliCommand) -> anyhow::Result<()> {
@@ -296,17 +283,14 @@ impl<Hooks: CommandHandlerHooks> CommandHandler<Hooks> {
                     .cmd_repl(component_name.component_name, version)
                     .await
             }
-            GolemCliSubcommand::Completion { shell } => self.cmd_completion(shell),
+            GolemCliSubcommand::Completion { shell } => {
+                self.ctx.completion_handler().cmd_completion(shell)
+            }
+            GolemCliSubcommand::Complete { words } => {
+                self.ctx.completion_handler().cmd_complete(words).await
+            }
         }
     }
-
-    fn cmd_completion(&self, shell: Shell) -> anyhow::Result<()> {
-        let mut command = GolemCliCommand::command();
-        let command_name = command_name();
-        debug!(command_name, shell=%shell, "completion");
-        clap_complete::generate(shell, &mut command, command_name, &mut std::io::stdout());
-        Ok(())
-    }
 }
 
 // NOTE: for now every handler can access any other handler, but this can be restricted
@@ -328,6 +312,7 @@ pub trait Handlers {
     fn cloud_project_plugin_handler(&self) -> CloudProjectPluginCommandHandler;
     fn cloud_project_policy_handler(&self) -> CloudProjectPolicyCommandHandler;
     fn cloud_token_handler(&self) -> CloudTokenCommandHandler;
+    fn completion_handler(&self) -> CompletionCommandHandler;
     fn component_handler(&self) -> ComponentCommandHandler;
     fn component_plugin_handler(&self) -> ComponentPluginCommandHandler;
     fn error_handler(&self) -> ErrorHandler;
@@ -337,7 +322,9 @@ pub trait Handlers {
     fn profile_config_handler(&self) -> ProfileConfigCommandHandler;
     fn profile_handler(&self) -> ProfileCommandHandler;
     fn rib_repl_handler(&self) -> RibReplHandler;
+    fn worker_files_handler(&self) -> WorkerFilesCommandHandler;
     fn worker_handler(&self) -> WorkerCommandHandler;
+    fn worker_scheduled_handler(&self) -> WorkerScheduledCommandHandler;
 }
 
 impl Handlers for Arc<Context> {
@@ -401,6 +388,10 @@ impl Handlers for Arc<Context> {
         CloudTokenCommandHandler::new(self.clone())
     }
 
+    fn completion_handler(&self) -> CompletionCommandHandler {
+        CompletionCommandHandler::new(self.clone())
+    }
+
     fn component_handler(&self) -> ComponentCommandHandler {
         ComponentCommandHandler::new(self.clone())
     }
@@ -437,9 +428,17 @@ impl Handlers for Arc<Context> {
         RibReplHandler::new(self.clone())
     }
 
+    fn worker_files_handler(&self) -> WorkerFilesCommandHandler {
+        WorkerFilesCommandHandler::new(self.clone())
+    }
+
     fn worker_handler(&self) -> WorkerCommandHandler {
         WorkerCommandHandler::new(self.clone())
     }
+
+    fn worker_scheduled_handler(&self) -> WorkerScheduledCommandHandler {
+        WorkerScheduledCommandHandler::new(self.clone())
+    }
 }
 
 fn clamp_exit_code(exit_code: i32) -> ExitCode {
@@ -452,6 +451,30 @@ fn clamp_exit_code(exit_code: i32) -> ExitCode {
     }
 }
 
+/// Logs the error (unless it was already reported) and returns the exit code for it
+fn error_exit_code(error: anyhow::Error) -> ExitCode {
+    if error.downcast_ref::<InvokedFunctionReturnedErr>().is_some() {
+        return ExitCode::from(InvokedFunctionReturnedErr::EXIT_CODE);
+    }
+
+    if error.downcast_ref::<NonSuccessfulExit>().is_some() {
+        // NOP
+    } else if error
+        .downcast_ref::<Arc<anyhow::Error>>()
+        .and_then(|err| err.downcast_ref::<AppValidationError>())
+        .is_some()
+    {
+        // App validation errors are already formatted and usually contain multiple
+        // errors (and warns)
+        logln("");
+        logln(format!("{:#}", error));
+    } else {
+        logln("");
+        log_error(format!("{:#}", error));
+    }
+    ExitCode::FAILURE
+}
+
 fn debug_log_parse_error(error: &clap::Error, fallback_command: &GolemCliFallbackCommand) {
     debug!(fallback_command = ?fallback_command, "Fallback command");
     debug!(error = ?error, "Clap error");
@@ -461,3 +484,31 @@ fn debug_log_parse_error(error: &clap::Error, fallback_command: &GolemCliFallbac
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::command_handler::error_exit_code;
+    use crate::error::{InvokedFunctionReturnedErr, NonSuccessfulExit};
+    use anyhow::{anyhow, Context};
+    use assert2::check;
+    use std::process::ExitCode;
+    use test_r::test;
+
+    #[test]
+    fn invoked_function_returned_err_exits_with_dedicated_code() {
+        check!(error_exit_code(anyhow!(InvokedFunctionReturnedErr)) == ExitCode::from(3));
+        check!(
+            error_exit_code(
+                Err::<(), _>(anyhow!(InvokedFunctionReturnedErr))
+                    .context("invoke")
+                    .unwrap_err()
+            ) == ExitCode::from(3)
+        );
+    }
+
+    #[test]
+    fn other_errors_exit_with_failure() {
+        check!(error_exit_code(anyhow!(NonSuccessfulExit)) == ExitCode::FAILURE);
+        check!(error_exit_code(anyhow!("failed")) == ExitCode::FAILURE);
+    }
+}
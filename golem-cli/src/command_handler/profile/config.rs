@@ -40,6 +40,25 @@ impl ProfileConfigCommandHandler {
             ProfileConfigSubcommand::SetFormat { format } => {
                 self.cmd_set_format(profile_name, format)
             }
+            ProfileConfigSubcommand::SetStream {
+                ping_interval_ms,
+                reconnect_initial_delay_ms,
+                reconnect_max_delay_ms,
+                reconnect_max_attempts,
+                reconnect_jitter,
+                idle_timeout_ms,
+            } => self.cmd_set_stream(
+                profile_name,
+                ping_interval_ms,
+                reconnect_initial_delay_ms,
+                reconnect_max_delay_ms,
+                reconnect_max_attempts,
+                reconnect_jitter,
+                idle_timeout_ms,
+            ),
+            ProfileConfigSubcommand::SetInvocationJournal { enabled } => {
+                self.cmd_set_invocation_journal(profile_name, enabled)
+            }
         }
     }
 
@@ -67,4 +86,82 @@ impl ProfileConfigCommandHandler {
             }
         }
     }
+
+    fn cmd_set_stream(
+        &mut self,
+        profile_name: ProfileName,
+        ping_interval_ms: Option<u64>,
+        reconnect_initial_delay_ms: Option<u64>,
+        reconnect_max_delay_ms: Option<u64>,
+        reconnect_max_attempts: Option<u32>,
+        reconnect_jitter: Option<bool>,
+        idle_timeout_ms: Option<u64>,
+    ) -> anyhow::Result<()> {
+        match Config::get_profile(&profile_name, self.ctx.config_dir())? {
+            Some(mut profile) => {
+                let stream = &mut profile.get_config_mut().stream;
+                if let Some(ping_interval_ms) = ping_interval_ms {
+                    stream.ping_interval_ms = ping_interval_ms;
+                }
+                if let Some(reconnect_initial_delay_ms) = reconnect_initial_delay_ms {
+                    stream.reconnect_initial_delay_ms = reconnect_initial_delay_ms;
+                }
+                if let Some(reconnect_max_delay_ms) = reconnect_max_delay_ms {
+                    stream.reconnect_max_delay_ms = reconnect_max_delay_ms;
+                }
+                if let Some(reconnect_max_attempts) = reconnect_max_attempts {
+                    stream.reconnect_max_attempts =
+                        (reconnect_max_attempts > 0).then_some(reconnect_max_attempts);
+                }
+                if let Some(reconnect_jitter) = reconnect_jitter {
+                    stream.reconnect_jitter = reconnect_jitter;
+                }
+                if let Some(idle_timeout_ms) = idle_timeout_ms {
+                    stream.idle_timeout_ms = (idle_timeout_ms > 0).then_some(idle_timeout_ms);
+                }
+
+                log_action(
+                    "Updating",
+                    format!("profile's stream settings for {}", &profile_name),
+                );
+                Config::set_profile(profile_name, profile, self.ctx.config_dir())?;
+                log_action("Updated", "");
+
+                Ok(())
+            }
+            None => {
+                log_error(format!("Profile {} not found", profile_name));
+                bail!(NonSuccessfulExit);
+            }
+        }
+    }
+
+    fn cmd_set_invocation_journal(
+        &mut self,
+        profile_name: ProfileName,
+        enabled: bool,
+    ) -> anyhow::Result<()> {
+        match Config::get_profile(&profile_name, self.ctx.config_dir())? {
+            Some(mut profile) => {
+                profile.get_config_mut().invocation_journal = enabled;
+
+                log_action(
+                    "Updating",
+                    format!(
+                        "profile's invocation journal for {} to {}",
+                        &profile_name,
+                        if enabled { "enabled" } else { "disabled" }
+                    ),
+                );
+                Config::set_profile(profile_name, profile, self.ctx.config_dir())?;
+                log_action("Updated", "");
+
+                Ok(())
+            }
+            None => {
+                log_error(format!("Profile {} not found", profile_name));
+                bail!(NonSuccessfulExit);
+            }
+        }
+    }
 }
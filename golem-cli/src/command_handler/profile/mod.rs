@@ -103,7 +103,10 @@ impl ProfileCommandHandler {
                         url: component_url.unwrap_or(Url::parse(DEFAULT_OSS_URL)?),
                         worker_url,
                         allow_insecure,
-                        config: ProfileConfig { default_format },
+                        config: ProfileConfig {
+                            default_format,
+                            ..ProfileConfig::default()
+                        },
                     }),
                     ProfileKind::Cloud => Profile::GolemCloud(CloudProfile {
                         custom_url: component_url,
@@ -0,0 +1,345 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::command::shared_args::WorkerNameArg;
+use crate::command::worker::files::WorkerFilesSubcommand;
+use crate::command_handler::Handlers;
+use crate::context::{Context, GolemClients};
+use crate::error::service::AnyhowMapServiceError;
+use crate::error::NonSuccessfulExit;
+use crate::fs;
+use crate::log::{log_action, LogColorize, LogIndent};
+use crate::model::text::fmt::{format_worker_name_match, log_error};
+use crate::model::{WorkerFileView, WorkerName};
+use anyhow::bail;
+use bytes::Bytes;
+use golem_client::api::WorkerClient as WorkerClientOss;
+use golem_cloud_client::api::WorkerClient as WorkerClientCloud;
+use std::io::Write;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct WorkerFilesCommandHandler {
+    ctx: Arc<Context>,
+}
+
+impl WorkerFilesCommandHandler {
+    pub fn new(ctx: Arc<Context>) -> Self {
+        Self { ctx }
+    }
+
+    pub async fn handle_command(&self, subcommand: WorkerFilesSubcommand) -> anyhow::Result<()> {
+        match subcommand {
+            WorkerFilesSubcommand::Ls { worker_name, path } => self.cmd_ls(worker_name, path).await,
+            WorkerFilesSubcommand::Cat { worker_name, path } => {
+                self.cmd_cat(worker_name, path).await
+            }
+            WorkerFilesSubcommand::Download {
+                worker_name,
+                path,
+                output,
+            } => self.cmd_download(worker_name, path, output).await,
+        }
+    }
+
+    async fn cmd_ls(&self, worker_name: WorkerNameArg, path: String) -> anyhow::Result<()> {
+        let (component_id, worker_name) = self.resolve_worker(worker_name, false).await?;
+
+        let mut files = self.list_files(component_id, &worker_name, &path).await?;
+        files.sort_by(|a, b| {
+            b.is_directory
+                .cmp(&a.is_directory)
+                .then_with(|| a.name.cmp(&b.name))
+        });
+
+        self.ctx.log_handler().log_view(&files);
+
+        Ok(())
+    }
+
+    async fn cmd_cat(&self, worker_name: WorkerNameArg, path: String) -> anyhow::Result<()> {
+        let (component_id, worker_name) = self.resolve_worker(worker_name, true).await?;
+
+        let content = self.file_content(component_id, &worker_name, &path).await?;
+
+        let mut stdout = std::io::stdout().lock();
+        stdout.write_all(&content)?;
+        stdout.flush()?;
+
+        Ok(())
+    }
+
+    async fn cmd_download(
+        &self,
+        worker_name: WorkerNameArg,
+        path: String,
+        output: PathBuf,
+    ) -> anyhow::Result<()> {
+        let (component_id, worker_name) = self.resolve_worker(worker_name, false).await?;
+
+        let Some(remote_path) = normalize_path(&path) else {
+            log_error(format!(
+                "Invalid path, it points outside of the worker's file system: {}",
+                path.log_color_highlight()
+            ));
+            bail!(NonSuccessfulExit);
+        };
+        let name = remote_path
+            .rsplit('/')
+            .next()
+            .filter(|name| !name.is_empty())
+            .map(|name| name.to_string());
+
+        let is_directory = match &name {
+            // Only the root has no name, and that is always a directory
+            None => true,
+            Some(name) => {
+                let parent = parent_path(&remote_path);
+                let siblings = self.list_files(component_id, &worker_name, &parent).await?;
+                match siblings.into_iter().find(|file| &file.name == name) {
+                    Some(file) => file.is_directory,
+                    None => {
+                        log_error(format!(
+                            "File not found: {}",
+                            remote_path.log_color_highlight()
+                        ));
+                        bail!(NonSuccessfulExit);
+                    }
+                }
+            }
+        };
+
+        let target = match &name {
+            Some(name) => output.join(name),
+            None => output,
+        };
+
+        log_action(
+            "Downloading",
+            format!(
+                "{} to {}",
+                remote_path.log_color_highlight(),
+                target.log_color_highlight()
+            ),
+        );
+        let _indent = LogIndent::new();
+
+        if is_directory {
+            let mut pending = vec![(remote_path, target)];
+            while let Some((remote_dir, local_dir)) = pending.pop() {
+                fs::create_dir_all(&local_dir)?;
+                for file in self
+                    .list_files(component_id, &worker_name, &remote_dir)
+                    .await?
+                {
+                    // File names are coming from the server, and are only used as a single
+                    // path component, so they cannot point outside of the output directory
+                    if !is_plain_file_name(&file.name) {
+                        log_error(format!(
+                            "Invalid file name in {}: {}",
+                            remote_dir.log_color_highlight(),
+                            file.name.log_color_error_highlight()
+                        ));
+                        bail!(NonSuccessfulExit);
+                    }
+                    let remote_path = join_path(&remote_dir, &file.name);
+                    let local_path = local_dir.join(&file.name);
+                    if file.is_directory {
+                        pending.push((remote_path, local_path));
+                    } else {
+                        self.download_file(component_id, &worker_name, &remote_path, &local_path)
+                            .await?;
+                    }
+                }
+            }
+        } else {
+            self.download_file(component_id, &worker_name, &remote_path, &target)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn resolve_worker(
+        &self,
+        worker_name: WorkerNameArg,
+        quiet: bool,
+    ) -> anyhow::Result<(Uuid, WorkerName)> {
+        self.ctx.silence_app_context_init().await;
+
+        let mut worker_handler = self.ctx.worker_handler();
+        let worker_name_match = worker_handler
+            .match_worker_name(worker_name.worker_name)
+            .await?;
+        let (component, worker_name) = worker_handler
+            .component_by_worker_name_match(&worker_name_match)
+            .await?;
+
+        // cat output is used as raw content, so it should not be mixed with log lines
+        if !quiet {
+            log_action(
+                "Using",
+                format!("worker {}", format_worker_name_match(&worker_name_match)),
+            );
+        }
+
+        Ok((component.versioned_component_id.component_id, worker_name))
+    }
+
+    async fn download_file(
+        &self,
+        component_id: Uuid,
+        worker_name: &WorkerName,
+        remote_path: &str,
+        local_path: &Path,
+    ) -> anyhow::Result<()> {
+        let content = self
+            .file_content(component_id, worker_name, remote_path)
+            .await?;
+        fs::write(local_path, &content)?;
+
+        log_action(
+            "Downloaded",
+            format!(
+                "{} ({} bytes)",
+                local_path.log_color_highlight(),
+                content.len()
+            ),
+        );
+
+        Ok(())
+    }
+
+    async fn list_files(
+        &self,
+        component_id: Uuid,
+        worker_name: &WorkerName,
+        path: &str,
+    ) -> anyhow::Result<Vec<WorkerFileView>> {
+        match self.ctx.golem_clients().await? {
+            GolemClients::Oss(clients) => clients
+                .worker
+                .get_files(&component_id, &worker_name.0, path)
+                .await
+                .map(|result| {
+                    result
+                        .nodes
+                        .into_iter()
+                        .map(WorkerFileView::from_oss)
+                        .collect()
+                })
+                .map_service_error(),
+            GolemClients::Cloud(clients) => clients
+                .worker
+                .get_files(&component_id, &worker_name.0, path)
+                .await
+                .map(|result| {
+                    result
+                        .nodes
+                        .into_iter()
+                        .map(WorkerFileView::from_cloud)
+                        .collect()
+                })
+                .map_service_error(),
+        }
+    }
+
+    async fn file_content(
+        &self,
+        component_id: Uuid,
+        worker_name: &WorkerName,
+        path: &str,
+    ) -> anyhow::Result<Bytes> {
+        match self.ctx.golem_clients().await? {
+            GolemClients::Oss(clients) => clients
+                .worker
+                .get_file_content(&component_id, &worker_name.0, path)
+                .await
+                .map_service_error(),
+            GolemClients::Cloud(clients) => clients
+                .worker
+                .get_file_content(&component_id, &worker_name.0, path)
+                .await
+                .map_service_error(),
+        }
+    }
+}
+
+/// Converts the path to an absolute path without "." and ".." segments,
+/// returns None if it would point above the root
+fn normalize_path(path: &str) -> Option<String> {
+    let mut segments = Vec::new();
+    for segment in path.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop()?;
+            }
+            segment => segments.push(segment),
+        }
+    }
+    Some(format!("/{}", segments.join("/")))
+}
+
+fn is_plain_file_name(name: &str) -> bool {
+    let mut components = Path::new(name).components();
+    matches!(
+        (components.next(), components.next()),
+        (Some(Component::Normal(component)), None) if component == name
+    )
+}
+
+fn parent_path(path: &str) -> String {
+    match path.rsplit_once('/') {
+        Some((parent, _)) if !parent.is_empty() => parent.to_string(),
+        _ => "/".to_string(),
+    }
+}
+
+fn join_path(dir: &str, name: &str) -> String {
+    format!("{}/{}", dir.trim_end_matches('/'), name)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::command_handler::worker::files::{is_plain_file_name, normalize_path};
+    use assert2::check;
+    use test_r::test;
+
+    #[test]
+    fn normalize_paths() {
+        check!(normalize_path("").as_deref() == Some("/"));
+        check!(normalize_path("/").as_deref() == Some("/"));
+        check!(normalize_path("a/b/").as_deref() == Some("/a/b"));
+        check!(normalize_path("//a/./b//c").as_deref() == Some("/a/b/c"));
+        check!(normalize_path("/a/b/../c").as_deref() == Some("/a/c"));
+        check!(normalize_path("/a/..").as_deref() == Some("/"));
+        check!(normalize_path("/..").is_none());
+        check!(normalize_path("a/../../b").is_none());
+    }
+
+    #[test]
+    fn plain_file_names() {
+        check!(is_plain_file_name("file.txt"));
+        check!(is_plain_file_name(".hidden"));
+        check!(!is_plain_file_name(""));
+        check!(!is_plain_file_name("."));
+        check!(!is_plain_file_name(".."));
+        check!(!is_plain_file_name("../x"));
+        check!(!is_plain_file_name("/etc/x"));
+        check!(!is_plain_file_name("a/b"));
+        check!(!is_plain_file_name("x/"));
+    }
+}
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod files;
+pub mod scheduled;
 mod stream;
 mod stream_output;
 
@@ -21,18 +23,32 @@ use crate::command::shared_args::{
 };
 use crate::command::worker::WorkerSubcommand;
 use crate::command_handler::worker::stream::WorkerConnection;
+use crate::command_handler::worker::stream_output::StreamOutputFile;
 use crate::command_handler::Handlers;
 use crate::context::{Context, GolemClients};
 use crate::error::service::{AnyhowMapServiceError, ServiceError};
-use crate::error::NonSuccessfulExit;
+use crate::error::{InvokedFunctionReturnedErr, NonSuccessfulExit};
+use crate::fs;
 use crate::fuzzy::{Error, FuzzySearch};
-use crate::log::{log_action, log_error_action, log_warn_action, logln, LogColorize, LogIndent};
+use crate::log::{
+    log_action, log_clear_screen, log_error_action, log_warn_action, logln, set_log_output,
+    LogColorize, LogIndent, Output,
+};
 use crate::model::app::ApplicationComponentSelectMode;
 use crate::model::component::{
-    function_params_types, show_exported_functions, AppComponentType, Component,
+    function_params_types, show_exported_functions, AppComponentType, Component, ComponentSelection,
+};
+use crate::model::component_diff::ComponentDiffView;
+use crate::model::deploy::{
+    TryUpdateAllWorkersResult, WorkerUpdateAttempt, WorkerUpdatePlan, WorkerUpdatePlanAction,
+    WorkerUpdatePlanEntry, LOAD_SNAPSHOT_INTERFACE, SAVE_SNAPSHOT_INTERFACE,
 };
-use crate::model::deploy::{TryUpdateAllWorkersResult, WorkerUpdateAttempt};
-use crate::model::invoke_result_view::InvokeResultView;
+use crate::model::invocation_journal::{
+    InvocationJournal, InvocationJournalEntry, InvocationJournalOutcome,
+};
+use crate::model::invoke_request::InvokeHttpRequest;
+use crate::model::invoke_result_view::{InvocationMetricsView, InvokeResultView};
+use crate::model::oplog_diff::{OplogIndexRange, WorkerOplogDiffView};
 use crate::model::text::fmt::{
     format_export, format_worker_name_match, log_error, log_fuzzy_match, log_text_view, log_warn,
 };
@@ -40,14 +56,19 @@ use crate::model::text::help::{
     ArgumentError, AvailableComponentNamesHelp, AvailableFunctionNamesHelp, ComponentNameHelp,
     ParameterErrorTableView, WorkerNameHelp,
 };
-use crate::model::text::worker::{WorkerCreateView, WorkerGetView};
+use crate::model::text::worker::{
+    WorkerCreateFailureView, WorkerCreateMultipleView, WorkerCreateView, WorkerGetView,
+};
 use crate::model::to_oss::ToOss;
-use crate::model::worker::fuzzy_match_function_name;
+use crate::model::worker::{fuzzy_match_function_name, parse_new_workers_csv, NewWorkerSpec};
 use crate::model::{
-    ComponentName, ComponentNameMatchKind, IdempotencyKey, ProjectName, WorkerMetadata,
-    WorkerMetadataView, WorkerName, WorkerNameMatch, WorkerUpdateMode, WorkersMetadataResponseView,
+    ComponentName, ComponentNameMatchKind, Format, IdempotencyKey, InvokeRequestFormat,
+    InvokeResultFormat, ProjectName, WorkerBenchView, WorkerConnectOptions, WorkerDescribeView,
+    WorkerInvocationView, WorkerMetadata, WorkerMetadataView, WorkerName, WorkerNameMatch,
+    WorkerRevertPreviewView, WorkerUpdateMode, WorkersMetadataResponseView, WorkersWatchView,
 };
 use anyhow::{anyhow, bail};
+use chrono::{DateTime, Utc};
 use colored::Colorize;
 use golem_client::api::{ComponentClient as ComponentClientOss, WorkerClient as WorkerClientOss};
 use golem_client::model::{
@@ -70,12 +91,22 @@ use golem_common::model::public_oplog::OplogCursor;
 use golem_wasm_rpc::json::OptionallyTypeAnnotatedValueJson;
 use golem_wasm_rpc::parse_type_annotated_value;
 use itertools::{EitherOrBoth, Itertools};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::path::PathBuf;
 use std::sync::Arc;
-use std::time::Duration;
-use tokio::time::timeout;
+use std::time::{Duration, Instant};
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+use tokio::time::{timeout, MissedTickBehavior};
+use tracing::debug;
 use uuid::Uuid;
 
+const DEFAULT_NEW_WORKERS_CONCURRENCY: u64 = 10;
+
+// Manual updates migrate the worker state by saving a snapshot with the source version,
+// and loading it with the target version
+
 pub struct WorkerCommandHandler {
     ctx: Arc<Context>,
 }
@@ -91,7 +122,43 @@ impl WorkerCommandHandler {
                 worker_name,
                 arguments,
                 env,
-            } => self.cmd_new(worker_name, arguments, env).await,
+                component,
+                from_csv,
+                name_pattern,
+                count,
+                pattern_arguments,
+                concurrency,
+            } => match (worker_name, from_csv, name_pattern) {
+                (_, Some(csv_path), _) => {
+                    self.cmd_new_multiple(
+                        component,
+                        NewWorkersSource::Csv(csv_path),
+                        env,
+                        concurrency.unwrap_or(DEFAULT_NEW_WORKERS_CONCURRENCY),
+                    )
+                    .await
+                }
+                (_, None, Some(name_pattern)) => {
+                    self.cmd_new_multiple(
+                        component,
+                        NewWorkersSource::NamePattern {
+                            name_pattern,
+                            count: count.unwrap_or(1),
+                            arguments: pattern_arguments,
+                        },
+                        env,
+                        concurrency.unwrap_or(DEFAULT_NEW_WORKERS_CONCURRENCY),
+                    )
+                    .await
+                }
+                (Some(worker_name), None, None) => {
+                    self.cmd_new(WorkerNameArg { worker_name }, arguments, env)
+                        .await
+                }
+                (None, None, None) => {
+                    unreachable!("the worker name is required without --from-csv or --name-pattern")
+                }
+            },
             WorkerSubcommand::Invoke {
                 worker_name,
                 function_name,
@@ -100,6 +167,11 @@ impl WorkerCommandHandler {
                 idempotency_key,
                 stream,
                 stream_args,
+                at,
+                after,
+                result_format,
+                print_request,
+                metrics,
             } => {
                 self.cmd_invoke(
                     worker_name,
@@ -107,12 +179,42 @@ impl WorkerCommandHandler {
                     arguments,
                     enqueue,
                     idempotency_key,
-                    stream,
-                    stream_args,
+                    stream.then_some(stream_args),
+                    at,
+                    after,
+                    result_format,
+                    print_request,
+                    metrics,
+                )
+                .await
+            }
+            WorkerSubcommand::Bench {
+                component_name,
+                function_name,
+                arguments,
+                workers,
+                worker_name_pattern,
+                rate,
+                concurrency,
+                duration,
+            } => {
+                self.cmd_bench(
+                    component_name,
+                    &function_name,
+                    arguments,
+                    workers,
+                    worker_name_pattern,
+                    rate,
+                    concurrency,
+                    duration,
                 )
                 .await
             }
             WorkerSubcommand::Get { worker_name } => self.cmd_get(worker_name).await,
+            WorkerSubcommand::Describe {
+                worker_name,
+                invocations,
+            } => self.cmd_describe(worker_name, invocations).await,
             WorkerSubcommand::Delete { worker_name } => self.cmd_delete(worker_name).await,
             WorkerSubcommand::List {
                 component_name,
@@ -120,20 +222,38 @@ impl WorkerCommandHandler {
                 scan_cursor,
                 max_count,
                 precise,
+                watch,
+                interval,
             } => {
-                self.cmd_list(
-                    component_name.component_name,
-                    filters,
-                    scan_cursor,
-                    max_count,
-                    precise,
-                )
-                .await
+                if watch {
+                    self.cmd_list_watch(
+                        component_name.component_name,
+                        filters,
+                        max_count,
+                        precise,
+                        interval,
+                    )
+                    .await
+                } else {
+                    self.cmd_list(
+                        component_name.component_name,
+                        filters,
+                        scan_cursor,
+                        max_count,
+                        precise,
+                    )
+                    .await
+                }
             }
             WorkerSubcommand::Stream {
-                worker_name,
+                worker_names,
+                component,
+                filter: filters,
                 stream_args,
-            } => self.cmd_stream(worker_name, stream_args).await,
+            } => {
+                self.cmd_stream(worker_names, component, filters, stream_args)
+                    .await
+            }
             WorkerSubcommand::Interrupt { worker_name } => self.cmd_interrupt(worker_name).await,
             WorkerSubcommand::Update {
                 worker_name,
@@ -156,13 +276,29 @@ impl WorkerCommandHandler {
                 from,
                 query,
             } => self.cmd_oplog(worker_name, from, query).await,
+            WorkerSubcommand::OplogDiff {
+                worker_name,
+                other_worker_name,
+                range,
+                other_range,
+                full,
+            } => {
+                self.cmd_oplog_diff(worker_name, other_worker_name, range, other_range, full)
+                    .await
+            }
             WorkerSubcommand::Revert {
                 worker_name,
                 last_oplog_index,
                 number_of_invocations,
+                preview,
             } => {
-                self.cmd_revert(worker_name, last_oplog_index, number_of_invocations)
-                    .await
+                self.cmd_revert(
+                    worker_name,
+                    last_oplog_index,
+                    number_of_invocations,
+                    preview,
+                )
+                .await
             }
             WorkerSubcommand::CancelInvocation {
                 worker_name,
@@ -171,6 +307,24 @@ impl WorkerCommandHandler {
                 self.cmd_cancel_invocation(worker_name, idempotency_key)
                     .await
             }
+            WorkerSubcommand::Files { subcommand } => {
+                self.ctx
+                    .worker_files_handler()
+                    .handle_command(subcommand)
+                    .await
+            }
+            WorkerSubcommand::History {
+                filter,
+                entry,
+                limit,
+            } => self.cmd_history(filter, entry, limit),
+            WorkerSubcommand::Retry { entry } => self.cmd_retry(entry).await,
+            WorkerSubcommand::Scheduled { subcommand } => {
+                self.ctx
+                    .worker_scheduled_handler()
+                    .handle_command(subcommand)
+                    .await
+            }
         }
     }
 
@@ -235,6 +389,197 @@ impl WorkerCommandHandler {
         Ok(())
     }
 
+    async fn cmd_new_multiple(
+        &mut self,
+        component_name: Option<ComponentName>,
+        source: NewWorkersSource,
+        env: Vec<(String, String)>,
+        concurrency: u64,
+    ) -> anyhow::Result<()> {
+        self.ctx.silence_app_context_init().await;
+
+        let specs = match source {
+            NewWorkersSource::Csv(csv_path) => {
+                let specs = parse_new_workers_csv(&fs::read_to_string(&csv_path)?)
+                    .map_err(|err| anyhow!("Failed to parse {}: {}", csv_path.display(), err))?;
+                // Env variables from the command line are shared, the CSV ones override them
+                specs
+                    .into_iter()
+                    .map(|spec| NewWorkerSpec {
+                        env: env.iter().cloned().chain(spec.env).collect(),
+                        ..spec
+                    })
+                    .collect::<Vec<_>>()
+            }
+            NewWorkersSource::NamePattern {
+                name_pattern,
+                count,
+                arguments,
+            } => {
+                if count > 1 && !name_pattern.contains("{n}") {
+                    log_error(format!(
+                        "The worker name pattern ({}) must contain {} when creating multiple workers",
+                        name_pattern.log_color_error_highlight(),
+                        "{n}".log_color_highlight()
+                    ));
+                    bail!(NonSuccessfulExit);
+                }
+                (1..=count)
+                    .map(|n| NewWorkerSpec {
+                        worker_name: name_pattern.replace("{n}", &n.to_string()).into(),
+                        args: arguments.clone(),
+                        env: env.clone(),
+                    })
+                    .collect()
+            }
+        };
+
+        let Some(first_spec) = specs.first() else {
+            log_warn_action("Skipping", "worker creation, no workers were specified");
+            return Ok(());
+        };
+
+        // The component is resolved through the first worker name, the same way as for new
+        let worker_name_match = self
+            .match_worker_name(match &component_name {
+                Some(component_name) => {
+                    format!("{}/{}", component_name.0, first_spec.worker_name.0).into()
+                }
+                None => first_spec.worker_name.clone(),
+            })
+            .await?;
+
+        let component = self
+            .ctx
+            .component_handler()
+            .component_by_name_with_auto_deploy(
+                worker_name_match.project.as_ref(),
+                worker_name_match.component_name_match_kind,
+                &worker_name_match.component_name,
+                worker_name_match.worker_name.as_ref().map(|wn| wn.into()),
+            )
+            .await?;
+
+        if component.component_type == AppComponentType::Ephemeral {
+            log_error("Cannot use explicit names for ephemeral workers!");
+            bail!(NonSuccessfulExit);
+        }
+
+        let existing_worker_names = self
+            .list_component_workers(
+                &worker_name_match.component_name,
+                component.versioned_component_id.component_id,
+                None,
+                None,
+                None,
+                false,
+            )
+            .await?
+            .0
+            .into_iter()
+            .map(|worker| WorkerName(worker.worker_id.worker_name))
+            .collect::<HashSet<_>>();
+
+        let (skipped, specs): (Vec<_>, Vec<_>) = specs
+            .into_iter()
+            .partition(|spec| existing_worker_names.contains(&spec.worker_name));
+
+        log_action(
+            "Creating",
+            format!(
+                "{} new worker(s) for component {} with {} concurrent request(s)",
+                specs.len().to_string().log_color_highlight(),
+                worker_name_match.component_name.0.log_color_highlight(),
+                concurrency.to_string().log_color_highlight()
+            ),
+        );
+        let _indent = LogIndent::new();
+
+        for spec in &skipped {
+            log_warn_action(
+                "Skipping",
+                format!(
+                    "worker {}, it already exists",
+                    spec.worker_name.0.log_color_highlight()
+                ),
+            );
+        }
+
+        let component_id = component.versioned_component_id.component_id;
+        let semaphore = Arc::new(Semaphore::new(concurrency as usize));
+        let mut creations = JoinSet::new();
+        for spec in specs {
+            let ctx = self.ctx.clone();
+            let semaphore = semaphore.clone();
+            creations.spawn(async move {
+                let _permit = semaphore.acquire_owned().await?;
+                let result = ctx
+                    .worker_handler()
+                    .new_worker(
+                        component_id,
+                        spec.worker_name.0.clone(),
+                        spec.args,
+                        spec.env.into_iter().collect(),
+                    )
+                    .await;
+                anyhow::Ok((spec.worker_name, result))
+            });
+        }
+
+        let total = creations.len();
+        let mut created = Vec::with_capacity(total);
+        let mut failed = Vec::new();
+        while let Some(result) = creations.join_next().await {
+            let (worker_name, result) = result??;
+            let progress = format!("({}/{})", created.len() + failed.len() + 1, total);
+            match result {
+                Ok(()) => {
+                    log_action(
+                        "Created",
+                        format!(
+                            "worker {} {}",
+                            worker_name.0.log_color_highlight(),
+                            progress
+                        ),
+                    );
+                    created.push(worker_name);
+                }
+                Err(error) => {
+                    log_error_action(
+                        "Failed",
+                        format!(
+                            "to create worker {} {}: {}",
+                            worker_name.0.log_color_error_highlight(),
+                            progress,
+                            error
+                        ),
+                    );
+                    failed.push(WorkerCreateFailureView {
+                        worker_name,
+                        error: error.to_string(),
+                    });
+                }
+            }
+        }
+        drop(_indent);
+
+        let has_failures = !failed.is_empty();
+
+        logln("");
+        self.ctx.log_handler().log_view(&WorkerCreateMultipleView {
+            component_name: worker_name_match.component_name,
+            created,
+            skipped: skipped.into_iter().map(|spec| spec.worker_name).collect(),
+            failed,
+        });
+
+        if has_failures {
+            bail!(NonSuccessfulExit);
+        }
+
+        Ok(())
+    }
+
     async fn cmd_invoke(
         &mut self,
         worker_name: WorkerNameArg,
@@ -242,11 +587,26 @@ impl WorkerCommandHandler {
         arguments: Vec<WorkerFunctionArgument>,
         enqueue: bool,
         idempotency_key: Option<IdempotencyKey>,
-        stream: bool,
-        stream_args: StreamArgs,
+        stream_args: Option<StreamArgs>,
+        at: Option<DateTime<Utc>>,
+        after: Option<Duration>,
+        result_format: Option<InvokeResultFormat>,
+        print_request: Option<InvokeRequestFormat>,
+        metrics: bool,
     ) -> anyhow::Result<()> {
         self.ctx.silence_app_context_init().await;
 
+        // Keeping the standard output for the result or the request only
+        if result_format.is_some() || print_request.is_some() {
+            set_log_output(Output::Stderr);
+        }
+
+        let scheduled_at = match (at, after) {
+            (Some(at), _) => Some(at),
+            (None, Some(after)) => Some(Utc::now() + chrono::Duration::from_std(after)?),
+            (None, None) => None,
+        };
+
         fn new_idempotency_key() -> IdempotencyKey {
             let key = IdempotencyKey::new();
             log_action(
@@ -273,69 +633,85 @@ impl WorkerCommandHandler {
 
         let worker_name_match = self.match_worker_name(worker_name.worker_name).await?;
 
-        let component = self
-            .ctx
-            .component_handler()
-            .component_by_name_with_auto_deploy(
-                worker_name_match.project.as_ref(),
-                worker_name_match.component_name_match_kind,
-                &worker_name_match.component_name,
-                worker_name_match.worker_name.as_ref().map(|wn| wn.into()),
-            )
-            .await?;
+        let component = if print_request.is_some() {
+            // Printing the request must not build or deploy the component
+            let component = self
+                .ctx
+                .component_handler()
+                .component(
+                    worker_name_match.project.as_ref(),
+                    (&worker_name_match.component_name).into(),
+                    worker_name_match.worker_name.as_ref().map(|wn| wn.into()),
+                )
+                .await?;
+            let Some(component) = component else {
+                log_error(format!(
+                    "Component {} not found, it has to be deployed for printing the request",
+                    worker_name_match
+                        .component_name
+                        .0
+                        .log_color_error_highlight()
+                ));
+                bail!(NonSuccessfulExit);
+            };
+            component
+        } else {
+            self.ctx
+                .component_handler()
+                .component_by_name_with_auto_deploy(
+                    worker_name_match.project.as_ref(),
+                    worker_name_match.component_name_match_kind,
+                    &worker_name_match.component_name,
+                    worker_name_match.worker_name.as_ref().map(|wn| wn.into()),
+                )
+                .await?
+        };
 
-        let matched_function_name =
-            fuzzy_match_function_name(function_name, &component.metadata.exports);
-        let function_name = match matched_function_name {
-            Ok(match_) => {
-                log_fuzzy_match(&match_);
-                match_.option
-            }
-            Err(error) => {
-                let component_functions =
-                    show_exported_functions(&component.metadata.exports, false);
-
-                match error {
-                    Error::Ambiguous {
-                        highlighted_options,
-                        ..
-                    } => {
-                        logln("");
-                        log_error(format!(
-                            "The requested function name ({}) is ambiguous.",
-                            function_name.log_color_error_highlight()
-                        ));
-                        logln("");
-                        logln("Did you mean one of");
-                        for option in highlighted_options {
-                            logln(format!(" - {}", option.bold()));
-                        }
-                        logln("?");
-                        logln("");
-                        log_text_view(&AvailableFunctionNamesHelp {
-                            component_name: worker_name_match.component_name.0,
-                            function_names: component_functions,
-                        });
+        let function_name =
+            match_function_name(&component, &worker_name_match.component_name, function_name)?;
 
-                        bail!(NonSuccessfulExit);
-                    }
-                    Error::NotFound { .. } => {
-                        logln("");
-                        log_error(format!(
-                            "The requested function name ({}) was not found.",
-                            function_name.log_color_error_highlight()
-                        ));
-                        logln("");
-                        log_text_view(&AvailableFunctionNamesHelp {
-                            component_name: worker_name_match.component_name.0,
-                            function_names: component_functions,
-                        });
+        if let Some(scheduled_at) = scheduled_at {
+            let invoke_args =
+                wave_args_to_invoke_args(&component, &function_name, arguments.clone())?;
+            return self
+                .ctx
+                .worker_scheduled_handler()
+                .schedule_invocation(
+                    &component,
+                    &worker_name_match,
+                    function_name,
+                    arguments,
+                    invoke_args,
+                    idempotency_key,
+                    scheduled_at,
+                )
+                .await;
+        }
 
-                        bail!(NonSuccessfulExit);
-                    }
-                }
-            }
-        };
+        if let Some(print_request) = print_request {
+            let invoke_args = wave_args_to_invoke_args(&component, &function_name, arguments)?;
+            let request = InvokeHttpRequest::new(
+                self.ctx.worker_service_url(),
+                component.versioned_component_id.component_id,
+                worker_name_match.worker_name.as_ref(),
+                &function_name,
+                invoke_args,
+                &idempotency_key,
+                enqueue,
+                self.ctx.auth_token().await?,
+            )?;
+            log_action(
+                "Printing",
+                format!(
+                    "request for {} worker {}/{}",
+                    if enqueue { "enqueueing" } else { "invoking" },
+                    format_worker_name_match(&worker_name_match),
+                    format_export(&function_name)
+                ),
+            );
+            println!("{}", request.render(print_request)?);
+            return Ok(());
+        }
 
         if enqueue {
             log_action(
@@ -357,8 +733,32 @@ impl WorkerCommandHandler {
             );
         }
 
+        // Journal failures are only reported as warnings, they must not fail the invocation
+        let journal_entry_id = self
+            .ctx
+            .invocation_journal_enabled()
+            .then(|| {
+                InvocationJournal::start(
+                    self.ctx.config_dir(),
+                    self.ctx.profile_name().clone(),
+                    worker_name_match.qualified_worker_name(),
+                    function_name.clone(),
+                    arguments.clone(),
+                    idempotency_key.clone(),
+                    enqueue,
+                )
+                .inspect_err(|err| {
+                    log_warn(format!(
+                        "Failed to record invocation in the journal: {err:#}"
+                    ))
+                })
+                .ok()
+            })
+            .flatten();
+
         let arguments = wave_args_to_invoke_args(&component, &function_name, arguments)?;
 
+        let started_at = Instant::now();
         let result = self
             .invoke_worker(
                 &component,
@@ -367,62 +767,507 @@ impl WorkerCommandHandler {
                 arguments,
                 idempotency_key.clone(),
                 enqueue,
-                stream.then_some(stream_args),
+                stream_args,
             )
-            .await?;
+            .await;
+        let wall_time = started_at.elapsed();
+
+        if let Some(journal_entry_id) = journal_entry_id {
+            let outcome = match &result {
+                Ok(Some(result)) => InvocationJournalOutcome::Succeeded {
+                    result: InvokeResultView::try_parse_wave(
+                        &result.result,
+                        &component,
+                        &function_name,
+                    )
+                    .ok(),
+                },
+                Ok(None) => InvocationJournalOutcome::Enqueued,
+                Err(error) => InvocationJournalOutcome::Failed {
+                    error: format!("{error:#}"),
+                },
+            };
+            match InvocationJournal::finish(self.ctx.config_dir(), journal_entry_id, outcome) {
+                Ok(()) => log_action(
+                    "Recorded",
+                    format!(
+                        "invocation in the journal as entry {}",
+                        journal_entry_id.to_string().log_color_highlight()
+                    ),
+                ),
+                Err(err) => log_warn(format!(
+                    "Failed to record the outcome of the invocation in journal entry {}: {err:#}",
+                    journal_entry_id.to_string().log_color_highlight()
+                )),
+            }
+        }
+
+        let result = result?;
 
         match result {
             Some(result) => {
-                logln("");
-                self.ctx
-                    .log_handler()
-                    .log_view(&InvokeResultView::new_invoke(
-                        idempotency_key,
-                        result,
-                        &component,
-                        &function_name,
-                    ));
+                let is_err_result = InvokeResultView::is_err_result(&result);
+
+                match result_format {
+                    Some(result_format) => {
+                        println!(
+                            "{}",
+                            InvokeResultView::format_result(
+                                &result,
+                                result_format,
+                                &component,
+                                &function_name
+                            )?
+                        );
+                    }
+                    None => {
+                        let metrics = if metrics {
+                            self.invocation_metrics(
+                                &component,
+                                worker_name_match.worker_name.as_ref(),
+                                &idempotency_key,
+                                wall_time,
+                            )
+                            .await
+                        } else {
+                            InvocationMetricsView::new(wall_time, &idempotency_key, &[])
+                        };
+                        logln("");
+                        self.ctx.log_handler().log_view(
+                            &InvokeResultView::new_invoke(
+                                idempotency_key,
+                                result,
+                                &component,
+                                &function_name,
+                            )
+                            .with_metrics(metrics),
+                        );
+                    }
+                }
+
+                if is_err_result {
+                    bail!(InvokedFunctionReturnedErr);
+                }
             }
             None => {
                 log_action("Enqueued", "invocation");
-                self.ctx
-                    .log_handler()
-                    .log_view(&InvokeResultView::new_enqueue(idempotency_key));
+                let metrics = InvocationMetricsView::new(wall_time, &idempotency_key, &[]);
+                self.ctx.log_handler().log_view(
+                    &InvokeResultView::new_enqueue(idempotency_key).with_metrics(metrics),
+                );
             }
         }
 
         Ok(())
     }
 
-    async fn cmd_stream(
-        &mut self,
-        worker_name: WorkerNameArg,
-        stream_args: StreamArgs,
-    ) -> anyhow::Result<()> {
-        self.ctx.silence_app_context_init().await;
-        let worker_name_match = self.match_worker_name(worker_name.worker_name).await?;
-        let (component, worker_name) = self
-            .component_by_worker_name_match(&worker_name_match)
-            .await?;
+    // Server side metrics are collected from the oplog on a best-effort basis, failing to get
+    // them should not fail the already completed invocation
+    async fn invocation_metrics(
+        &self,
+        component: &Component,
+        worker_name: Option<&WorkerName>,
+        idempotency_key: &IdempotencyKey,
+        wall_time: Duration,
+    ) -> InvocationMetricsView {
+        // Only the end of the oplog is fetched, until the start of the invocation is found
+        let oplog = match worker_name {
+            Some(worker_name) => self
+                .oplog_tail(
+                    component.versioned_component_id.component_id,
+                    worker_name,
+                    |entries| {
+                        entries.iter().any(|(_, entry)| {
+                            InvocationMetricsView::is_invocation_start(entry, idempotency_key)
+                        })
+                    },
+                )
+                .await
+                .unwrap_or_else(|err| {
+                    debug!(error = %err, "Failed to get oplog for invocation metrics");
+                    vec![]
+                }),
+            // Ephemeral workers cannot be looked up after the invocation
+            None => vec![],
+        };
 
-        log_action(
-            "Connecting",
-            format!("to worker {}", format_worker_name_match(&worker_name_match)),
-        );
+        InvocationMetricsView::new(wall_time, idempotency_key, &oplog)
+    }
 
-        let connection = WorkerConnection::new(
-            self.ctx.worker_service_url().clone(),
-            self.ctx.auth_token().await?,
-            component.versioned_component_id.component_id,
-            worker_name.0.clone(),
-            stream_args.into(),
-            self.ctx.allow_insecure(),
-            self.ctx.format(),
+    fn cmd_history(
+        &self,
+        filter: Option<String>,
+        entry: Option<u64>,
+        limit: usize,
+    ) -> anyhow::Result<()> {
+        if !self.ctx.invocation_journal_enabled() {
+            log_warn(format!(
+                "The invocation journal is disabled for profile {}, enable it with {}",
+                self.ctx.profile_name().0.log_color_highlight(),
+                format!(
+                    "profile config {} set-invocation-journal true",
+                    self.ctx.profile_name().0
+                )
+                .log_color_highlight()
+            ));
+            logln("");
+        }
+
+        match entry {
+            Some(entry) => {
+                let entry = self.journal_entry(entry)?;
+                self.ctx.log_handler().log_view(&entry);
+            }
+            None => {
+                let entries =
+                    InvocationJournal::for_profile(self.ctx.config_dir(), self.ctx.profile_name())?
+                        .into_iter()
+                        .filter(|entry| {
+                            filter
+                                .as_ref()
+                                .is_none_or(|filter| entry.matches_filter(filter))
+                        })
+                        .take(limit)
+                        .collect::<Vec<_>>();
+                self.ctx.log_handler().log_view(&entries);
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn cmd_retry(&mut self, entry: u64) -> anyhow::Result<()> {
+        let entry = self.journal_entry(entry)?;
+
+        log_action(
+            "Retrying",
+            format!(
+                "invocation from journal entry {} ({}), started at {}",
+                entry.id.to_string().log_color_highlight(),
+                entry.outcome.to_string().log_color_highlight(),
+                entry.started_at.to_string().log_color_highlight()
+            ),
+        );
+
+        self.cmd_invoke(
+            WorkerNameArg {
+                worker_name: entry.worker_name,
+            },
+            &entry.function_name,
+            entry.arguments,
+            entry.enqueue,
+            Some(entry.idempotency_key),
             None,
+            None,
+            None,
+            None,
+            None,
+            false,
         )
-        .await?;
+        .await
+    }
+
+    fn journal_entry(&self, id: u64) -> anyhow::Result<InvocationJournalEntry> {
+        match InvocationJournal::get(self.ctx.config_dir(), self.ctx.profile_name(), id)? {
+            Some(entry) => Ok(entry),
+            None => {
+                log_error(format!(
+                    "Invocation journal entry not found: {}",
+                    id.to_string().log_color_highlight()
+                ));
+                bail!(NonSuccessfulExit)
+            }
+        }
+    }
+
+    async fn cmd_bench(
+        &mut self,
+        component_name: ComponentName,
+        function_name: &WorkerFunctionName,
+        arguments: Vec<WorkerFunctionArgument>,
+        workers: u64,
+        worker_name_pattern: String,
+        rate: Option<f64>,
+        concurrency: u64,
+        duration: u64,
+    ) -> anyhow::Result<()> {
+        self.ctx.silence_app_context_init().await;
+
+        if workers > 1 && !worker_name_pattern.contains("{n}") {
+            log_error(format!(
+                "The worker name pattern ({}) must contain {} when using multiple workers",
+                worker_name_pattern.log_color_error_highlight(),
+                "{n}".log_color_highlight()
+            ));
+            bail!(NonSuccessfulExit);
+        }
+
+        let worker_names = (1..=workers)
+            .map(|n| WorkerName(worker_name_pattern.replace("{n}", &n.to_string())))
+            .collect::<Vec<_>>();
+
+        // The component is resolved through the first worker name, the same way as for invoke
+        let worker_name_match = self
+            .match_worker_name(format!("{}/{}", component_name.0, worker_names[0].0).into())
+            .await?;
+
+        let component = self
+            .ctx
+            .component_handler()
+            .component_by_name_with_auto_deploy(
+                worker_name_match.project.as_ref(),
+                worker_name_match.component_name_match_kind,
+                &worker_name_match.component_name,
+                worker_name_match.worker_name.as_ref().map(|wn| wn.into()),
+            )
+            .await?;
+
+        let ephemeral = component.component_type == AppComponentType::Ephemeral;
+        if ephemeral {
+            log_warn_action(
+                "Ignoring",
+                "worker name pattern, invoking ephemeral workers".to_string(),
+            );
+        }
+
+        let function_name =
+            match_function_name(&component, &worker_name_match.component_name, function_name)?;
+        let arguments = wave_args_to_invoke_args(&component, &function_name, arguments)?;
+
+        log_action(
+            "Benchmarking",
+            format!(
+                "{}/{} on {} worker(s) for {} seconds, {}",
+                worker_name_match.component_name.0.log_color_highlight(),
+                format_export(&function_name),
+                workers.to_string().log_color_highlight(),
+                duration.to_string().log_color_highlight(),
+                match rate {
+                    Some(rate) => format!(
+                        "at {} invocation(s) per second with at most {} concurrent invocation(s)",
+                        rate.to_string().log_color_highlight(),
+                        concurrency.to_string().log_color_highlight()
+                    ),
+                    None => format!(
+                        "with {} concurrent invocation(s)",
+                        concurrency.to_string().log_color_highlight()
+                    ),
+                }
+            ),
+        );
+
+        let component = Arc::new(component);
+        let function_name = Arc::new(function_name);
+        let arguments = Arc::new(arguments);
+        let semaphore = Arc::new(Semaphore::new(concurrency as usize));
+        // The rate is validated during argument parsing, so the interval is not zero
+        let mut rate_limiter = rate.map(|rate| {
+            let mut interval = tokio::time::interval(Duration::from_secs_f64(1.0 / rate));
+            interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            interval
+        });
+
+        let started_at = Instant::now();
+        let deadline = started_at + Duration::from_secs(duration);
+        let mut invocations = JoinSet::new();
+        let mut invocation_count = 0usize;
+        loop {
+            if let Some(rate_limiter) = &mut rate_limiter {
+                rate_limiter.tick().await;
+            }
+            let permit = semaphore.clone().acquire_owned().await?;
+            if Instant::now() >= deadline {
+                break;
+            }
 
-        connection.run_forever().await;
+            let ctx = self.ctx.clone();
+            let component = component.clone();
+            let function_name = function_name.clone();
+            let arguments = arguments.clone();
+            let worker_name = worker_names[invocation_count % worker_names.len()].clone();
+            invocation_count += 1;
+
+            invocations.spawn(async move {
+                let invocation_started_at = Instant::now();
+                let result = ctx
+                    .worker_handler()
+                    .invoke_worker(
+                        &component,
+                        (!ephemeral).then_some(&worker_name),
+                        &function_name,
+                        arguments.as_ref().clone(),
+                        IdempotencyKey::new(),
+                        false,
+                        None,
+                    )
+                    .await;
+                drop(permit);
+                (
+                    invocation_started_at.elapsed(),
+                    result.err().map(|err| bench_error_kind(&err)),
+                )
+            });
+        }
+
+        let mut latencies = Vec::with_capacity(invocation_count);
+        let mut errors = Vec::new();
+        while let Some(result) = invocations.join_next().await {
+            match result? {
+                (latency, None) => latencies.push(latency),
+                (_, Some(error_kind)) => errors.push(error_kind),
+            }
+        }
+
+        logln("");
+        self.ctx.log_handler().log_view(&WorkerBenchView::new(
+            worker_name_match.component_name,
+            function_name.as_ref().clone(),
+            workers,
+            concurrency,
+            rate,
+            started_at.elapsed(),
+            latencies,
+            errors,
+        ));
+
+        Ok(())
+    }
+
+    async fn cmd_stream(
+        &mut self,
+        worker_names: Vec<WorkerName>,
+        component_name: Option<ComponentName>,
+        filters: Vec<String>,
+        stream_args: StreamArgs,
+    ) -> anyhow::Result<()> {
+        self.ctx.silence_app_context_init().await;
+
+        let mut workers = Vec::<(ComponentName, Uuid, WorkerName)>::new();
+        let multiple_workers = match &component_name {
+            Some(component_name) => {
+                let selected_components = self
+                    .ctx
+                    .component_handler()
+                    .must_select_components_by_app_or_name(Some(component_name))
+                    .await?;
+
+                for component_name in &selected_components.component_names {
+                    match self
+                        .ctx
+                        .component_handler()
+                        .component(
+                            selected_components.project.as_ref(),
+                            component_name.into(),
+                            None,
+                        )
+                        .await?
+                    {
+                        Some(component) => {
+                            let component_id = component.versioned_component_id.component_id;
+                            let (component_workers, _) = self
+                                .list_component_workers(
+                                    component_name,
+                                    component_id,
+                                    Some(filters.as_slice()),
+                                    None,
+                                    None,
+                                    false,
+                                )
+                                .await?;
+
+                            log_action(
+                                "Connecting",
+                                format!(
+                                    "to {} worker(s) of component {}",
+                                    component_workers.len(),
+                                    component_name.0.log_color_highlight()
+                                ),
+                            );
+
+                            workers.extend(component_workers.into_iter().map(|worker| {
+                                (
+                                    component_name.clone(),
+                                    component_id,
+                                    worker.worker_id.worker_name.into(),
+                                )
+                            }));
+                        }
+                        None => {
+                            log_warn(format!(
+                                "Component not found: {}",
+                                component_name.0.log_color_error_highlight()
+                            ));
+                        }
+                    }
+                }
+
+                true
+            }
+            None => {
+                let multiple_workers = worker_names.len() > 1;
+                for worker_name in worker_names {
+                    let worker_name_match = self.match_worker_name(worker_name).await?;
+                    let (component, worker_name) = self
+                        .component_by_worker_name_match(&worker_name_match)
+                        .await?;
+
+                    log_action(
+                        "Connecting",
+                        format!("to worker {}", format_worker_name_match(&worker_name_match)),
+                    );
+
+                    workers.push((
+                        component.component_name,
+                        component.versioned_component_id.component_id,
+                        worker_name,
+                    ));
+                }
+
+                multiple_workers
+            }
+        };
+
+        if workers.is_empty() {
+            log_error("No workers found to stream");
+            bail!(NonSuccessfulExit);
+        }
+
+        let output_file = stream_output_file(&stream_args)?;
+        let connect_options = WorkerConnectOptions::new(stream_args, self.ctx.stream_config());
+
+        let mut connections = Vec::with_capacity(workers.len());
+        for (component_name, component_id, worker_name) in workers {
+            let mut connect_options = connect_options.clone();
+            if multiple_workers {
+                connect_options.worker_label = Some(format!("{component_name}/{worker_name}"));
+            }
+
+            connections.push(
+                WorkerConnection::new(
+                    self.ctx.worker_service_url().clone(),
+                    self.ctx.auth_token().await?,
+                    component_id,
+                    worker_name.0,
+                    connect_options,
+                    output_file.clone(),
+                    self.ctx.allow_insecure(),
+                    self.ctx.format(),
+                    None,
+                )
+                .await?,
+            );
+        }
+
+        let results = futures_util::future::join_all(
+            connections
+                .into_iter()
+                .map(|connection| connection.run_forever()),
+        )
+        .await;
+
+        if results.iter().any(|result| result.is_err()) {
+            bail!(NonSuccessfulExit);
+        }
 
         Ok(())
     }
@@ -468,58 +1313,14 @@ impl WorkerCommandHandler {
             .component_by_worker_name_match(&worker_name_match)
             .await?;
 
-        let batch_size = self.ctx.http_batch_size();
-        let mut entries = Vec::<(u64, PublicOplogEntry)>::new();
-        let mut cursor = Option::<OplogCursor>::None;
-        loop {
-            cursor = match self.ctx.golem_clients().await? {
-                GolemClients::Oss(clients) => {
-                    let result = clients
-                        .worker
-                        .get_oplog(
-                            &component.versioned_component_id.component_id,
-                            &worker_name.0,
-                            from,
-                            batch_size,
-                            cursor.as_ref(),
-                            query.as_deref(),
-                        )
-                        .await
-                        .map_service_error()?;
-                    entries.extend(
-                        result
-                            .entries
-                            .into_iter()
-                            .map(|entry| (entry.oplog_index, entry.entry)),
-                    );
-                    result.next
-                }
-                GolemClients::Cloud(clients) => {
-                    let result = clients
-                        .worker
-                        .get_oplog(
-                            &component.versioned_component_id.component_id,
-                            &worker_name.0,
-                            from,
-                            batch_size,
-                            cursor.as_ref(),
-                            query.as_deref(),
-                        )
-                        .await
-                        .map_service_error()?;
-                    entries.extend(
-                        result
-                            .entries
-                            .into_iter()
-                            .map(|entry| (entry.oplog_index, entry.entry)),
-                    );
-                    result.next
-                }
-            };
-            if cursor.is_none() {
-                break;
-            }
-        }
+        let entries = self
+            .oplog_entries(
+                component.versioned_component_id.component_id,
+                &worker_name,
+                from,
+                query.as_deref(),
+            )
+            .await?;
 
         if entries.is_empty() {
             log_warn("No results.")
@@ -530,11 +1331,81 @@ impl WorkerCommandHandler {
         Ok(())
     }
 
+    async fn cmd_oplog_diff(
+        &mut self,
+        worker_name: WorkerNameArg,
+        other_worker_name: Option<WorkerName>,
+        range: Option<OplogIndexRange>,
+        other_range: Option<OplogIndexRange>,
+        full: bool,
+    ) -> anyhow::Result<()> {
+        if other_worker_name.is_none() && (range.is_none() || other_range.is_none()) {
+            log_error(format!(
+                "Both {} and {} are required when comparing ranges of the same worker",
+                "--range".log_color_highlight(),
+                "--other-range".log_color_highlight()
+            ));
+            bail!(NonSuccessfulExit)
+        }
+
+        self.ctx.silence_app_context_init().await;
+
+        let range = range.unwrap_or_default();
+        let other_range = other_range.unwrap_or(range);
+
+        let (left_name, left) = self
+            .oplog_entries_in_range(worker_name.worker_name.clone(), range)
+            .await?;
+        let (right_name, right) = self
+            .oplog_entries_in_range(
+                other_worker_name.unwrap_or(worker_name.worker_name),
+                other_range,
+            )
+            .await?;
+
+        self.ctx.log_handler().log_view(&WorkerOplogDiffView::new(
+            left_name, &left, right_name, &right, full,
+        ));
+
+        Ok(())
+    }
+
+    async fn oplog_entries_in_range(
+        &mut self,
+        worker_name: WorkerName,
+        range: OplogIndexRange,
+    ) -> anyhow::Result<(String, Vec<(u64, PublicOplogEntry)>)> {
+        let worker_name_match = self.match_worker_name(worker_name).await?;
+        let (component, worker_name) = self
+            .component_by_worker_name_match(&worker_name_match)
+            .await?;
+
+        let entries = self
+            .oplog_entries(
+                component.versioned_component_id.component_id,
+                &worker_name,
+                range.from,
+                None,
+            )
+            .await?
+            .into_iter()
+            .filter(|(idx, _)| range.contains(*idx))
+            .collect();
+
+        let mut name = format!("{}/{}", worker_name_match.component_name.0, worker_name.0);
+        if range != OplogIndexRange::default() {
+            name.push_str(&format!(" #{}", range));
+        }
+
+        Ok((name, entries))
+    }
+
     async fn cmd_revert(
         &mut self,
         worker_name: WorkerNameArg,
         last_oplog_index: Option<u64>,
         number_of_invocations: Option<u64>,
+        preview: bool,
     ) -> anyhow::Result<()> {
         if last_oplog_index.is_none() && number_of_invocations.is_none() {
             log_error(format!(
@@ -551,6 +1422,49 @@ impl WorkerCommandHandler {
             .component_by_worker_name_match(&worker_name_match)
             .await?;
 
+        // Only the reverted tail is needed when reverting to an oplog index, the invocations
+        // to revert can only be found from the whole oplog
+        let oplog = self
+            .oplog_entries(
+                component.versioned_component_id.component_id,
+                &worker_name,
+                last_oplog_index.map(|last_oplog_index| last_oplog_index + 1),
+                None,
+            )
+            .await?;
+        let preview_view = WorkerRevertPreviewView::new(
+            component.component_name.clone(),
+            worker_name.clone(),
+            oplog,
+            last_oplog_index,
+            number_of_invocations,
+        );
+
+        if preview {
+            self.ctx.log_handler().log_view(&preview_view);
+            return Ok(());
+        }
+
+        // The preview is only an estimate, the reverted entries are decided by the worker service
+        if preview_view.entries.is_empty() {
+            log_warn(
+                "The preview found no oplog entries to revert, sending the revert request anyway",
+            );
+        }
+
+        if !self.ctx.yes() {
+            log_text_view(&preview_view);
+            logln("");
+        }
+
+        if !self
+            .ctx
+            .interactive_handler()
+            .confirm_revert_worker(&preview_view)?
+        {
+            bail!(NonSuccessfulExit);
+        }
+
         log_action(
             "Reverting",
             format!("worker {}", format_worker_name_match(&worker_name_match)),
@@ -701,8 +1615,69 @@ impl WorkerCommandHandler {
             bail!(NonSuccessfulExit);
         }
 
-        let mut view = WorkersMetadataResponseView::default();
+        let mut view = WorkersMetadataResponseView::default();
+
+        for component_name in &selected_components.component_names {
+            match self
+                .ctx
+                .component_handler()
+                .component(
+                    selected_components.project.as_ref(),
+                    component_name.into(),
+                    None,
+                )
+                .await?
+            {
+                Some(component) => {
+                    let (workers, scan_cursor) = self
+                        .list_component_workers(
+                            component_name,
+                            component.versioned_component_id.component_id,
+                            Some(filters.as_slice()),
+                            scan_cursor.as_ref(),
+                            max_count,
+                            precise,
+                        )
+                        .await?;
+
+                    view.workers
+                        .extend(workers.into_iter().map(WorkerMetadataView::from));
+                    scan_cursor.into_iter().for_each(|scan_cursor| {
+                        view.cursors.insert(
+                            component_name.to_string(),
+                            scan_cursor_to_string(&scan_cursor),
+                        );
+                    });
+                }
+                None => {
+                    log_warn(format!(
+                        "Component not found: {}",
+                        component_name.0.log_color_error_highlight()
+                    ));
+                }
+            }
+        }
+
+        self.ctx.log_handler().log_view(&view);
+
+        Ok(())
+    }
+
+    async fn cmd_list_watch(
+        &self,
+        component_name: Option<ComponentName>,
+        filters: Vec<String>,
+        max_count: Option<u64>,
+        precise: bool,
+        interval: u64,
+    ) -> anyhow::Result<()> {
+        let selected_components = self
+            .ctx
+            .component_handler()
+            .must_select_components_by_app_or_name(component_name.as_ref())
+            .await?;
 
+        let mut components = Vec::with_capacity(selected_components.component_names.len());
         for component_name in &selected_components.component_names {
             match self
                 .ctx
@@ -715,25 +1690,10 @@ impl WorkerCommandHandler {
                 .await?
             {
                 Some(component) => {
-                    let (workers, scan_cursor) = self
-                        .list_component_workers(
-                            component_name,
-                            component.versioned_component_id.component_id,
-                            Some(filters.as_slice()),
-                            scan_cursor.as_ref(),
-                            max_count,
-                            precise,
-                        )
-                        .await?;
-
-                    view.workers
-                        .extend(workers.into_iter().map(WorkerMetadataView::from));
-                    scan_cursor.into_iter().for_each(|scan_cursor| {
-                        view.cursors.insert(
-                            component_name.to_string(),
-                            scan_cursor_to_string(&scan_cursor),
-                        );
-                    });
+                    components.push((
+                        component_name.clone(),
+                        component.versioned_component_id.component_id,
+                    ));
                 }
                 None => {
                     log_warn(format!(
@@ -744,9 +1704,42 @@ impl WorkerCommandHandler {
             }
         }
 
-        self.ctx.log_handler().log_view(&view);
+        if components.is_empty() {
+            bail!(NonSuccessfulExit);
+        }
 
-        Ok(())
+        let interval = interval.max(1);
+        // Only the text view is redrawn, the other formats are streamed as separate documents
+        let clear_screen = self.ctx.format() == Format::Text;
+        let mut previous: Option<WorkersWatchView> = None;
+
+        loop {
+            let mut workers = Vec::new();
+            for (component_name, component_id) in &components {
+                let (component_workers, _) = self
+                    .list_component_workers(
+                        component_name,
+                        *component_id,
+                        Some(filters.as_slice()),
+                        None,
+                        max_count,
+                        precise,
+                    )
+                    .await?;
+                workers.extend(component_workers.into_iter().map(WorkerMetadataView::from));
+            }
+
+            let view = WorkersWatchView::new(previous.as_ref(), interval, workers);
+
+            if clear_screen {
+                log_clear_screen();
+            }
+            self.ctx.log_handler().log_view(&view);
+
+            previous = Some(view);
+
+            tokio::time::sleep(Duration::from_secs(interval)).await;
+        }
     }
 
     async fn cmd_interrupt(&mut self, worker_name: WorkerNameArg) -> anyhow::Result<()> {
@@ -895,6 +1888,60 @@ impl WorkerCommandHandler {
         Ok(())
     }
 
+    async fn cmd_describe(
+        &mut self,
+        worker_name: WorkerNameArg,
+        invocations: usize,
+    ) -> anyhow::Result<()> {
+        self.ctx.silence_app_context_init().await;
+        let worker_name_match = self.match_worker_name(worker_name.worker_name).await?;
+        let (component, worker_name) = self
+            .component_by_worker_name_match(&worker_name_match)
+            .await?;
+        let component_id = component.versioned_component_id.component_id;
+
+        let metadata = self
+            .worker_metadata(
+                component_id,
+                &worker_name_match.component_name,
+                &worker_name,
+            )
+            .await?;
+        let oplog = self
+            .oplog_tail(component_id, &worker_name, |entries| {
+                entries
+                    .iter()
+                    .filter(|(_, entry)| {
+                        matches!(entry, PublicOplogEntry::ExportedFunctionInvoked(_))
+                    })
+                    .count()
+                    >= invocations
+            })
+            .await?;
+
+        // The worker's component version is selected above, so the latest has to be fetched too
+        let latest_component = self
+            .ctx
+            .component_handler()
+            .component(
+                worker_name_match.project.as_ref(),
+                (&worker_name_match.component_name).into(),
+                None,
+            )
+            .await?
+            .unwrap_or_else(|| component.clone());
+
+        self.ctx.log_handler().log_view(&WorkerDescribeView::new(
+            WorkerMetadataView::from(metadata),
+            latest_component.versioned_component_id.version,
+            show_exported_functions(&component.metadata.exports, true),
+            show_exported_functions(&latest_component.metadata.exports, true),
+            WorkerInvocationView::from_oplog(&oplog, invocations),
+        ));
+
+        Ok(())
+    }
+
     async fn cmd_delete(&mut self, worker_name: WorkerNameArg) -> anyhow::Result<()> {
         self.ctx.silence_app_context_init().await;
         let worker_name_match = self.match_worker_name(worker_name.worker_name).await?;
@@ -971,12 +2018,14 @@ impl WorkerCommandHandler {
         let mut connect_handle = match &worker_name {
             Some(worker_name) => match stream_args {
                 Some(stream_args) => {
+                    let output_file = stream_output_file(&stream_args)?;
                     let connection = WorkerConnection::new(
                         self.ctx.worker_service_url().clone(),
                         self.ctx.auth_token().await?,
                         component.versioned_component_id.component_id,
                         worker_name.0.clone(),
-                        stream_args.into(),
+                        WorkerConnectOptions::new(stream_args, self.ctx.stream_config()),
+                        output_file,
                         self.ctx.allow_insecure(),
                         self.ctx.format(),
                         if enqueue {
@@ -1138,6 +2187,128 @@ impl WorkerCommandHandler {
         Ok(result)
     }
 
+    pub async fn oplog_entries(
+        &self,
+        component_id: Uuid,
+        worker_name: &WorkerName,
+        from: Option<u64>,
+        query: Option<&str>,
+    ) -> anyhow::Result<Vec<(u64, PublicOplogEntry)>> {
+        let batch_size = self.ctx.http_batch_size();
+        let mut entries = Vec::<(u64, PublicOplogEntry)>::new();
+        let mut cursor = Option::<OplogCursor>::None;
+        loop {
+            cursor = match self.ctx.golem_clients().await? {
+                GolemClients::Oss(clients) => {
+                    let result = clients
+                        .worker
+                        .get_oplog(
+                            &component_id,
+                            &worker_name.0,
+                            from,
+                            batch_size,
+                            cursor.as_ref(),
+                            query,
+                        )
+                        .await
+                        .map_service_error()?;
+                    entries.extend(
+                        result
+                            .entries
+                            .into_iter()
+                            .map(|entry| (entry.oplog_index, entry.entry)),
+                    );
+                    result.next
+                }
+                GolemClients::Cloud(clients) => {
+                    let result = clients
+                        .worker
+                        .get_oplog(
+                            &component_id,
+                            &worker_name.0,
+                            from,
+                            batch_size,
+                            cursor.as_ref(),
+                            query,
+                        )
+                        .await
+                        .map_service_error()?;
+                    entries.extend(
+                        result
+                            .entries
+                            .into_iter()
+                            .map(|entry| (entry.oplog_index, entry.entry)),
+                    );
+                    result.next
+                }
+            };
+            if cursor.is_none() {
+                break;
+            }
+        }
+
+        Ok(entries)
+    }
+
+    /// Fetches a single page of oplog entries, starting from the given index
+    async fn oplog_page(
+        &self,
+        component_id: Uuid,
+        worker_name: &WorkerName,
+        from: u64,
+        count: u64,
+    ) -> anyhow::Result<Vec<(u64, PublicOplogEntry)>> {
+        let entries = match self.ctx.golem_clients().await? {
+            GolemClients::Oss(clients) => clients
+                .worker
+                .get_oplog(&component_id, &worker_name.0, Some(from), count, None, None)
+                .await
+                .map_service_error()?
+                .entries
+                .into_iter()
+                .map(|entry| (entry.oplog_index, entry.entry))
+                .collect(),
+            GolemClients::Cloud(clients) => clients
+                .worker
+                .get_oplog(&component_id, &worker_name.0, Some(from), count, None, None)
+                .await
+                .map_service_error()?
+                .entries
+                .into_iter()
+                .map(|entry| (entry.oplog_index, entry.entry))
+                .collect(),
+        };
+        Ok(entries)
+    }
+
+    /// Fetches the end of the oplog, doubling the fetched range backwards from the last entry
+    /// until `is_enough` accepts the fetched entries or the whole oplog is fetched
+    async fn oplog_tail(
+        &self,
+        component_id: Uuid,
+        worker_name: &WorkerName,
+        is_enough: impl Fn(&[(u64, PublicOplogEntry)]) -> bool,
+    ) -> anyhow::Result<Vec<(u64, PublicOplogEntry)>> {
+        let last_index = last_oplog_index(|index| async move {
+            self.oplog_page(component_id, worker_name, index, 1)
+                .await
+                .map(|entries| !entries.is_empty())
+        })
+        .await?;
+
+        let mut tail_length = self.ctx.http_batch_size().max(1);
+        loop {
+            let from = oplog_tail_start(last_index, tail_length);
+            let entries = self
+                .oplog_entries(component_id, worker_name, Some(from), None)
+                .await?;
+            if from == 1 || is_enough(&entries) {
+                return Ok(entries);
+            }
+            tail_length = tail_length.saturating_mul(2);
+        }
+    }
+
     pub async fn worker_metadata(
         &self,
         component_id: Uuid,
@@ -1191,6 +2362,7 @@ impl WorkerCommandHandler {
         component_id: Uuid,
         update_mode: WorkerUpdateMode,
         target_version: u64,
+        check_compatibility: bool,
     ) -> anyhow::Result<TryUpdateAllWorkersResult> {
         let (workers, _) = self
             .list_component_workers(component_name, component_id, None, None, None, false)
@@ -1218,8 +2390,50 @@ impl WorkerCommandHandler {
         );
         let _indent = LogIndent::new();
 
+        let update_plan = if check_compatibility {
+            let update_plan = self
+                .worker_update_plan(
+                    component_name,
+                    component_id,
+                    &workers,
+                    update_mode,
+                    target_version,
+                )
+                .await?;
+            log_text_view(&update_plan);
+            Some(update_plan)
+        } else {
+            None
+        };
+
         let mut update_results = TryUpdateAllWorkersResult::default();
         for worker in workers {
+            match update_plan
+                .as_ref()
+                .and_then(|plan| plan.action(worker.component_version))
+            {
+                Some(WorkerUpdatePlanAction::UpToDate) => continue,
+                Some(WorkerUpdatePlanAction::Refuse { reason }) => {
+                    log_warn_action(
+                        "Skipping",
+                        format!(
+                            "update for worker {}/{}: {}",
+                            component_name.0.bold().blue(),
+                            worker.worker_id.worker_name.bold().green(),
+                            reason
+                        ),
+                    );
+                    update_results.failed.push(WorkerUpdateAttempt {
+                        component_name: component_name.clone(),
+                        target_version,
+                        worker_name: worker.worker_id.worker_name.as_str().into(),
+                        error: Some(reason.clone()),
+                    });
+                    continue;
+                }
+                Some(WorkerUpdatePlanAction::Update { .. }) | None => {}
+            }
+
             let result = self
                 .update_worker(
                     component_name,
@@ -1253,6 +2467,83 @@ impl WorkerCommandHandler {
         Ok(update_results)
     }
 
+    // Groups the workers by their current component version, and checks if the update from each
+    // version is safe, based on the exports of the versions and the requested update mode
+    async fn worker_update_plan(
+        &self,
+        component_name: &ComponentName,
+        component_id: Uuid,
+        workers: &[WorkerMetadata],
+        update_mode: WorkerUpdateMode,
+        target_version: u64,
+    ) -> anyhow::Result<WorkerUpdatePlan> {
+        let component_handler = self.ctx.component_handler();
+        let component_version = |version: u64| {
+            component_handler.component(
+                None,
+                ComponentSelection::Id(component_id),
+                Some(version.into()),
+            )
+        };
+
+        let target_component = component_version(target_version)
+            .await?
+            .ok_or_else(|| anyhow!("Component version {} not found", target_version))?;
+
+        let worker_counts = workers.iter().counts_by(|worker| worker.component_version);
+
+        let mut entries = Vec::with_capacity(worker_counts.len());
+        for (source_version, worker_count) in worker_counts.into_iter().sorted() {
+            let action = if source_version == target_version {
+                WorkerUpdatePlanAction::UpToDate
+            } else {
+                match component_version(source_version).await {
+                    Ok(Some(source_component)) => {
+                        let breaking_changes = ComponentDiffView::new(
+                            component_name.clone(),
+                            source_version.to_string(),
+                            &source_component.metadata.exports,
+                            target_version.to_string(),
+                            &target_component.metadata.exports,
+                        )
+                        .breaking_count();
+
+                        WorkerUpdatePlanAction::new(
+                            update_mode,
+                            source_version,
+                            target_version,
+                            breaking_changes,
+                            source_component.exports_interface(SAVE_SNAPSHOT_INTERFACE)
+                                && target_component.exports_interface(LOAD_SNAPSHOT_INTERFACE),
+                        )
+                    }
+                    Ok(None) => WorkerUpdatePlanAction::Refuse {
+                        reason: format!("component version {} not found", source_version),
+                    },
+                    Err(error) => WorkerUpdatePlanAction::Refuse {
+                        reason: format!(
+                            "failed to get component version {}: {}",
+                            source_version, error
+                        ),
+                    },
+                }
+            };
+
+            entries.push(WorkerUpdatePlanEntry {
+                source_version,
+                worker_count,
+                action,
+            });
+        }
+
+        Ok(WorkerUpdatePlan {
+            component_name: component_name.clone(),
+            target_version,
+            update_mode,
+            entries,
+        })
+    }
+
     async fn update_worker(
         &self,
         component_name: &ComponentName,
@@ -1504,7 +2795,7 @@ impl WorkerCommandHandler {
         Ok((workers, final_result_cursor))
     }
 
-    async fn component_by_worker_name_match(
+    pub async fn component_by_worker_name_match(
         &mut self,
         worker_name_match: &WorkerNameMatch,
     ) -> anyhow::Result<(Component, WorkerName)> {
@@ -1826,6 +3117,114 @@ impl WorkerCommandHandler {
     }
 }
 
+enum NewWorkersSource {
+    Csv(PathBuf),
+    NamePattern {
+        name_pattern: String,
+        count: u64,
+        arguments: Vec<NewWorkerArgument>,
+    },
+}
+
+/// Finds the index of the last oplog entry by probing for entries at given indexes, as the
+/// oplog can only be paged forward. Returns 0 for an empty oplog.
+async fn last_oplog_index<F, Fut>(mut exists: F) -> anyhow::Result<u64>
+where
+    F: FnMut(u64) -> Fut,
+    Fut: Future<Output = anyhow::Result<bool>>,
+{
+    // Oplog indexes start from 1
+    if !exists(1).await? {
+        return Ok(0);
+    }
+
+    let mut found = 1u64;
+    let mut missing = 2u64;
+    while exists(missing).await? {
+        found = missing;
+        missing = missing.saturating_mul(2);
+    }
+
+    while missing - found > 1 {
+        let middle = found + (missing - found) / 2;
+        if exists(middle).await? {
+            found = middle;
+        } else {
+            missing = middle;
+        }
+    }
+
+    Ok(found)
+}
+
+/// First index of the last `tail_length` oplog entries
+fn oplog_tail_start(last_index: u64, tail_length: u64) -> u64 {
+    last_index.saturating_sub(tail_length).saturating_add(1)
+}
+
+fn bench_error_kind(error: &anyhow::Error) -> String {
+    match error.downcast_ref::<ServiceError>() {
+        Some(error) => error.kind_name(),
+        None => "Other error".to_string(),
+    }
+}
+
+fn match_function_name(
+    component: &Component,
+    component_name: &ComponentName,
+    function_name: &str,
+) -> anyhow::Result<String> {
+    match fuzzy_match_function_name(function_name, &component.metadata.exports) {
+        Ok(match_) => {
+            log_fuzzy_match(&match_);
+            Ok(match_.option)
+        }
+        Err(error) => {
+            let component_functions = show_exported_functions(&component.metadata.exports, false);
+
+            match error {
+                Error::Ambiguous {
+                    highlighted_options,
+                    ..
+                } => {
+                    logln("");
+                    log_error(format!(
+                        "The requested function name ({}) is ambiguous.",
+                        function_name.log_color_error_highlight()
+                    ));
+                    logln("");
+                    logln("Did you mean one of");
+                    for option in highlighted_options {
+                        logln(format!(" - {}", option.bold()));
+                    }
+                    logln("?");
+                    logln("");
+                    log_text_view(&AvailableFunctionNamesHelp {
+                        component_name: component_name.0.clone(),
+                        function_names: component_functions,
+                    });
+
+                    bail!(NonSuccessfulExit);
+                }
+                Error::NotFound { .. } => {
+                    logln("");
+                    log_error(format!(
+                        "The requested function name ({}) was not found.",
+                        function_name.log_color_error_highlight()
+                    ));
+                    logln("");
+                    log_text_view(&AvailableFunctionNamesHelp {
+                        component_name: component_name.0.clone(),
+                        function_names: component_functions,
+                    });
+
+                    bail!(NonSuccessfulExit);
+                }
+            }
+        }
+    }
+}
+
 fn wave_args_to_invoke_args(
     component: &Component,
     function_name: &str,
@@ -1910,6 +3309,21 @@ fn wave_args_to_invoke_args(
         .map_err(|err| anyhow!("Failed to convert type annotated value: {err}"))
 }
 
+fn stream_output_file(stream_args: &StreamArgs) -> anyhow::Result<Option<StreamOutputFile>> {
+    stream_args
+        .stream_output_file
+        .as_ref()
+        .map(|path| {
+            StreamOutputFile::new(
+                path.clone(),
+                stream_args.stream_output_format,
+                stream_args.stream_output_max_size,
+                stream_args.stream_output_max_files,
+            )
+        })
+        .transpose()
+}
+
 fn scan_cursor_to_string(cursor: &ScanCursor) -> String {
     format!("{}/{}", cursor.layer, cursor.cursor)
 }
@@ -1960,3 +3374,57 @@ fn parse_worker_error(status: u16, body: Vec<u8>) -> ServiceError {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::command_handler::worker::{last_oplog_index, oplog_tail_start};
+    use assert2::check;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use test_r::test;
+
+    async fn find_last_index(oplog_length: u64) -> (u64, usize) {
+        let probes = AtomicUsize::new(0);
+        let last_index = last_oplog_index(|index| {
+            probes.fetch_add(1, Ordering::SeqCst);
+            async move { Ok(index <= oplog_length) }
+        })
+        .await
+        .unwrap();
+        (last_index, probes.load(Ordering::SeqCst))
+    }
+
+    #[test]
+    async fn last_oplog_index_of_empty_oplog() {
+        check!(find_last_index(0).await == (0, 1));
+    }
+
+    #[test]
+    async fn last_oplog_index_is_found_with_logarithmic_probes() {
+        for oplog_length in [1, 2, 3, 5, 8, 100, 1023, 1024, 1025, 1_000_000] {
+            let (last_index, probes) = find_last_index(oplog_length).await;
+            check!(last_index == oplog_length);
+            check!(probes <= 2 * (oplog_length.ilog2() as usize + 2));
+        }
+    }
+
+    #[test]
+    async fn last_oplog_index_propagates_errors() {
+        let result = last_oplog_index(|index| async move {
+            if index > 4 {
+                Err(anyhow::anyhow!("failed"))
+            } else {
+                Ok(true)
+            }
+        })
+        .await;
+        check!(result.is_err());
+    }
+
+    #[test]
+    fn oplog_tail_start_is_clamped_to_the_first_entry() {
+        check!(oplog_tail_start(100, 10) == 91);
+        check!(oplog_tail_start(100, 100) == 1);
+        check!(oplog_tail_start(100, 1000) == 1);
+        check!(oplog_tail_start(0, 10) == 1);
+    }
+}
@@ -0,0 +1,255 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::command::shared_args::{WorkerFunctionArgument, WorkerNameArg};
+use crate::command::worker::scheduled::WorkerScheduledSubcommand;
+use crate::command_handler::Handlers;
+use crate::context::Context;
+use crate::error::NonSuccessfulExit;
+use crate::log::{log_action, log_warn_action, logln, LogColorize};
+use crate::model::component::Component;
+use crate::model::invoke_result_view::InvokeResultView;
+use crate::model::scheduled::{ScheduledInvocation, ScheduledInvocations};
+use crate::model::text::fmt::{format_export, format_worker_name_match, log_error};
+use crate::model::{IdempotencyKey, WorkerNameMatch};
+use anyhow::bail;
+use chrono::{DateTime, Utc};
+use golem_wasm_rpc::json::OptionallyTypeAnnotatedValueJson;
+use std::sync::Arc;
+use std::time::Duration;
+
+// The waiting process checks this often whether the invocation got cancelled
+const CANCELLATION_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+pub struct WorkerScheduledCommandHandler {
+    ctx: Arc<Context>,
+}
+
+impl WorkerScheduledCommandHandler {
+    pub fn new(ctx: Arc<Context>) -> Self {
+        Self { ctx }
+    }
+
+    pub async fn handle_command(
+        &self,
+        subcommand: WorkerScheduledSubcommand,
+    ) -> anyhow::Result<()> {
+        match subcommand {
+            WorkerScheduledSubcommand::List => self.cmd_list(),
+            WorkerScheduledSubcommand::Cancel { idempotency_key } => {
+                self.cmd_cancel(idempotency_key)
+            }
+            WorkerScheduledSubcommand::Run => self.cmd_run().await,
+        }
+    }
+
+    fn cmd_list(&self) -> anyhow::Result<()> {
+        let invocations =
+            ScheduledInvocations::for_profile(self.ctx.config_dir(), self.ctx.profile_name())?;
+
+        self.ctx.log_handler().log_view(&invocations);
+
+        Ok(())
+    }
+
+    fn cmd_cancel(&self, idempotency_key: IdempotencyKey) -> anyhow::Result<()> {
+        match ScheduledInvocations::remove(
+            self.ctx.config_dir(),
+            self.ctx.profile_name(),
+            &idempotency_key,
+        )? {
+            Some(invocation) => {
+                log_action(
+                    "Cancelled",
+                    format!(
+                        "scheduled invocation {} of {}",
+                        idempotency_key.0.log_color_highlight(),
+                        format_export(&invocation.function_name)
+                    ),
+                );
+                Ok(())
+            }
+            None => {
+                log_error(format!(
+                    "Scheduled invocation not found: {}",
+                    idempotency_key.0.log_color_highlight()
+                ));
+                bail!(NonSuccessfulExit)
+            }
+        }
+    }
+
+    async fn cmd_run(&self) -> anyhow::Result<()> {
+        let config_dir = self.ctx.config_dir();
+        let profile_name = self.ctx.profile_name();
+
+        let due_invocations = ScheduledInvocations::for_profile(config_dir, profile_name)?
+            .into_iter()
+            .filter(|invocation| invocation.is_overdue())
+            .collect::<Vec<_>>();
+
+        if due_invocations.is_empty() {
+            logln("No due scheduled invocations found");
+            return Ok(());
+        }
+
+        for invocation in due_invocations {
+            // Removing before enqueueing, so a waiting process or a concurrent run cannot enqueue it again
+            let Some(invocation) = ScheduledInvocations::remove(
+                config_dir,
+                profile_name,
+                &invocation.idempotency_key,
+            )?
+            else {
+                continue;
+            };
+
+            let result = self
+                .ctx
+                .worker_handler()
+                .cmd_invoke(
+                    WorkerNameArg {
+                        worker_name: invocation.qualified_worker_name(),
+                    },
+                    &invocation.function_name,
+                    invocation.arguments.clone(),
+                    true,
+                    Some(invocation.idempotency_key.clone()),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    false,
+                )
+                .await;
+
+            // Keeping the failed invocation pending, so it can be run again or cancelled
+            if let Err(error) = result {
+                ScheduledInvocations::add(config_dir, invocation)?;
+                return Err(error);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Records the invocation as pending, then waits until the scheduled time and enqueues it,
+    /// unless it gets cancelled in the meantime by `worker scheduled cancel`.
+    pub async fn schedule_invocation(
+        &self,
+        component: &Component,
+        worker_name_match: &WorkerNameMatch,
+        function_name: String,
+        arguments: Vec<WorkerFunctionArgument>,
+        invoke_args: Vec<OptionallyTypeAnnotatedValueJson>,
+        idempotency_key: IdempotencyKey,
+        scheduled_at: DateTime<Utc>,
+    ) -> anyhow::Result<()> {
+        let config_dir = self.ctx.config_dir();
+        let profile_name = self.ctx.profile_name();
+
+        ScheduledInvocations::add(
+            config_dir,
+            ScheduledInvocation {
+                idempotency_key: idempotency_key.clone(),
+                profile_name: profile_name.clone(),
+                account_id: worker_name_match.account_id.clone(),
+                project_name: worker_name_match
+                    .project
+                    .as_ref()
+                    .map(|project| project.project_name.clone()),
+                component_name: worker_name_match.component_name.clone(),
+                worker_name: worker_name_match.worker_name.clone(),
+                function_name: function_name.clone(),
+                arguments,
+                scheduled_at,
+                created_at: Utc::now(),
+            },
+        )?;
+
+        log_action(
+            "Scheduled",
+            format!(
+                "invocation for worker {}/{} at {}",
+                format_worker_name_match(worker_name_match),
+                format_export(&function_name),
+                scheduled_at.to_string().log_color_highlight()
+            ),
+        );
+        logln(format!(
+            "The invocation is scheduled locally and enqueued by this command, keep it running until \
+             the scheduled time. If it gets stopped, use {} after the scheduled time to enqueue it, \
+             or {} to cancel it.",
+            "worker scheduled run".log_color_highlight(),
+            format!("worker scheduled cancel {}", idempotency_key.0).log_color_highlight()
+        ));
+
+        loop {
+            let now = Utc::now();
+            if now >= scheduled_at {
+                break;
+            }
+
+            if !ScheduledInvocations::contains(config_dir, profile_name, &idempotency_key)? {
+                log_warn_action(
+                    "Skipping",
+                    "scheduled invocation, it was cancelled or enqueued by another command",
+                );
+                return Ok(());
+            }
+
+            let remaining = (scheduled_at - now).to_std().unwrap_or_default();
+            tokio::time::sleep(remaining.min(CANCELLATION_CHECK_INTERVAL)).await;
+        }
+
+        // Removing before enqueueing, so a concurrent cancel either wins or finds nothing
+        if ScheduledInvocations::remove(config_dir, profile_name, &idempotency_key)?.is_none() {
+            log_warn_action(
+                "Skipping",
+                "scheduled invocation, it was cancelled or enqueued by another command",
+            );
+            return Ok(());
+        }
+
+        log_action(
+            "Enqueueing",
+            format!(
+                "invocation for worker {}/{}",
+                format_worker_name_match(worker_name_match),
+                format_export(&function_name)
+            ),
+        );
+
+        self.ctx
+            .worker_handler()
+            .invoke_worker(
+                component,
+                worker_name_match.worker_name.as_ref(),
+                &function_name,
+                invoke_args,
+                idempotency_key.clone(),
+                true,
+                None,
+            )
+            .await?;
+
+        log_action("Enqueued", "invocation");
+        self.ctx
+            .log_handler()
+            .log_view(&InvokeResultView::new_enqueue(idempotency_key));
+
+        Ok(())
+    }
+}
@@ -13,7 +13,8 @@
 // limitations under the License.
 
 use crate::command_handler::worker::parse_worker_error;
-use crate::command_handler::worker::stream_output::WorkerStreamOutput;
+use crate::command_handler::worker::stream_output::{StreamOutputFile, WorkerStreamOutput};
+use crate::config::StreamConfig;
 use crate::model::{Format, WorkerConnectOptions};
 use anyhow::{anyhow, Context};
 use bytes::Bytes;
@@ -24,7 +25,7 @@ use golem_common::model::{IdempotencyKey, Timestamp, WorkerEvent};
 use native_tls::TlsConnector;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tokio::net::TcpStream;
 use tokio::sync::Mutex;
 use tokio::{task, time};
@@ -38,9 +39,20 @@ use tracing::{debug, error, info, trace};
 use url::Url;
 use uuid::Uuid;
 
+// Connections closed sooner than this are counted as failed attempts, so a server accepting
+// and then immediately closing connections is also backed off
+const MIN_STABLE_CONNECTION: Duration = Duration::from_secs(5);
+
+enum ReadError {
+    Disconnected,
+    IdleTimeout,
+    GoalReached,
+}
+
 pub struct WorkerConnection {
     request: Request,
     connector: Option<Connector>,
+    config: StreamConfig,
     output: WorkerStreamOutput,
     idempotency_key: Option<IdempotencyKey>,
     last_seen_idempotency_key: Arc<Mutex<Option<IdempotencyKey>>>,
@@ -55,6 +67,7 @@ impl WorkerConnection {
         component_id: Uuid,
         worker_name: String,
         connect_options: WorkerConnectOptions,
+        output_file: Option<StreamOutputFile>,
         allow_insecure: bool,
         format: Format,
         idempotency_key: Option<IdempotencyKey>,
@@ -66,7 +79,8 @@ impl WorkerConnection {
             worker_name,
             allow_insecure,
         )?;
-        let output = WorkerStreamOutput::new(connect_options, format);
+        let config = connect_options.connection.clone();
+        let output = WorkerStreamOutput::new(connect_options, format, output_file);
 
         let last_seen_idempotency_key = Arc::new(Mutex::new(None));
         let goal_reached = Arc::new(AtomicBool::new(false));
@@ -74,6 +88,7 @@ impl WorkerConnection {
         Ok(Self {
             request,
             connector,
+            config,
             output,
             idempotency_key,
             last_seen_idempotency_key,
@@ -82,18 +97,82 @@ impl WorkerConnection {
     }
 
     /// Creates a new worker connection and every time the connection is dropped tries to
-    /// reconnect. If there was an idempotency_key goal and it has been reached, the loop
-    /// exits.
-    pub async fn run_forever(self) {
+    /// reconnect, using exponential backoff between failed attempts. If there was an
+    /// idempotency_key goal and it has been reached, the loop exits. Fails if the configured
+    /// maximum number of consecutive failed attempts is reached. Connections which do not stay
+    /// up for a minimum time also count as failed attempts.
+    pub async fn run_forever(self) -> anyhow::Result<()> {
+        let mut failed_attempts: u32 = 0;
+        let mut disconnected_at: Option<Instant> = None;
+
         while !self.goal_reached.load(Ordering::Acquire) {
-            let _ = self.run().await;
-            self.output.flush().await;
-            tokio::time::sleep(Duration::from_millis(100)).await;
+            match self.connect().await {
+                Ok(ws_stream) => {
+                    if let Some(disconnected_at) = disconnected_at.take() {
+                        self.output
+                            .emit_reconnected(disconnected_at.elapsed())
+                            .await;
+                    }
+
+                    let connected_at = Instant::now();
+                    let _ = self.run(ws_stream).await;
+                    self.output.flush().await;
+                    disconnected_at = Some(Instant::now());
+
+                    if self.goal_reached.load(Ordering::Acquire) {
+                        break;
+                    }
+
+                    let connected_for = connected_at.elapsed();
+                    if connected_for >= MIN_STABLE_CONNECTION {
+                        failed_attempts = 0;
+                        tokio::time::sleep(self.config.reconnect_delay(1, rand::random())).await;
+                    } else {
+                        self.failed_attempt(
+                            &mut failed_attempts,
+                            anyhow!(
+                                "Connection closed after {:.1}s",
+                                connected_for.as_secs_f64()
+                            ),
+                        )
+                        .await?;
+                    }
+                }
+                Err(err) => {
+                    self.failed_attempt(&mut failed_attempts, err).await?;
+                }
+            }
         }
+
+        Ok(())
+    }
+
+    /// Waits before the next attempt, or fails if the maximum number of attempts is reached
+    async fn failed_attempt(
+        &self,
+        failed_attempts: &mut u32,
+        err: anyhow::Error,
+    ) -> anyhow::Result<()> {
+        *failed_attempts += 1;
+        if let Some(max_attempts) = self.config.reconnect_max_attempts {
+            if *failed_attempts >= max_attempts {
+                self.output.emit_gave_up(*failed_attempts).await;
+                return Err(err);
+            }
+        }
+
+        let delay = self
+            .config
+            .reconnect_delay(*failed_attempts, rand::random());
+        self.output
+            .emit_connect_failed(*failed_attempts, delay, &err.to_string())
+            .await;
+        tokio::time::sleep(delay).await;
+
+        Ok(())
     }
 
-    /// Connects to the worker event stream and outputs incoming messages until the connection is dropped
-    async fn run(&self) -> anyhow::Result<()> {
+    async fn connect(&self) -> anyhow::Result<WebSocketStream<MaybeTlsStream<TcpStream>>> {
         let (ws_stream, _) = connect_async_tls_with_config(
             self.request.clone(),
             None,
@@ -112,14 +191,24 @@ impl WorkerConnection {
             _ => anyhow!("Websocket connect failed, error: {}", e),
         })?;
 
+        Ok(ws_stream)
+    }
+
+    /// Outputs incoming messages of a connected worker event stream until the connection is dropped
+    async fn run(
+        &self,
+        ws_stream: WebSocketStream<MaybeTlsStream<TcpStream>>,
+    ) -> anyhow::Result<()> {
         let (write, read) = ws_stream.split();
 
-        let pings = task::spawn(async move { Self::ping_loop(write).await });
+        let ping_interval = self.config.ping_interval();
+        let pings = task::spawn(async move { Self::ping_loop(write, ping_interval).await });
 
         let output = self.output.clone();
         let last_seen_idempotency_key = self.last_seen_idempotency_key.clone();
         let idempotency_key = self.idempotency_key.clone();
         let goal_reached = self.goal_reached.clone();
+        let idle_timeout = self.config.idle_timeout();
         let read_messages = task::spawn(async move {
             Self::read_loop(
                 read,
@@ -127,6 +216,7 @@ impl WorkerConnection {
                 last_seen_idempotency_key,
                 idempotency_key,
                 goal_reached,
+                idle_timeout,
             )
             .await;
         });
@@ -190,8 +280,9 @@ impl WorkerConnection {
 
     async fn ping_loop(
         mut write: SplitSink<WebSocketStream<MaybeTlsStream<TcpStream>>, Message>,
+        ping_interval: Duration,
     ) -> anyhow::Error {
-        let mut interval = time::interval(Duration::from_secs(1)); // TODO configure
+        let mut interval = time::interval(ping_interval);
         let mut cnt: i64 = 1;
 
         loop {
@@ -216,8 +307,19 @@ impl WorkerConnection {
         last_seen_idempotency_key: Arc<Mutex<Option<IdempotencyKey>>>,
         idempotency_key_to_look_for: Option<IdempotencyKey>,
         goal_reached: Arc<AtomicBool>,
+        idle_timeout: Option<Duration>,
     ) {
-        let _ = read
+        let idle_output = output.clone();
+        let read = read.map_err(|_| ReadError::Disconnected);
+        let read = match idle_timeout {
+            Some(idle_timeout) => Either::Left(
+                tokio_stream::StreamExt::timeout(read, idle_timeout)
+                    .map(|message| message.unwrap_or_else(|_| Err(ReadError::IdleTimeout))),
+            ),
+            None => Either::Right(read),
+        };
+
+        let result = read
             .try_for_each(move |message| {
                 let output = output.clone();
                 let idempotency_key_to_look_for = idempotency_key_to_look_for.clone();
@@ -313,13 +415,17 @@ impl WorkerConnection {
 
                     if goal_reached.load(Ordering::Acquire) {
                         // Early return from the stream as the goal of observing a given idempotency key has been reached
-                        Err(tungstenite::error::Error::ConnectionClosed)
+                        Err(ReadError::GoalReached)
                     } else {
                         Ok(())
                     }
                 }
             })
             .await;
+
+        if let (Err(ReadError::IdleTimeout), Some(idle_timeout)) = (result, idle_timeout) {
+            idle_output.emit_idle_timeout(idle_timeout).await;
+        }
     }
 
     fn parse_websocket_message(message: Message) -> Option<WorkerEvent> {
@@ -12,22 +12,43 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::model::{Format, WorkerConnectOptions};
-use colored::Colorize;
+use crate::log::LogColorize;
+use crate::model::{Format, StreamLogLevel, StreamOutputFileFormat, WorkerConnectOptions};
+use anyhow::{anyhow, Context};
+use colored::{Color, Colorize};
 use golem_common::model::{IdempotencyKey, LogLevel, Timestamp};
 use std::cmp::Ordering;
 use std::collections::HashSet;
+use std::ffi::OsString;
 use std::fmt::Write;
 use std::hash::{DefaultHasher, Hash, Hasher};
+use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::Arc;
+use std::time::Duration;
+use tokio::fs::{File, OpenOptions};
+use tokio::io::AsyncWriteExt;
 use tokio::sync::Mutex;
+use tracing::error;
+
+static WORKER_LABEL_COLORS: &[Color] = &[
+    Color::Cyan,
+    Color::Magenta,
+    Color::Blue,
+    Color::Green,
+    Color::Yellow,
+    Color::BrightCyan,
+    Color::BrightMagenta,
+    Color::BrightBlue,
+];
 
 #[derive(Clone)]
 pub struct WorkerStreamOutput {
     state: Arc<Mutex<WorkerStreamOutputState>>,
     options: WorkerConnectOptions,
     format: Format,
+    worker_label_color: Color,
+    output_file: Option<StreamOutputFile>,
 }
 
 struct WorkerStreamOutputState {
@@ -37,21 +58,39 @@ struct WorkerStreamOutputState {
     pub stderr: String,
     pub last_timestamp: Timestamp,
     pub last_timestamp_hashes: HashSet<u64>,
+    /// Timestamp of the last event seen before a reconnect, until the first event after it arrives
+    pub gap_start: Option<Timestamp>,
 }
 
 impl WorkerStreamOutput {
-    pub fn new(options: WorkerConnectOptions, format: Format) -> Self {
+    pub fn new(
+        options: WorkerConnectOptions,
+        format: Format,
+        output_file: Option<StreamOutputFile>,
+    ) -> Self {
+        let worker_label_color = match &options.worker_label {
+            Some(worker_label) => {
+                let mut hasher = DefaultHasher::new();
+                worker_label.hash(&mut hasher);
+                WORKER_LABEL_COLORS[hasher.finish() as usize % WORKER_LABEL_COLORS.len()]
+            }
+            None => Color::White,
+        };
+
         WorkerStreamOutput {
             state: Arc::new(Mutex::new(WorkerStreamOutputState {
                 last_stdout_timestamp: Timestamp::now_utc(),
                 stdout: String::new(),
                 last_stderr_timestamp: Timestamp::now_utc(),
                 stderr: String::new(),
-                last_timestamp: Timestamp::from_str("2000-01-01T00:00:00Z").unwrap(),
+                last_timestamp: Self::initial_timestamp(),
                 last_timestamp_hashes: HashSet::new(),
+                gap_start: None,
             })),
             options,
             format,
+            worker_label_color,
+            output_file,
         }
     }
 
@@ -68,18 +107,20 @@ impl WorkerStreamOutput {
                 if idx == (lines.len() - 1) {
                     // last line, if message did not end with newline, just store it
                     if message.ends_with('\n') {
-                        self.print_stdout(timestamp, &format!("{}{}", state.stdout, line));
+                        self.print_stdout(timestamp, &format!("{}{}", state.stdout, line))
+                            .await;
                         state.stdout = String::new();
                     } else {
                         state.stdout = format!("{}{}", state.stdout, line);
                     }
                 } else if idx == 0 {
                     // first line, there are more
-                    self.print_stdout(timestamp, &format!("{}{}", state.stdout, line));
+                    self.print_stdout(timestamp, &format!("{}{}", state.stdout, line))
+                        .await;
                     state.stdout = String::new();
                 } else {
                     // middle line
-                    self.print_stdout(timestamp, line);
+                    self.print_stdout(timestamp, line).await;
                 }
             }
         }
@@ -98,18 +139,20 @@ impl WorkerStreamOutput {
                 if idx == (lines.len() - 1) {
                     // last line, if message did not end with newline, just store it
                     if message.ends_with('\n') {
-                        self.print_stderr(timestamp, &format!("{}{}", state.stderr, line));
+                        self.print_stderr(timestamp, &format!("{}{}", state.stderr, line))
+                            .await;
                         state.stderr = String::new();
                     } else {
                         state.stderr = format!("{}{}", state.stderr, line);
                     }
                 } else if idx == 0 {
                     // first line, there are more
-                    self.print_stderr(timestamp, &format!("{}{}", state.stderr, line));
+                    self.print_stderr(timestamp, &format!("{}{}", state.stderr, line))
+                        .await;
                     state.stderr = String::new();
                 } else {
                     // middle line
-                    self.print_stderr(timestamp, line);
+                    self.print_stderr(timestamp, line).await;
                 }
             }
         }
@@ -137,14 +180,20 @@ impl WorkerStreamOutput {
                 LogLevel::Critical => "CRITICAL",
             };
 
-            match self.format {
-                Format::Json => self.json(level_str, &context, &message),
-                Format::Yaml => self.yaml(level_str, &context, &message),
-                Format::Text => {
-                    let prefix = self.prefix(timestamp, level_str);
-                    self.colored(level, &format!("{prefix}[{context}] {message}"));
-                }
+            if !self.is_visible(level, Some(&context), &message) {
+                return;
             }
+
+            self.print(
+                self.format,
+                timestamp,
+                level,
+                level_str,
+                &context,
+                &message,
+                &format!("[{context}] {message}"),
+            )
+            .await;
         }
     }
 
@@ -155,8 +204,8 @@ impl WorkerStreamOutput {
             .check_already_seen(&mut state, timestamp, "Stream closed")
             .await
         {
-            let prefix = self.prefix(timestamp, "STREAM");
-            self.colored(LogLevel::Debug, &format!("{prefix}Stream closed"));
+            self.print_event(timestamp, LogLevel::Debug, "STREAM", "Stream closed")
+                .await;
         }
     }
 
@@ -176,11 +225,13 @@ impl WorkerStreamOutput {
             )
             .await
         {
-            let prefix = self.prefix(timestamp, "INVOKE");
-            self.colored(
+            self.print_event(
+                timestamp,
                 LogLevel::Trace,
-                &format!("{prefix}STARTED  {function_name} ({idempotency_key})"),
-            );
+                "INVOKE",
+                &format!("STARTED  {function_name} ({idempotency_key})"),
+            )
+            .await;
         }
     }
 
@@ -200,22 +251,88 @@ impl WorkerStreamOutput {
             )
             .await
         {
-            let prefix = self.prefix(timestamp, "INVOKE");
-            self.colored(
+            self.print_event(
+                timestamp,
                 LogLevel::Trace,
-                &format!("{prefix}FINISHED {function_name} ({idempotency_key})",),
-            );
+                "INVOKE",
+                &format!("FINISHED {function_name} ({idempotency_key})"),
+            )
+            .await;
         }
     }
 
+    pub async fn emit_connect_failed(&self, attempt: u32, delay: Duration, error: &str) {
+        self.print_event(
+            Timestamp::now_utc(),
+            LogLevel::Warn,
+            "STREAM",
+            &format!(
+                "Connection failed (attempt {attempt}), retrying in {}ms: {error}",
+                delay.as_millis()
+            ),
+        )
+        .await;
+    }
+
+    pub async fn emit_gave_up(&self, attempts: u32) {
+        self.print_event(
+            Timestamp::now_utc(),
+            LogLevel::Error,
+            "STREAM",
+            &format!("Giving up after {attempts} failed connection attempt(s)"),
+        )
+        .await;
+    }
+
+    pub async fn emit_idle_timeout(&self, idle_timeout: Duration) {
+        self.print_event(
+            Timestamp::now_utc(),
+            LogLevel::Warn,
+            "STREAM",
+            &format!(
+                "No message received for {}ms, reconnecting",
+                idle_timeout.as_millis()
+            ),
+        )
+        .await;
+    }
+
+    /// Reports the window in which events may have been missed because of a reconnect.
+    /// If the events replayed after reconnecting overlap with the ones already seen,
+    /// that is reported once the first event arrives.
+    pub async fn emit_reconnected(&self, disconnected_for: Duration) {
+        let mut state = self.state.lock().await;
+        let now = Timestamp::now_utc();
+
+        let message = if state.last_timestamp == Self::initial_timestamp() {
+            format!(
+                "Reconnected after {:.1}s, no events were seen before the disconnect",
+                disconnected_for.as_secs_f64()
+            )
+        } else {
+            state.gap_start = Some(state.last_timestamp);
+            format!(
+                "Reconnected after {:.1}s, events between {} and {} may have been missed",
+                disconnected_for.as_secs_f64(),
+                state.last_timestamp,
+                now
+            )
+        };
+
+        self.print_event(now, LogLevel::Warn, "STREAM", &message)
+            .await;
+    }
+
     pub async fn flush(&self) {
         let mut state = self.state.lock().await;
         if !state.stdout.is_empty() {
-            self.print_stdout(state.last_stdout_timestamp, &state.stdout);
+            self.print_stdout(state.last_stdout_timestamp, &state.stdout)
+                .await;
             state.stdout = String::new();
         }
         if !state.stderr.is_empty() {
-            self.print_stderr(state.last_stdout_timestamp, &state.stderr);
+            self.print_stderr(state.last_stdout_timestamp, &state.stderr)
+                .await;
             state.stderr = String::new();
         }
     }
@@ -230,6 +347,17 @@ impl WorkerStreamOutput {
         message.hash(&mut hasher);
         let hash = hasher.finish();
 
+        if let Some(gap_start) = state.gap_start.take() {
+            if timestamp <= gap_start {
+                self.print_event(
+                    Timestamp::now_utc(),
+                    LogLevel::Info,
+                    "STREAM",
+                    "Replayed events overlap with the ones seen before the disconnect, no events were missed",
+                ).await;
+            }
+        }
+
         match state.last_timestamp.cmp(&timestamp) {
             Ordering::Less => {
                 // definitely new
@@ -255,45 +383,141 @@ impl WorkerStreamOutput {
         }
     }
 
-    fn print_stdout(&self, timestamp: Timestamp, message: &str) {
-        match self.format {
-            Format::Json => self.json("STDOUT", "", message),
-            Format::Yaml => self.yaml("STDOUT", "", message),
+    fn initial_timestamp() -> Timestamp {
+        Timestamp::from_str("2000-01-01T00:00:00Z").unwrap()
+    }
+
+    async fn print_stdout(&self, timestamp: Timestamp, message: &str) {
+        if self.is_visible(LogLevel::Info, None, message) {
+            self.print(
+                self.format,
+                timestamp,
+                LogLevel::Info,
+                "STDOUT",
+                "",
+                message,
+                message,
+            )
+            .await;
+        }
+    }
+
+    async fn print_stderr(&self, timestamp: Timestamp, message: &str) {
+        if self.is_visible(LogLevel::Error, None, message) {
+            self.print(
+                self.format,
+                timestamp,
+                LogLevel::Error,
+                "STDERR",
+                "",
+                message,
+                message,
+            )
+            .await;
+        }
+    }
+
+    /// Stream and invocation events are always printed as text on the terminal. They are not
+    /// worker output, so only the minimum level applies to them, not the context and message filters.
+    async fn print_event(
+        &self,
+        timestamp: Timestamp,
+        level: LogLevel,
+        source: &str,
+        message: &str,
+    ) {
+        if self.is_level_visible(level) {
+            self.print(Format::Text, timestamp, level, source, "", message, message)
+                .await;
+        }
+    }
+
+    async fn print(
+        &self,
+        format: Format,
+        timestamp: Timestamp,
+        level: LogLevel,
+        level_or_source: &str,
+        context: &str,
+        message: &str,
+        text: &str,
+    ) {
+        match format {
+            Format::Json => self.json(timestamp, level_or_source, context, message),
+            Format::Yaml => self.yaml(timestamp, level_or_source, context, message),
             Format::Text => {
-                let prefix = self.prefix(timestamp, "STDOUT");
-                self.colored(LogLevel::Info, &format!("{prefix}{message}"));
+                let prefix = self.prefix(timestamp, level_or_source);
+                self.colored(level, &format!("{prefix}{text}"));
             }
         }
+
+        if let Some(output_file) = &self.output_file {
+            let line = match output_file.format {
+                StreamOutputFileFormat::Text => format!(
+                    "{}{}{}",
+                    self.worker_label_prefix(),
+                    self.prefix(timestamp, level_or_source),
+                    text
+                ),
+                StreamOutputFileFormat::JsonLines => self
+                    .json_value(timestamp, level_or_source, context, message)
+                    .to_string(),
+            };
+            output_file.write_line(&line).await;
+        }
     }
 
-    fn print_stderr(&self, timestamp: Timestamp, message: &str) {
-        match self.format {
-            Format::Json => self.json("STDERR", "", message),
-            Format::Yaml => self.yaml("STDERR", "", message),
-            Format::Text => {
-                let prefix = self.prefix(timestamp, "STDERR");
-                self.colored(LogLevel::Error, &format!("{prefix}{message}"));
+    fn is_level_visible(&self, level: LogLevel) -> bool {
+        self.options
+            .min_level
+            .is_none_or(|min_level| StreamLogLevel::from(level) >= min_level)
+    }
+
+    // Stdout and stderr entries have no context, so they never match a context filter
+    fn is_visible(&self, level: LogLevel, context: Option<&str>, message: &str) -> bool {
+        if !self.is_level_visible(level) {
+            return false;
+        }
+        if let Some(context_filter) = &self.options.context_filter {
+            if !context.is_some_and(|context| context_filter.is_match(context)) {
+                return false;
+            }
+        }
+        if let Some(message_filter) = &self.options.message_filter {
+            if !message_filter.is_match(message) {
+                return false;
             }
         }
+        true
     }
 
-    fn json(&self, level_or_source: &str, context: &str, message: &str) {
-        let json = self.json_value(level_or_source, context, message);
+    fn json(&self, timestamp: Timestamp, level_or_source: &str, context: &str, message: &str) {
+        let json = self.json_value(timestamp, level_or_source, context, message);
         println!("{}", json);
     }
 
-    fn yaml(&self, level_or_source: &str, context: &str, message: &str) {
-        let json = self.json_value(level_or_source, context, message);
+    fn yaml(&self, timestamp: Timestamp, level_or_source: &str, context: &str, message: &str) {
+        let json = self.json_value(timestamp, level_or_source, context, message);
         println!("{}", serde_yaml::to_string(&json).unwrap());
     }
 
-    fn json_value(&self, level_or_source: &str, context: &str, message: &str) -> serde_json::Value {
-        serde_json::json!({
-            "timestamp": Timestamp::now_utc(),
+    fn json_value(
+        &self,
+        timestamp: Timestamp,
+        level_or_source: &str,
+        context: &str,
+        message: &str,
+    ) -> serde_json::Value {
+        let mut json = serde_json::json!({
+            "timestamp": timestamp,
             "level": level_or_source,
             "context": context,
             "message": message,
-        })
+        });
+        if let Some(worker_label) = &self.options.worker_label {
+            json["worker"] = serde_json::Value::String(worker_label.clone());
+        }
+        json
     }
 
     fn colored(&self, level: LogLevel, s: &str) {
@@ -306,9 +530,20 @@ impl WorkerStreamOutput {
                 LogLevel::Error => s.red(),
                 LogLevel::Critical => s.red().bold(),
             };
-            println!("{}", colored);
+            println!(
+                "{}{}",
+                self.worker_label_prefix().color(self.worker_label_color),
+                colored
+            );
         } else {
-            println!("{}", s);
+            println!("{}{}", self.worker_label_prefix(), s);
+        }
+    }
+
+    fn worker_label_prefix(&self) -> String {
+        match &self.options.worker_label {
+            Some(worker_label) => format!("[{worker_label}] "),
+            None => String::new(),
         }
     }
 
@@ -329,3 +564,218 @@ impl WorkerStreamOutput {
         result
     }
 }
+
+/// File sink for stream output, shared between the streams of multiple workers.
+/// The file is rotated when it would grow over `max_size`, keeping at most `max_files`
+/// rotated files with numbered suffixes (`<file>.1` being the most recent one).
+#[derive(Clone)]
+pub struct StreamOutputFile {
+    state: Arc<Mutex<StreamOutputFileState>>,
+    format: StreamOutputFileFormat,
+    max_size: u64,
+    max_files: usize,
+}
+
+struct StreamOutputFileState {
+    path: PathBuf,
+    file: File,
+    size: u64,
+}
+
+impl StreamOutputFile {
+    pub fn new(
+        path: PathBuf,
+        format: StreamOutputFileFormat,
+        max_size: u64,
+        max_files: usize,
+    ) -> anyhow::Result<Self> {
+        let file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .with_context(|| {
+                anyhow!(
+                    "Failed to open stream output file: {}",
+                    path.log_color_highlight()
+                )
+            })?;
+        let size = file.metadata()?.len();
+
+        Ok(Self {
+            state: Arc::new(Mutex::new(StreamOutputFileState {
+                path,
+                file: File::from_std(file),
+                size,
+            })),
+            format,
+            max_size,
+            max_files,
+        })
+    }
+
+    async fn write_line(&self, line: &str) {
+        let mut state = self.state.lock().await;
+        let line_size = line.len() as u64 + 1;
+
+        if state.size > 0 && state.size + line_size > self.max_size {
+            if let Err(err) = self.rotate(&mut state).await {
+                error!(
+                    "Failed to rotate stream output file {}: {err}",
+                    state.path.display()
+                );
+            }
+        }
+
+        let write_result = async {
+            state.file.write_all(format!("{line}\n").as_bytes()).await?;
+            state.file.flush().await
+        }
+        .await;
+        match write_result {
+            Ok(()) => state.size += line_size,
+            Err(err) => error!(
+                "Failed to write stream output file {}: {err}",
+                state.path.display()
+            ),
+        }
+    }
+
+    async fn rotate(&self, state: &mut StreamOutputFileState) -> std::io::Result<()> {
+        let rotated_path = |idx: usize| {
+            let mut path = OsString::from(state.path.as_os_str());
+            path.push(format!(".{idx}"));
+            PathBuf::from(path)
+        };
+
+        if self.max_files == 0 {
+            tokio::fs::remove_file(&state.path).await?;
+        } else {
+            for idx in (1..self.max_files).rev() {
+                let from = rotated_path(idx);
+                if tokio::fs::try_exists(&from).await? {
+                    tokio::fs::rename(&from, rotated_path(idx + 1)).await?;
+                }
+            }
+            tokio::fs::rename(&state.path, rotated_path(1)).await?;
+        }
+
+        state.file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(true)
+            .open(&state.path)
+            .await?;
+        state.size = 0;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::command_handler::worker::stream_output::{StreamOutputFile, WorkerStreamOutput};
+    use crate::config::StreamConfig;
+    use crate::model::{Format, StreamLogLevel, StreamOutputFileFormat, WorkerConnectOptions};
+    use assert2::check;
+    use golem_common::model::{LogLevel, Timestamp};
+    use regex::Regex;
+    use std::str::FromStr;
+    use test_r::test;
+
+    fn output(
+        min_level: Option<StreamLogLevel>,
+        context_filter: Option<&str>,
+        message_filter: Option<&str>,
+    ) -> WorkerStreamOutput {
+        WorkerStreamOutput::new(
+            WorkerConnectOptions {
+                colors: false,
+                show_timestamp: false,
+                show_level: false,
+                min_level,
+                context_filter: context_filter.map(|filter| Regex::new(filter).unwrap()),
+                message_filter: message_filter.map(|filter| Regex::new(filter).unwrap()),
+                worker_label: None,
+                connection: StreamConfig::default(),
+            },
+            Format::Text,
+            None,
+        )
+    }
+
+    #[test]
+    fn min_level_filter() {
+        let output = output(Some(StreamLogLevel::Warn), None, None);
+
+        check!(!output.is_visible(LogLevel::Info, Some("ctx"), "message"));
+        check!(output.is_visible(LogLevel::Warn, Some("ctx"), "message"));
+        check!(output.is_visible(LogLevel::Critical, None, "message"));
+    }
+
+    #[test]
+    fn context_filter_rejects_entries_without_context() {
+        let output = output(None, Some("^http"), None);
+
+        check!(output.is_visible(LogLevel::Info, Some("http-client"), "message"));
+        check!(!output.is_visible(LogLevel::Info, Some("db"), "message"));
+        check!(!output.is_visible(LogLevel::Info, None, "stdout line"));
+        check!(output.is_level_visible(LogLevel::Info));
+    }
+
+    #[test]
+    fn message_filter() {
+        let output = output(None, None, Some("order-[0-9]+"));
+
+        check!(output.is_visible(LogLevel::Info, None, "created order-12"));
+        check!(!output.is_visible(LogLevel::Info, Some("ctx"), "created cart"));
+    }
+
+    #[test]
+    fn json_value_uses_event_timestamp() {
+        let output = output(None, None, None);
+        let timestamp = Timestamp::from_str("2025-01-02T03:04:05Z").unwrap();
+
+        let json = output.json_value(timestamp, "INFO", "ctx", "message");
+
+        check!(json["timestamp"] == serde_json::to_value(timestamp).unwrap());
+        check!(json["level"] == "INFO");
+        check!(json["context"] == "ctx");
+        check!(json["message"] == "message");
+        check!(json.get("worker").is_none());
+    }
+
+    #[test]
+    async fn output_file_rotation() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("stream.log");
+        let rotated = |idx: usize| temp_dir.path().join(format!("stream.log.{idx}"));
+
+        let output_file =
+            StreamOutputFile::new(path.clone(), StreamOutputFileFormat::Text, 10, 2).unwrap();
+        for line in ["line1", "line2", "line3", "line4", "line5"] {
+            output_file.write_line(line).await;
+        }
+
+        // Every file holds at most one line of 6 bytes, and only the 2 most recent
+        // rotated files are kept
+        check!(std::fs::read_to_string(&path).unwrap() == "line5\n");
+        check!(std::fs::read_to_string(rotated(1)).unwrap() == "line4\n");
+        check!(std::fs::read_to_string(rotated(2)).unwrap() == "line3\n");
+        check!(!rotated(3).exists());
+    }
+
+    #[test]
+    async fn output_file_without_rotated_files() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("stream.log");
+
+        let output_file =
+            StreamOutputFile::new(path.clone(), StreamOutputFileFormat::Text, 12, 0).unwrap();
+        for line in ["line1", "line2", "line3"] {
+            output_file.write_line(line).await;
+        }
+
+        check!(std::fs::read_to_string(&path).unwrap() == "line3\n");
+        check!(!temp_dir.path().join("stream.log.1").exists());
+    }
+}
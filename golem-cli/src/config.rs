@@ -44,6 +44,18 @@ pub struct Config {
     pub active_profile: Option<ProfileName>,
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub active_cloud_profile: Option<ProfileName>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub template_repositories: Vec<TemplateRepositoryConfig>,
+}
+
+/// Local directory of custom component templates (e.g. a git checkout), using the same layout
+/// as the builtin templates: `<language>/<template>/metadata.json`
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+pub struct TemplateRepositoryConfig {
+    /// Source label of the templates
+    pub name: String,
+    /// Relative paths are resolved from the config directory
+    pub path: PathBuf,
 }
 
 const PROFILE_NAME_LOCAL: &str = "local";
@@ -231,6 +243,70 @@ impl HasFormatConfig for OssProfile {
 pub struct ProfileConfig {
     #[serde(default)]
     pub default_format: Format,
+    #[serde(default)]
+    pub stream: StreamConfig,
+    /// Record invocations in the local invocation journal, see `worker history`
+    #[serde(default)]
+    pub invocation_journal: bool,
+}
+
+/// Connection settings for worker streams, can be overridden per command with `--stream-*` flags
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(default)]
+pub struct StreamConfig {
+    pub ping_interval_ms: u64,
+    pub reconnect_initial_delay_ms: u64,
+    pub reconnect_max_delay_ms: u64,
+    /// Maximum number of consecutive failed reconnect attempts, unlimited if not set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reconnect_max_attempts: Option<u32>,
+    pub reconnect_jitter: bool,
+    /// Reconnect if no message (including pongs) arrives within the timeout, disabled if not set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub idle_timeout_ms: Option<u64>,
+}
+
+impl Default for StreamConfig {
+    fn default() -> Self {
+        Self {
+            ping_interval_ms: 1000,
+            reconnect_initial_delay_ms: 100,
+            reconnect_max_delay_ms: 30_000,
+            reconnect_max_attempts: None,
+            reconnect_jitter: true,
+            idle_timeout_ms: None,
+        }
+    }
+}
+
+impl StreamConfig {
+    pub fn ping_interval(&self) -> Duration {
+        Duration::from_millis(self.ping_interval_ms.max(1))
+    }
+
+    pub fn idle_timeout(&self) -> Option<Duration> {
+        self.idle_timeout_ms.map(Duration::from_millis)
+    }
+
+    /// Exponential backoff delay for the given (1-based) consecutive failed attempt,
+    /// with "equal jitter" applied when enabled: the delay is randomized between half and
+    /// the full computed value.
+    pub fn reconnect_delay(&self, attempt: u32, random: u64) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(31);
+        let delay_ms = self
+            .reconnect_initial_delay_ms
+            .saturating_mul(1u64 << exponent)
+            .min(self.reconnect_max_delay_ms);
+
+        let delay_ms = if self.reconnect_jitter && delay_ms > 1 {
+            let half = delay_ms / 2;
+            half + random % (delay_ms - half + 1)
+        } else {
+            delay_ms
+        };
+
+        Duration::from_millis(delay_ms)
+    }
 }
 
 impl Config {
@@ -394,6 +470,19 @@ impl Config {
         config.store_file(config_dir)
     }
 
+    pub fn get_template_repositories(
+        config_dir: &Path,
+    ) -> anyhow::Result<Vec<TemplateRepositoryConfig>> {
+        Ok(Self::from_dir(config_dir)?
+            .template_repositories
+            .into_iter()
+            .map(|repository| TemplateRepositoryConfig {
+                path: config_dir.join(repository.path),
+                ..repository
+            })
+            .collect())
+    }
+
     pub fn delete_profile(name: &ProfileName, config_dir: &Path) -> anyhow::Result<()> {
         let mut config = Self::from_dir(config_dir)?;
         config.profiles.remove(name);
@@ -542,3 +631,56 @@ impl HttpClientConfig {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::config::StreamConfig;
+    use assert2::check;
+    use std::time::Duration;
+    use test_r::test;
+
+    fn config(reconnect_jitter: bool) -> StreamConfig {
+        StreamConfig {
+            reconnect_initial_delay_ms: 100,
+            reconnect_max_delay_ms: 1000,
+            reconnect_jitter,
+            ..StreamConfig::default()
+        }
+    }
+
+    #[test]
+    fn reconnect_delay_is_exponential_and_capped() {
+        let config = config(false);
+
+        check!(config.reconnect_delay(0, 0) == Duration::from_millis(100));
+        check!(config.reconnect_delay(1, 0) == Duration::from_millis(100));
+        check!(config.reconnect_delay(2, 0) == Duration::from_millis(200));
+        check!(config.reconnect_delay(4, 0) == Duration::from_millis(800));
+        check!(config.reconnect_delay(5, 0) == Duration::from_millis(1000));
+        check!(config.reconnect_delay(u32::MAX, 0) == Duration::from_millis(1000));
+    }
+
+    #[test]
+    fn reconnect_delay_jitter_is_between_half_and_full_delay() {
+        let config = config(true);
+
+        check!(config.reconnect_delay(2, 0) == Duration::from_millis(100));
+        check!(config.reconnect_delay(2, 100) == Duration::from_millis(200));
+        check!(config.reconnect_delay(2, 101) == Duration::from_millis(100));
+        for random in [1, 7, 12345, u64::MAX] {
+            let delay = config.reconnect_delay(10, random);
+            check!(delay >= Duration::from_millis(500));
+            check!(delay <= Duration::from_millis(1000));
+        }
+    }
+
+    #[test]
+    fn reconnect_delay_without_initial_delay() {
+        let config = StreamConfig {
+            reconnect_initial_delay_ms: 0,
+            ..config(true)
+        };
+
+        check!(config.reconnect_delay(3, u64::MAX) == Duration::ZERO);
+    }
+}
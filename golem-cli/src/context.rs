@@ -17,7 +17,8 @@ use crate::auth::{Auth, CloudAuthentication};
 use crate::cloud::{AccountId, CloudAuthenticationConfig};
 use crate::command::GolemCliGlobalFlags;
 use crate::config::{
-    ClientConfig, HttpClientConfig, NamedProfile, Profile, ProfileKind, ProfileName,
+    ClientConfig, Config, HttpClientConfig, NamedProfile, Profile, ProfileKind, ProfileName,
+    StreamConfig,
 };
 use crate::error::HintError;
 use crate::log::{set_log_output, LogOutput, Output};
@@ -52,9 +53,8 @@ use golem_cloud_client::api::WorkerClientLive as WorkerClientCloud;
 use golem_cloud_client::api::{AccountClientLive as AccountClientCloud, LoginClientLive};
 use golem_cloud_client::{Context as ContextCloud, Security};
 use golem_rib_repl::ReplDependencies;
-use golem_templates::model::{ComposableAppGroupName, GuestLanguage};
-use golem_templates::ComposableAppTemplate;
-use std::collections::{BTreeMap, HashSet};
+use golem_templates::ComposableAppTemplates;
+use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tracing::debug;
@@ -78,9 +78,7 @@ pub struct Context {
 
     // Lazy initialized
     clients: tokio::sync::OnceCell<Clients>,
-    templates: std::sync::OnceLock<
-        BTreeMap<GuestLanguage, BTreeMap<ComposableAppGroupName, ComposableAppTemplate>>,
-    >,
+    templates: std::sync::OnceLock<Templates>,
 
     // Directly mutable
     app_context_state: tokio::sync::RwLock<ApplicationContextState>,
@@ -161,6 +159,10 @@ impl Context {
         state.silent_init = true;
     }
 
+    pub fn profile_name(&self) -> &ProfileName {
+        &self.profile_name
+    }
+
     pub fn profile_kind(&self) -> ProfileKind {
         self.profile_kind
     }
@@ -173,6 +175,14 @@ impl Context {
         self.http_batch_size
     }
 
+    pub fn stream_config(&self) -> &StreamConfig {
+        &self.profile.get_config().stream
+    }
+
+    pub fn invocation_journal_enabled(&self) -> bool {
+        self.profile.get_config().invocation_journal
+    }
+
     pub async fn clients(&self) -> anyhow::Result<&Clients> {
         self.clients
             .get_or_try_init(|| async {
@@ -301,14 +311,60 @@ impl Context {
         }
     }
 
-    pub fn templates(
-        &self,
-    ) -> &BTreeMap<GuestLanguage, BTreeMap<ComposableAppGroupName, ComposableAppTemplate>> {
-        self.templates
-            .get_or_init(golem_templates::all_composable_app_templates)
+    pub fn templates(&self) -> &ComposableAppTemplates {
+        &self.loaded_templates().templates
+    }
+
+    pub fn template_repository_errors(&self) -> &[String] {
+        &self.loaded_templates().repository_errors
+    }
+
+    fn loaded_templates(&self) -> &Templates {
+        self.templates.get_or_init(|| {
+            let mut templates = golem_templates::all_composable_app_templates();
+            let mut repository_errors = vec![];
+
+            match Config::get_template_repositories(&self.config_dir) {
+                Ok(repositories) => {
+                    for repository in repositories {
+                        match golem_templates::add_local_composable_app_templates(
+                            &mut templates,
+                            &repository.name,
+                            &repository.path,
+                        ) {
+                            Ok(warnings) => {
+                                repository_errors.extend(warnings.into_iter().map(|warning| {
+                                    format!("Template repository {}: {}", repository.name, warning)
+                                }))
+                            }
+                            Err(err) => repository_errors.push(format!(
+                                "Failed to load templates from repository {}: {:#}",
+                                repository.name, err
+                            )),
+                        }
+                    }
+                }
+                Err(err) => repository_errors.push(format!(
+                    "Failed to read template repositories from the config: {:#}",
+                    err
+                )),
+            }
+
+            Templates {
+                templates,
+                repository_errors,
+            }
+        })
     }
 }
 
+struct Templates {
+    templates: ComposableAppTemplates,
+    // Custom template repositories are optional, so their errors are only reported
+    // by the command handlers using the templates
+    repository_errors: Vec<String>,
+}
+
 // TODO: add healthcheck clients
 pub struct Clients {
     pub golem: GolemClients,
@@ -18,6 +18,25 @@ impl Display for NonSuccessfulExit {
 
 impl Error for NonSuccessfulExit {}
 
+// InvokedFunctionReturnedErr is used to signal that an invocation succeeded, and its result was
+// already printed, but the invoked function returned a result::err, so the process should exit
+// with a dedicated exit code, which can be used in scripts to branch on application errors.
+#[derive(Debug)]
+pub struct InvokedFunctionReturnedErr;
+
+impl InvokedFunctionReturnedErr {
+    pub const EXIT_CODE: u8 = 3;
+}
+
+impl Display for InvokedFunctionReturnedErr {
+    fn fmt(&self, _f: &mut Formatter<'_>) -> std::fmt::Result {
+        //NOP
+        Ok(())
+    }
+}
+
+impl Error for InvokedFunctionReturnedErr {}
+
 /// Errors that should be handled by the command handler with showing hints or error messages
 #[derive(Debug, Display)]
 pub enum HintError {
@@ -80,6 +99,23 @@ pub mod service {
         UnexpectedResponse { status_code: u16, payload: Bytes },
     }
 
+    impl ServiceError {
+        /// Short description of the error kind without the error details, used for grouping errors
+        pub fn kind_name(&self) -> String {
+            match &self.kind {
+                ServiceErrorKind::ErrorResponse(response) => {
+                    format!("Error response ({})", response.status_code)
+                }
+                ServiceErrorKind::ReqwestError(_) => "HTTP client error".to_string(),
+                ServiceErrorKind::ReqwestHeaderError(_) => "HTTP header error".to_string(),
+                ServiceErrorKind::SerdeError(_) => "Serialization error".to_string(),
+                ServiceErrorKind::UnexpectedResponse { status_code, .. } => {
+                    format!("Unexpected response ({})", status_code)
+                }
+            }
+        }
+    }
+
     impl Display for ServiceError {
         fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
             fn format_status_code(status_code: u16) -> String {
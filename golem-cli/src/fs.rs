@@ -14,6 +14,8 @@
 
 use crate::log::LogColorize;
 use anyhow::{anyhow, bail, Context, Error};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 use std::cmp::PartialEq;
 use std::fs::{Metadata, OpenOptions};
 use std::io::Write;
@@ -132,6 +134,65 @@ pub fn append_str<P: AsRef<Path>, S: AsRef<str>>(path: P, str: S) -> anyhow::Res
     file.write(str.as_bytes()).with_context(context).map(|_| ())
 }
 
+/// Runs the given read-modify-write of the path while holding an exclusive lock
+/// on `<path>.lock`, so concurrent CLI processes do not lose each other's changes
+pub fn with_lock<P: AsRef<Path>, R, F: FnOnce() -> anyhow::Result<R>>(
+    path: P,
+    update: F,
+) -> anyhow::Result<R> {
+    let mut lock_path = path.as_ref().as_os_str().to_os_string();
+    lock_path.push(".lock");
+    let lock_path = PathBuf::from(lock_path);
+
+    let context = || anyhow!("Failed to lock {}", lock_path.log_color_highlight());
+
+    let target_parent = lock_path.parent().with_context(context)?;
+    create_dir_all(target_parent).with_context(context)?;
+    let lock_file = OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(&lock_path)
+        .with_context(context)?;
+    lock_file.lock().with_context(context)?;
+
+    // The lock is released when the lock file is closed
+    update()
+}
+
+/// Reads the JSON file while holding its lock, a missing file is read as the default value
+pub fn read_json_with_lock<P: AsRef<Path>, T: DeserializeOwned + Default, R>(
+    path: P,
+    read: impl FnOnce(T) -> R,
+) -> anyhow::Result<R> {
+    let path = path.as_ref();
+    with_lock(path, || Ok(read(read_json_or_default(path)?)))
+}
+
+/// Updates the JSON file while holding its lock, a missing file is updated starting from the
+/// default value
+pub fn update_json_with_lock<P: AsRef<Path>, T: Serialize + DeserializeOwned + Default, R>(
+    path: P,
+    update: impl FnOnce(&mut T) -> R,
+) -> anyhow::Result<R> {
+    let path = path.as_ref();
+    with_lock(path, || {
+        let mut value = read_json_or_default(path)?;
+        let result = update(&mut value);
+        write_str(path, serde_json::to_string_pretty(&value)?)?;
+        Ok(result)
+    })
+}
+
+fn read_json_or_default<T: DeserializeOwned + Default>(path: &Path) -> anyhow::Result<T> {
+    if !path.exists() {
+        return Ok(T::default());
+    }
+
+    serde_json::from_str(&read_to_string(path)?)
+        .with_context(|| anyhow!("Failed to deserialize {}", path.log_color_highlight()))
+}
+
 pub fn write<P: AsRef<Path>, C: AsRef<[u8]>>(path: P, contents: C) -> anyhow::Result<()> {
     let path = PathExtra(path);
 
@@ -547,6 +608,7 @@ pub fn compile_and_collect_globs(root_dir: &Path, globs: &[String]) -> Result<Ve
 
 #[cfg(test)]
 mod test {
+    use crate::fs;
     use crate::fs::resolve_relative_glob;
     use assert2::check;
     use std::path::PathBuf;
@@ -570,4 +632,31 @@ mod test {
                 == (base_dir.join("../../../"), "target/a".to_string())
         );
     }
+
+    #[test]
+    fn with_lock_serializes_updates() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("counter");
+        fs::write_str(&path, "0").unwrap();
+
+        let threads = (0..8)
+            .map(|_| {
+                let path = path.clone();
+                std::thread::spawn(move || {
+                    for _ in 0..25 {
+                        fs::with_lock(&path, || {
+                            let counter: u64 = fs::read_to_string(&path)?.parse()?;
+                            fs::write_str(&path, (counter + 1).to_string())
+                        })
+                        .unwrap();
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+
+        check!(fs::read_to_string(&path).unwrap() == "200");
+    }
 }
@@ -15,6 +15,7 @@
 use crate::fs::{OverwriteSafeAction, OverwriteSafeActionPlan, PathExtra};
 use colored::{ColoredString, Colorize};
 use std::borrow::Cow;
+use std::io::IsTerminal;
 use std::path::{Path, PathBuf};
 use std::sync::{LazyLock, OnceLock, RwLock};
 use terminal_size::terminal_size;
@@ -154,6 +155,18 @@ pub fn log_error_action<T: AsRef<str>>(action: &str, subject: T) {
     ));
 }
 
+/// Clears the screen, if the log is written to a terminal
+pub fn log_clear_screen() {
+    let state = LOG_STATE.read().unwrap();
+
+    const CLEAR_SCREEN: &str = "\x1B[2J\x1B[1;1H";
+    match state.output {
+        Output::Stdout if std::io::stdout().is_terminal() => print!("{CLEAR_SCREEN}"),
+        Output::Stderr if std::io::stderr().is_terminal() => eprint!("{CLEAR_SCREEN}"),
+        Output::Stdout | Output::Stderr | Output::None | Output::TracingDebug => {}
+    }
+}
+
 pub fn logln<T: AsRef<str>>(message: T) {
     logln_internal(message.as_ref());
 }
@@ -18,6 +18,7 @@ use std::hash::Hash;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use url::Url;
+use wax::{Glob, Pattern};
 use wit_parser::PackageName;
 
 pub const DEFAULT_CONFIG_FILE_NAME: &str = "golem.yaml";
@@ -96,6 +97,7 @@ pub enum AppBuildStep {
     Componentize,
     Link,
     AddMetadata,
+    Optimize,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -421,6 +423,14 @@ impl Application {
         self.temp_dir().join("task-results")
     }
 
+    pub fn component_sizes_file(&self) -> PathBuf {
+        self.temp_dir().join("component-sizes.json")
+    }
+
+    pub fn component_file_keys_file(&self) -> PathBuf {
+        self.temp_dir().join("component-file-keys.json")
+    }
+
     pub fn rib_repl_history_file(&self) -> PathBuf {
         self.temp_dir().join(".rib_repl_history")
     }
@@ -680,6 +690,10 @@ pub struct ComponentProperties {
     pub clean: Vec<String>,
     pub component_type: AppComponentType,
     pub files: Vec<InitialComponentFile>,
+    pub variables: HashMap<String, String>,
+    pub strip_sections: Vec<app_raw::StripSection>,
+    /// Size budget of the final linked WASM in bytes
+    pub max_size: Option<u64>,
 }
 
 impl ComponentProperties {
@@ -689,6 +703,7 @@ impl ComponentProperties {
         raw: app_raw::ComponentProperties,
     ) -> Option<Self> {
         let files = InitialComponentFile::from_raw_vec(validation, source, raw.files)?;
+        let max_size = validate_max_size(validation, raw.max_size)?;
 
         Some(Self {
             source_wit: raw.source_wit.unwrap_or_default(),
@@ -700,6 +715,9 @@ impl ComponentProperties {
             clean: raw.clean,
             component_type: raw.component_type.unwrap_or_default(),
             files,
+            variables: raw.variables,
+            strip_sections: raw.strip_sections,
+            max_size,
         })
     }
 
@@ -773,6 +791,28 @@ impl ComponentProperties {
             }
         }
 
+        if !overrides.variables.is_empty() {
+            any_overrides = true;
+            self.variables.extend(overrides.variables);
+        }
+
+        if !overrides.strip_sections.is_empty() {
+            any_overrides = true;
+            self.strip_sections = overrides.strip_sections;
+        }
+
+        if overrides.max_size.is_some() {
+            any_overrides = true;
+            match validate_max_size(validation, overrides.max_size) {
+                Some(max_size) => {
+                    self.max_size = max_size;
+                }
+                None => {
+                    any_errors = true;
+                }
+            }
+        }
+
         Ok((!any_errors).then_some((self, any_overrides)))
     }
 
@@ -789,10 +829,40 @@ impl ComponentProperties {
     }
 }
 
+fn validate_max_size(
+    validation: &mut ValidationBuilder,
+    max_size: Option<u64>,
+) -> Option<Option<u64>> {
+    if max_size == Some(0) {
+        validation.add_error(format!(
+            "Property {} must be greater than zero",
+            "maxSize".log_color_highlight()
+        ));
+        None
+    } else {
+        Some(max_size)
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct InitialComponentFile {
     pub source: InitialComponentFileSource,
     pub target: ComponentFilePathWithPermissions,
+    /// Pattern of the included files relative to the source directory, used for glob sources
+    pub include: Option<Glob<'static>>,
+    pub exclude: Vec<Glob<'static>>,
+    /// Permission overrides, the first rule matching the relative path of a file is used
+    pub permission_rules: Vec<InitialComponentFilePermissionRule>,
+    /// Render the file contents with the component variables when creating the IFS archive
+    pub template: bool,
+    /// Total size of the loaded files above which a warning is shown
+    pub size_limit: Option<u64>,
+}
+
+#[derive(Clone, Debug)]
+pub struct InitialComponentFilePermissionRule {
+    pub pattern: Glob<'static>,
+    pub permissions: ComponentFilePermissions,
 }
 
 impl InitialComponentFile {
@@ -801,22 +871,79 @@ impl InitialComponentFile {
         source: &Path,
         file: app_raw::InitialComponentFile,
     ) -> Option<InitialComponentFile> {
-        let source = InitialComponentFileSource::new(&file.source_path, source)
-            .map_err(|err| {
-                validation.push_context("source path", file.source_path.to_string());
-                validation.add_error(err);
-                validation.pop_context();
+        validation.push_context("source path", file.source_path.clone());
+
+        let (source_path, include) = match split_glob_source(&file.source_path) {
+            Some((source_path, include)) => (source_path, Some(include)),
+            None => (file.source_path.clone(), None),
+        };
+
+        let source = InitialComponentFileSource::new(&source_path, source)
+            .map_err(|err| validation.add_error(err))
+            .ok();
+        let mut valid = source.is_some();
+
+        if let Some(source) = &source {
+            if !source.is_local() && (!file.exclude.is_empty() || !file.permission_rules.is_empty())
+            {
+                validation.add_error(format!(
+                    "Properties {} and {} are only supported for local sources",
+                    "exclude".log_color_highlight(),
+                    "permissionRules".log_color_highlight()
+                ));
+                valid = false;
+            }
+        }
+
+        let exclude = file
+            .exclude
+            .iter()
+            .filter_map(|pattern| compile_file_glob(validation, "exclude pattern", pattern))
+            .collect::<Vec<_>>();
+
+        let permission_rules = file
+            .permission_rules
+            .iter()
+            .filter_map(|rule| {
+                compile_file_glob(validation, "permission rule pattern", &rule.pattern).map(
+                    |pattern| InitialComponentFilePermissionRule {
+                        pattern,
+                        permissions: rule.permissions,
+                    },
+                )
             })
-            .ok()?;
+            .collect::<Vec<_>>();
+
+        if file.size_limit == Some(0) {
+            validation.add_error(format!(
+                "Property {} must be greater than zero",
+                "sizeLimit".log_color_highlight()
+            ));
+            valid = false;
+        }
+
+        validation.pop_context();
+
+        let valid = valid
+            && exclude.len() == file.exclude.len()
+            && permission_rules.len() == file.permission_rules.len();
+        if !valid {
+            return None;
+        }
 
         Some(InitialComponentFile {
-            source,
+            source: source?,
             target: ComponentFilePathWithPermissions {
                 path: file.target_path,
                 permissions: file
                     .permissions
                     .unwrap_or(ComponentFilePermissions::ReadOnly),
             },
+            include,
+            exclude,
+            permission_rules,
+            template: file.template.unwrap_or_default(),
+            size_limit: file.size_limit,
         })
     }
 
@@ -834,6 +961,56 @@ impl InitialComponentFile {
 
         (files.len() == source_count).then_some(files)
     }
+
+    /// Checks a file or directory path, relative to the source, against the exclude patterns
+    pub fn is_excluded(&self, relative_path: &Path) -> bool {
+        self.exclude.iter().any(|glob| glob.is_match(relative_path))
+    }
+
+    /// Checks a file path, relative to the source, against the include and exclude patterns
+    pub fn is_included(&self, relative_path: &Path) -> bool {
+        self.include
+            .as_ref()
+            .is_none_or(|glob| glob.is_match(relative_path))
+            && !self.is_excluded(relative_path)
+    }
+
+    pub fn permissions(&self, relative_path: &Path) -> ComponentFilePermissions {
+        self.permission_rules
+            .iter()
+            .find(|rule| rule.pattern.is_match(relative_path))
+            .map(|rule| rule.permissions)
+            .unwrap_or(self.target.permissions)
+    }
+}
+
+// Local source paths with wildcards are split into the base directory and the pattern of the
+// included files, other sources (and paths which are not valid globs) are used as they are
+fn split_glob_source(source_path: &str) -> Option<(String, Glob<'static>)> {
+    if Url::parse(source_path).is_ok() {
+        return None;
+    }
+    let glob = Glob::new(source_path).ok()?;
+    if glob.variance().is_invariant() {
+        return None;
+    }
+    let (base_dir, glob) = glob.partition();
+    Some((base_dir.to_string_lossy().to_string(), glob.into_owned()))
+}
+
+fn compile_file_glob(
+    validation: &mut ValidationBuilder,
+    context: &'static str,
+    pattern: &str,
+) -> Option<Glob<'static>> {
+    Glob::new(pattern)
+        .map(Glob::into_owned)
+        .map_err(|err| {
+            validation.push_context(context, pattern.to_string());
+            validation.add_error(format!("Invalid glob pattern: {}", err));
+            validation.pop_context();
+        })
+        .ok()
 }
 
 #[derive(Clone, Debug)]
@@ -880,6 +1057,10 @@ impl InitialComponentFileSource {
         &self.0
     }
 
+    pub fn is_local(&self) -> bool {
+        matches!(self.0.scheme(), "file" | "")
+    }
+
     pub fn into_url(self) -> Url {
         self.0
     }
@@ -1705,3 +1886,154 @@ mod app_builder {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::model::app::{split_glob_source, InitialComponentFile};
+    use crate::model::app_raw;
+    use crate::validation::ValidationBuilder;
+    use assert2::{check, let_assert};
+    use golem_common::model::ComponentFilePermissions;
+    use std::path::Path;
+    use wax::Pattern;
+
+    fn initial_component_file(raw: &str) -> Option<InitialComponentFile> {
+        let raw = serde_yaml::from_str::<app_raw::InitialComponentFile>(raw).unwrap();
+        let mut validation = ValidationBuilder::new();
+        let file = InitialComponentFile::from_raw(
+            &mut validation,
+            &std::env::temp_dir().join("golem.yaml"),
+            raw,
+        );
+        check!(file.is_some() != validation.has_any_errors());
+        file
+    }
+
+    #[test]
+    fn split_glob_source_of_local_patterns() {
+        let_assert!(Some((base_dir, glob)) = split_glob_source("assets/**/*.json"));
+        check!(base_dir == "assets");
+        check!(glob.is_match(Path::new("a.json")));
+        check!(glob.is_match(Path::new("config/a.json")));
+        check!(!glob.is_match(Path::new("a.txt")));
+
+        let_assert!(Some((base_dir, glob)) = split_glob_source("*.txt"));
+        check!(base_dir == "");
+        check!(glob.is_match(Path::new("a.txt")));
+    }
+
+    #[test]
+    fn split_glob_source_keeps_plain_paths_and_urls() {
+        check!(split_glob_source("assets").is_none());
+        check!(split_glob_source("assets/config.json").is_none());
+        check!(split_glob_source("https://example.com/files/*.txt").is_none());
+    }
+
+    #[test]
+    fn initial_component_file_include_and_exclude() {
+        let_assert!(
+            Some(file) = initial_component_file(
+                r#"
+                sourcePath: assets/**/*.json
+                targetPath: /data
+                exclude:
+                  - tmp
+                  - "**/*.local.json"
+                "#,
+            )
+        );
+
+        check!(file.is_included(Path::new("a.json")));
+        check!(file.is_included(Path::new("config/a.json")));
+        check!(!file.is_included(Path::new("a.txt")));
+        check!(!file.is_included(Path::new("config/a.local.json")));
+
+        check!(file.is_excluded(Path::new("tmp")));
+        check!(!file.is_excluded(Path::new("config")));
+    }
+
+    #[test]
+    fn initial_component_file_without_include_pattern() {
+        let_assert!(
+            Some(file) = initial_component_file(
+                r#"
+                sourcePath: assets
+                targetPath: /data
+                "#,
+            )
+        );
+
+        check!(file.include.is_none());
+        check!(file.is_included(Path::new("a.txt")));
+        check!(file.is_included(Path::new("config/a.json")));
+    }
+
+    #[test]
+    fn initial_component_file_permissions_use_first_matching_rule() {
+        let_assert!(
+            Some(file) = initial_component_file(
+                r#"
+                sourcePath: assets
+                targetPath: /data
+                permissions: read-write
+                permissionRules:
+                  - pattern: config/defaults.json
+                    permissions: read-write
+                  - pattern: config/**
+                    permissions: read-only
+                "#,
+            )
+        );
+
+        check!(file.permissions(Path::new("config/a.json")) == ComponentFilePermissions::ReadOnly);
+        check!(
+            file.permissions(Path::new("config/defaults.json"))
+                == ComponentFilePermissions::ReadWrite
+        );
+        check!(file.permissions(Path::new("state.json")) == ComponentFilePermissions::ReadWrite);
+    }
+
+    #[test]
+    fn initial_component_file_validation() {
+        check!(initial_component_file(
+            r#"
+            sourcePath: assets
+            targetPath: /data
+            exclude:
+              - "[a"
+            "#,
+        )
+        .is_none());
+
+        check!(initial_component_file(
+            r#"
+            sourcePath: https://example.com/assets
+            targetPath: /data
+            permissionRules:
+              - pattern: "*.json"
+                permissions: read-write
+            "#,
+        )
+        .is_none());
+
+        check!(initial_component_file(
+            r#"
+            sourcePath: assets
+            targetPath: /data
+            sizeLimit: 0
+            "#,
+        )
+        .is_none());
+
+        check!(initial_component_file(
+            r#"
+            sourcePath: https://example.com/assets/config.json
+            targetPath: /data/config.json
+            template: true
+            "#,
+        )
+        .is_some());
+    }
+}
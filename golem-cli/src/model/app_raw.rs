@@ -92,6 +92,21 @@ pub struct InitialComponentFile {
     pub source_path: String,
     pub target_path: ComponentFilePath,
     pub permissions: Option<ComponentFilePermissions>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub permission_rules: Vec<InitialComponentFilePermissionRule>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size_limit: Option<u64>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct InitialComponentFilePermissionRule {
+    pub pattern: String,
+    pub permissions: ComponentFilePermissions,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -115,6 +130,24 @@ pub struct ComponentProperties {
     pub component_type: Option<AppComponentType>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub files: Vec<InitialComponentFile>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub variables: HashMap<String, String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub strip_sections: Vec<StripSection>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_size: Option<u64>,
+}
+
+/// Custom sections which can be removed from the final linked WASM
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum StripSection {
+    /// DWARF debug information and source maps
+    Debug,
+    /// Toolchain producer information
+    Producers,
+    /// Function and local names of the nested modules and components
+    Names,
 }
 
 impl ComponentProperties {
@@ -153,6 +186,18 @@ impl ComponentProperties {
             vec.push("files");
         }
 
+        if !self.variables.is_empty() {
+            vec.push("variables");
+        }
+
+        if !self.strip_sections.is_empty() {
+            vec.push("stripSections");
+        }
+
+        if self.max_size.is_some() {
+            vec.push("maxSize");
+        }
+
         vec
     }
 }
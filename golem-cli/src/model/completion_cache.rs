@@ -0,0 +1,184 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::fs;
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+// Short enough to pick up new components and workers, but long enough to cover
+// the repeated requests of a single completion session
+const COMPLETION_CACHE_TTL: TimeDelta = TimeDelta::seconds(30);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CompletionCacheEntry {
+    created_at: DateTime<Utc>,
+    values: Vec<String>,
+}
+
+impl CompletionCacheEntry {
+    fn is_expired(&self) -> bool {
+        self.created_at + COMPLETION_CACHE_TTL < Utc::now()
+    }
+}
+
+/// Short-lived cache of dynamic completion candidates, stored in the config directory
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompletionCache {
+    entries: BTreeMap<String, CompletionCacheEntry>,
+}
+
+impl CompletionCache {
+    fn path(config_dir: &Path) -> PathBuf {
+        config_dir.join("completion-cache.json")
+    }
+
+    // Being only a cache, a missing or broken file is handled as empty, which also covers
+    // reading it while another process is writing it
+    fn from_path(path: &Path) -> Self {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn get(config_dir: &Path, key: &str) -> Option<Vec<String>> {
+        Self::from_path(&Self::path(config_dir))
+            .entries
+            .remove(key)
+            .filter(|entry| !entry.is_expired())
+            .map(|entry| entry.values)
+    }
+
+    /// Stores the values while holding the lock of the file, as completions running
+    /// in multiple shells can update it concurrently
+    pub fn set(config_dir: &Path, key: String, values: Vec<String>) -> anyhow::Result<()> {
+        let path = Self::path(config_dir);
+        fs::with_lock(&path, || {
+            let mut cache = Self::from_path(&path);
+            cache.entries.retain(|_, entry| !entry.is_expired());
+            cache.entries.insert(
+                key,
+                CompletionCacheEntry {
+                    created_at: Utc::now(),
+                    values,
+                },
+            );
+            fs::write_str(&path, serde_json::to_string(&cache)?)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::fs;
+    use crate::model::completion_cache::{
+        CompletionCache, CompletionCacheEntry, COMPLETION_CACHE_TTL,
+    };
+    use assert2::check;
+    use chrono::{TimeDelta, Utc};
+
+    fn values(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn get_returns_set_values() {
+        let config_dir = tempfile::tempdir().unwrap();
+
+        check!(CompletionCache::get(config_dir.path(), "components").is_none());
+
+        CompletionCache::set(
+            config_dir.path(),
+            "components".to_string(),
+            values(&["comp-a", "comp-b"]),
+        )
+        .unwrap();
+        CompletionCache::set(
+            config_dir.path(),
+            "workers".to_string(),
+            values(&["comp-a/w1"]),
+        )
+        .unwrap();
+
+        check!(
+            CompletionCache::get(config_dir.path(), "components")
+                == Some(values(&["comp-a", "comp-b"]))
+        );
+        check!(CompletionCache::get(config_dir.path(), "workers") == Some(values(&["comp-a/w1"])));
+        check!(CompletionCache::get(config_dir.path(), "functions").is_none());
+
+        CompletionCache::set(
+            config_dir.path(),
+            "components".to_string(),
+            values(&["comp-c"]),
+        )
+        .unwrap();
+        check!(CompletionCache::get(config_dir.path(), "components") == Some(values(&["comp-c"])));
+    }
+
+    #[test]
+    fn expired_entries_are_not_returned_and_get_pruned() {
+        let config_dir = tempfile::tempdir().unwrap();
+        let path = CompletionCache::path(config_dir.path());
+
+        let mut cache = CompletionCache::default();
+        cache.entries.insert(
+            "expired".to_string(),
+            CompletionCacheEntry {
+                created_at: Utc::now() - COMPLETION_CACHE_TTL - TimeDelta::seconds(1),
+                values: values(&["old"]),
+            },
+        );
+        cache.entries.insert(
+            "fresh".to_string(),
+            CompletionCacheEntry {
+                created_at: Utc::now(),
+                values: values(&["new"]),
+            },
+        );
+        fs::write_str(&path, serde_json::to_string(&cache).unwrap()).unwrap();
+
+        check!(CompletionCache::get(config_dir.path(), "expired").is_none());
+        check!(CompletionCache::get(config_dir.path(), "fresh") == Some(values(&["new"])));
+
+        CompletionCache::set(config_dir.path(), "other".to_string(), values(&["x"])).unwrap();
+
+        let cache: CompletionCache =
+            serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        check!(cache.entries.keys().cloned().collect::<Vec<_>>() == values(&["fresh", "other"]));
+    }
+
+    #[test]
+    fn broken_file_is_handled_as_empty() {
+        let config_dir = tempfile::tempdir().unwrap();
+        let path = CompletionCache::path(config_dir.path());
+        fs::write_str(&path, "{\"entries\":").unwrap();
+
+        check!(CompletionCache::get(config_dir.path(), "components").is_none());
+
+        CompletionCache::set(
+            config_dir.path(),
+            "components".to_string(),
+            values(&["comp-a"]),
+        )
+        .unwrap();
+        check!(CompletionCache::get(config_dir.path(), "components") == Some(values(&["comp-a"])));
+    }
+}
@@ -23,6 +23,7 @@ use golem_client::model::{
 };
 use golem_common::model::component_metadata::DynamicLinkedInstance;
 use golem_common::model::trim_date::TrimDateTime;
+use golem_common::model::ComponentFilePermissions;
 use golem_wasm_ast::analysis::wave::DisplayNamedFunc;
 use golem_wasm_ast::analysis::{
     AnalysedExport, AnalysedFunction, AnalysedInstance, AnalysedResourceMode, NameOptionTypePair,
@@ -228,6 +229,150 @@ impl From<&Component> for ComponentView {
     }
 }
 
+impl Component {
+    /// Checks if the component exports the given interface, in any version
+    pub fn exports_interface(&self, interface_name: &str) -> bool {
+        self.metadata.exports.iter().any(|export| match export {
+            AnalysedExport::Instance(instance) => {
+                instance.name.split('@').next() == Some(interface_name)
+            }
+            AnalysedExport::Function(_) => false,
+        })
+    }
+}
+
+/// Initial file of a component, identified by a hash of its content. Files without a known hash
+/// are always treated as changed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComponentFileHash {
+    pub path: String,
+    pub permissions: ComponentFilePermissions,
+    pub hash: Option<String>,
+}
+
+impl ComponentFileHash {
+    fn is_same(&self, other: &ComponentFileHash) -> bool {
+        self.hash.is_some() && self == other
+    }
+}
+
+/// Uses the server assigned file key as the hash, so it is only comparable with other deployed files
+impl From<&InitialComponentFile> for ComponentFileHash {
+    fn from(value: &InitialComponentFile) -> Self {
+        Self {
+            path: value.path.to_string(),
+            permissions: value.permissions,
+            hash: Some(value.key.0.clone()),
+        }
+    }
+}
+
+/// Local content hashes of the uploaded initial files, by the keys the server assigned to them.
+///
+/// The server does not report content hashes, and the format of its file keys is not part of
+/// the API, so deployed files can only be compared with local ones through the keys recorded
+/// on earlier deploys.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComponentFileKeys(BTreeMap<String, String>);
+
+impl ComponentFileKeys {
+    pub fn record(
+        &mut self,
+        deployed: &[InitialComponentFile],
+        local_hashes: &BTreeMap<String, String>,
+    ) {
+        for file in deployed {
+            if let Some(hash) = local_hashes.get(&file.path.to_string()) {
+                self.0.insert(file.key.0.clone(), hash.clone());
+            }
+        }
+    }
+
+    pub fn deployed_file_hash(&self, file: &InitialComponentFile) -> ComponentFileHash {
+        ComponentFileHash {
+            path: file.path.to_string(),
+            permissions: file.permissions,
+            hash: self.0.get(&file.key.0).cloned(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ComponentFileChangeKind {
+    Added,
+    /// Content or permissions changed
+    Changed,
+    Removed,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentFileChange {
+    pub path: String,
+    pub kind: ComponentFileChangeKind,
+}
+
+/// Differences between the initial files of the deployed component version and the local ones
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentFilesDiff {
+    pub component_name: ComponentName,
+    pub unchanged: usize,
+    pub changes: Vec<ComponentFileChange>,
+}
+
+impl ComponentFilesDiff {
+    pub fn new(
+        component_name: ComponentName,
+        deployed: impl IntoIterator<Item = ComponentFileHash>,
+        local: impl IntoIterator<Item = ComponentFileHash>,
+    ) -> Self {
+        let mut deployed = deployed
+            .into_iter()
+            .map(|file| (file.path.clone(), file))
+            .collect::<BTreeMap<_, _>>();
+
+        let mut unchanged = 0;
+        let mut changes = Vec::new();
+        for file in local {
+            match deployed.remove(&file.path) {
+                Some(deployed_file) if deployed_file.is_same(&file) => unchanged += 1,
+                Some(_) => changes.push(ComponentFileChange {
+                    path: file.path,
+                    kind: ComponentFileChangeKind::Changed,
+                }),
+                None => changes.push(ComponentFileChange {
+                    path: file.path,
+                    kind: ComponentFileChangeKind::Added,
+                }),
+            }
+        }
+        changes.extend(deployed.into_keys().map(|path| ComponentFileChange {
+            path,
+            kind: ComponentFileChangeKind::Removed,
+        }));
+        changes.sort_by(|a, b| a.path.cmp(&b.path));
+
+        Self {
+            component_name,
+            unchanged,
+            changes,
+        }
+    }
+
+    pub fn has_changes(&self) -> bool {
+        !self.changes.is_empty()
+    }
+
+    pub fn count(&self, kind: ComponentFileChangeKind) -> usize {
+        self.changes
+            .iter()
+            .filter(|change| change.kind == kind)
+            .count()
+    }
+}
+
 pub fn render_type(typ: &AnalysedType) -> String {
     match typ {
         AnalysedType::Variant(TypeVariant { cases }) => {
@@ -436,7 +581,14 @@ pub fn function_params_types<'t>(
 mod tests {
     use test_r::test;
 
-    use crate::model::component::render_exported_function;
+    use crate::model::component::{
+        render_exported_function, ComponentFileChange, ComponentFileChangeKind, ComponentFileHash,
+        ComponentFileKeys, ComponentFilesDiff,
+    };
+    use golem_client::model::InitialComponentFile;
+    use golem_common::model::{
+        ComponentFilePath, ComponentFilePermissions, InitialComponentFileKey,
+    };
     use golem_wasm_ast::analysis::analysed_type::{
         bool, case, chr, f32, f64, field, flags, handle, list, option, r#enum, record, result,
         result_err, result_ok, s16, s32, s64, s8, str, tuple, u16, u32, u64, u8, unit_case,
@@ -446,6 +598,7 @@ mod tests {
         AnalysedFunction, AnalysedFunctionParameter, AnalysedFunctionResult, AnalysedResourceId,
         AnalysedResourceMode, AnalysedType,
     };
+    use std::collections::BTreeMap;
 
     #[test]
     fn show_exported_function_handles_type_handle() {
@@ -737,4 +890,120 @@ mod tests {
     fn same_export_for_bool() {
         ensure_same_export(bool(), "bool")
     }
+
+    #[test]
+    fn files_diff_reports_added_changed_and_removed_files() {
+        let file = |path: &str, permissions, hash: &str| ComponentFileHash {
+            path: path.to_string(),
+            permissions,
+            hash: Some(hash.to_string()),
+        };
+
+        let diff = ComponentFilesDiff::new(
+            "ns:component".into(),
+            [
+                file("/a.txt", ComponentFilePermissions::ReadOnly, "1"),
+                file("/b.txt", ComponentFilePermissions::ReadOnly, "2"),
+                file("/c.txt", ComponentFilePermissions::ReadOnly, "3"),
+                file("/d.txt", ComponentFilePermissions::ReadOnly, "4"),
+            ],
+            [
+                file("/a.txt", ComponentFilePermissions::ReadOnly, "1"),
+                file("/b.txt", ComponentFilePermissions::ReadOnly, "5"),
+                file("/c.txt", ComponentFilePermissions::ReadWrite, "3"),
+                file("/e.txt", ComponentFilePermissions::ReadOnly, "6"),
+            ],
+        );
+
+        assert_eq!(diff.unchanged, 1);
+        assert_eq!(
+            diff.changes,
+            vec![
+                ComponentFileChange {
+                    path: "/b.txt".to_string(),
+                    kind: ComponentFileChangeKind::Changed,
+                },
+                ComponentFileChange {
+                    path: "/c.txt".to_string(),
+                    kind: ComponentFileChangeKind::Changed,
+                },
+                ComponentFileChange {
+                    path: "/d.txt".to_string(),
+                    kind: ComponentFileChangeKind::Removed,
+                },
+                ComponentFileChange {
+                    path: "/e.txt".to_string(),
+                    kind: ComponentFileChangeKind::Added,
+                },
+            ]
+        );
+
+        let diff = ComponentFilesDiff::new(
+            "ns:component".into(),
+            [file("/a.txt", ComponentFilePermissions::ReadOnly, "1")],
+            [file("/a.txt", ComponentFilePermissions::ReadOnly, "1")],
+        );
+        assert!(!diff.has_changes());
+    }
+
+    #[test]
+    fn files_diff_with_unknown_deployed_hashes() {
+        let deployed = |path: &str, key: &str| InitialComponentFile {
+            key: InitialComponentFileKey(key.to_string()),
+            path: ComponentFilePath::from_abs_str(path).unwrap(),
+            permissions: ComponentFilePermissions::ReadOnly,
+        };
+        let local = |path: &str, hash: &str| ComponentFileHash {
+            path: path.to_string(),
+            permissions: ComponentFilePermissions::ReadOnly,
+            hash: Some(hash.to_string()),
+        };
+
+        let deployed_files = vec![deployed("/a.txt", "key-a"), deployed("/b.txt", "key-b")];
+        let local_files = vec![local("/a.txt", "hash-a"), local("/b.txt", "hash-b")];
+
+        // Without recorded keys the server keys are not compared with the local hashes
+        let file_keys = ComponentFileKeys::default();
+        let diff = ComponentFilesDiff::new(
+            "ns:component".into(),
+            deployed_files
+                .iter()
+                .map(|file| file_keys.deployed_file_hash(file)),
+            local_files.clone(),
+        );
+        assert_eq!(diff.unchanged, 0);
+        assert_eq!(diff.count(ComponentFileChangeKind::Changed), 2);
+
+        let mut file_keys = ComponentFileKeys::default();
+        file_keys.record(
+            &deployed_files,
+            &BTreeMap::from([
+                ("/a.txt".to_string(), "hash-a".to_string()),
+                ("/b.txt".to_string(), "hash-old".to_string()),
+            ]),
+        );
+        let diff = ComponentFilesDiff::new(
+            "ns:component".into(),
+            deployed_files
+                .iter()
+                .map(|file| file_keys.deployed_file_hash(file)),
+            local_files,
+        );
+        assert_eq!(diff.unchanged, 1);
+        assert_eq!(
+            diff.changes,
+            vec![ComponentFileChange {
+                path: "/b.txt".to_string(),
+                kind: ComponentFileChangeKind::Changed,
+            }]
+        );
+
+        // Deployed files are compared by their keys
+        let diff = ComponentFilesDiff::new(
+            "ns:component".into(),
+            deployed_files.iter().map(ComponentFileHash::from),
+            deployed_files.iter().map(ComponentFileHash::from),
+        );
+        assert!(!diff.has_changes());
+    }
 }
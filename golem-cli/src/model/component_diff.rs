@@ -0,0 +1,659 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::model::component::{format_function_name, render_exported_function, render_type};
+use crate::model::ComponentName;
+use golem_wasm_ast::analysis::{AnalysedExport, AnalysedFunction, AnalysedType};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ComponentChangeCompatibility {
+    /// Existing invocations keep working
+    Compatible,
+    /// Existing invocations can fail
+    Breaking,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ComponentFunctionChangeKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+/// Change of a parameter or result type, or of a part of it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentTypeChangeView {
+    /// Location of the change, e.g. "param user.address.city" or "result.ok"
+    pub path: String,
+    pub description: String,
+    pub compatibility: ComponentChangeCompatibility,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentFunctionChangeView {
+    pub function_name: String,
+    pub kind: ComponentFunctionChangeKind,
+    pub compatibility: ComponentChangeCompatibility,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old_signature: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_signature: Option<String>,
+    pub type_changes: Vec<ComponentTypeChangeView>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentDiffView {
+    pub component_name: ComponentName,
+    /// Description of the compared versions, e.g. "version 3" or "local build"
+    pub old_source: String,
+    pub new_source: String,
+    pub changes: Vec<ComponentFunctionChangeView>,
+}
+
+impl ComponentDiffView {
+    pub fn new(
+        component_name: ComponentName,
+        old_source: String,
+        old_exports: &[AnalysedExport],
+        new_source: String,
+        new_exports: &[AnalysedExport],
+    ) -> Self {
+        let old_functions = exported_functions(old_exports);
+        let new_functions = exported_functions(new_exports);
+
+        let function_names = old_functions
+            .keys()
+            .chain(new_functions.keys())
+            .collect::<BTreeSet<_>>();
+
+        let changes = function_names
+            .into_iter()
+            .filter_map(|function_name| {
+                match (
+                    old_functions.get(function_name),
+                    new_functions.get(function_name),
+                ) {
+                    (Some(old), Some(new)) => function_change(function_name, old, new),
+                    (Some(old), None) => Some(ComponentFunctionChangeView {
+                        function_name: function_name.clone(),
+                        kind: ComponentFunctionChangeKind::Removed,
+                        compatibility: ComponentChangeCompatibility::Breaking,
+                        old_signature: Some(old.signature()),
+                        new_signature: None,
+                        type_changes: vec![],
+                    }),
+                    (None, Some(new)) => Some(ComponentFunctionChangeView {
+                        function_name: function_name.clone(),
+                        kind: ComponentFunctionChangeKind::Added,
+                        compatibility: ComponentChangeCompatibility::Compatible,
+                        old_signature: None,
+                        new_signature: Some(new.signature()),
+                        type_changes: vec![],
+                    }),
+                    (None, None) => None,
+                }
+            })
+            .collect();
+
+        Self {
+            component_name,
+            old_source,
+            new_source,
+            changes,
+        }
+    }
+
+    pub fn count(&self, kind: ComponentFunctionChangeKind) -> usize {
+        self.changes
+            .iter()
+            .filter(|change| change.kind == kind)
+            .count()
+    }
+
+    pub fn breaking_count(&self) -> usize {
+        self.changes
+            .iter()
+            .filter(|change| change.compatibility == ComponentChangeCompatibility::Breaking)
+            .count()
+    }
+
+    pub fn has_breaking_changes(&self) -> bool {
+        self.breaking_count() > 0
+    }
+}
+
+struct ExportedFunction<'a> {
+    interface: Option<&'a str>,
+    function: &'a AnalysedFunction,
+}
+
+impl ExportedFunction<'_> {
+    fn signature(&self) -> String {
+        render_exported_function(self.interface, self.function, true)
+    }
+}
+
+fn exported_functions(exports: &[AnalysedExport]) -> BTreeMap<String, ExportedFunction<'_>> {
+    exports
+        .iter()
+        .flat_map(|export| match export {
+            AnalysedExport::Instance(instance) => instance
+                .functions
+                .iter()
+                .map(|function| ExportedFunction {
+                    interface: Some(instance.name.as_str()),
+                    function,
+                })
+                .collect::<Vec<_>>(),
+            AnalysedExport::Function(function) => vec![ExportedFunction {
+                interface: None,
+                function,
+            }],
+        })
+        .map(|function| {
+            (
+                format_function_name(function.interface, &function.function.name),
+                function,
+            )
+        })
+        .collect()
+}
+
+// Parameters are written by the callers and results are read by them, so the same change can be
+// compatible in one position, and breaking in the other
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TypePosition {
+    Parameter,
+    Result,
+}
+
+fn function_change(
+    function_name: &str,
+    old: &ExportedFunction,
+    new: &ExportedFunction,
+) -> Option<ComponentFunctionChangeView> {
+    let old_signature = old.signature();
+    let new_signature = new.signature();
+    if old_signature == new_signature {
+        return None;
+    }
+
+    let mut type_changes = Vec::new();
+
+    let (old_params, new_params) = (&old.function.parameters, &new.function.parameters);
+    if old_params.len() != new_params.len() {
+        type_changes.push(ComponentTypeChangeView {
+            path: "params".to_string(),
+            description: format!(
+                "number of parameters changed from {} to {}",
+                old_params.len(),
+                new_params.len()
+            ),
+            compatibility: ComponentChangeCompatibility::Breaking,
+        });
+    } else {
+        for (old_param, new_param) in old_params.iter().zip(new_params) {
+            let path = format!("param {}", new_param.name);
+            if old_param.name != new_param.name {
+                // Arguments are passed by position, so renaming is only informative
+                type_changes.push(ComponentTypeChangeView {
+                    path: path.clone(),
+                    description: format!("renamed from {}", old_param.name),
+                    compatibility: ComponentChangeCompatibility::Compatible,
+                });
+            }
+            compare_types(
+                &path,
+                &old_param.typ,
+                &new_param.typ,
+                TypePosition::Parameter,
+                &mut type_changes,
+            );
+        }
+    }
+
+    let (old_results, new_results) = (&old.function.results, &new.function.results);
+    if old_results.len() != new_results.len() {
+        type_changes.push(ComponentTypeChangeView {
+            path: "results".to_string(),
+            description: format!(
+                "number of results changed from {} to {}",
+                old_results.len(),
+                new_results.len()
+            ),
+            compatibility: ComponentChangeCompatibility::Breaking,
+        });
+    } else {
+        let single_result = new_results.len() == 1;
+        for (idx, (old_result, new_result)) in old_results.iter().zip(new_results).enumerate() {
+            let path = match &new_result.name {
+                Some(name) => format!("result {}", name),
+                None if single_result => "result".to_string(),
+                None => format!("result {}", idx),
+            };
+            compare_types(
+                &path,
+                &old_result.typ,
+                &new_result.typ,
+                TypePosition::Result,
+                &mut type_changes,
+            );
+        }
+    }
+
+    let compatibility = type_changes
+        .iter()
+        .map(|change| change.compatibility)
+        .max()
+        .unwrap_or(ComponentChangeCompatibility::Compatible);
+
+    Some(ComponentFunctionChangeView {
+        function_name: function_name.to_string(),
+        kind: ComponentFunctionChangeKind::Changed,
+        compatibility,
+        old_signature: Some(old_signature),
+        new_signature: Some(new_signature),
+        type_changes,
+    })
+}
+
+fn compare_types(
+    path: &str,
+    old: &AnalysedType,
+    new: &AnalysedType,
+    position: TypePosition,
+    changes: &mut Vec<ComponentTypeChangeView>,
+) {
+    if old == new {
+        return;
+    }
+
+    // Widening a parameter type or narrowing a result type does not affect existing callers
+    let widening = match position {
+        TypePosition::Parameter => ComponentChangeCompatibility::Compatible,
+        TypePosition::Result => ComponentChangeCompatibility::Breaking,
+    };
+    let narrowing = match position {
+        TypePosition::Parameter => ComponentChangeCompatibility::Breaking,
+        TypePosition::Result => ComponentChangeCompatibility::Compatible,
+    };
+
+    match (old, new) {
+        (AnalysedType::Record(old), AnalysedType::Record(new)) => {
+            for old_field in &old.fields {
+                let field_path = format!("{}.{}", path, old_field.name);
+                match new.fields.iter().find(|field| field.name == old_field.name) {
+                    Some(new_field) => compare_types(
+                        &field_path,
+                        &old_field.typ,
+                        &new_field.typ,
+                        position,
+                        changes,
+                    ),
+                    None => push_change(
+                        changes,
+                        field_path,
+                        "record field removed".to_string(),
+                        ComponentChangeCompatibility::Breaking,
+                    ),
+                }
+            }
+            for new_field in &new.fields {
+                if !old.fields.iter().any(|field| field.name == new_field.name) {
+                    // New fields are required in parameters, and ignored by existing readers of results
+                    push_change(
+                        changes,
+                        format!("{}.{}", path, new_field.name),
+                        format!("record field added: {}", render_type(&new_field.typ)),
+                        narrowing,
+                    );
+                }
+            }
+        }
+        (AnalysedType::Variant(old), AnalysedType::Variant(new)) => {
+            for old_case in &old.cases {
+                let case_path = format!("{}.{}", path, old_case.name);
+                match new.cases.iter().find(|case| case.name == old_case.name) {
+                    Some(new_case) => match (&old_case.typ, &new_case.typ) {
+                        (Some(old_typ), Some(new_typ)) => {
+                            compare_types(&case_path, old_typ, new_typ, position, changes)
+                        }
+                        (None, None) => {}
+                        _ => push_change(
+                            changes,
+                            case_path,
+                            "variant case payload changed".to_string(),
+                            ComponentChangeCompatibility::Breaking,
+                        ),
+                    },
+                    None => push_change(
+                        changes,
+                        case_path,
+                        "variant case removed".to_string(),
+                        narrowing,
+                    ),
+                }
+            }
+            for new_case in &new.cases {
+                if !old.cases.iter().any(|case| case.name == new_case.name) {
+                    push_change(
+                        changes,
+                        format!("{}.{}", path, new_case.name),
+                        "variant case added".to_string(),
+                        widening,
+                    );
+                }
+            }
+        }
+        (AnalysedType::Enum(old), AnalysedType::Enum(new)) => {
+            for removed in old.cases.iter().filter(|case| !new.cases.contains(case)) {
+                push_change(
+                    changes,
+                    format!("{}.{}", path, removed),
+                    "enum case removed".to_string(),
+                    narrowing,
+                );
+            }
+            for added in new.cases.iter().filter(|case| !old.cases.contains(case)) {
+                push_change(
+                    changes,
+                    format!("{}.{}", path, added),
+                    "enum case added".to_string(),
+                    widening,
+                );
+            }
+        }
+        (AnalysedType::Flags(old), AnalysedType::Flags(new)) => {
+            for removed in old.names.iter().filter(|name| !new.names.contains(name)) {
+                push_change(
+                    changes,
+                    format!("{}.{}", path, removed),
+                    "flag removed".to_string(),
+                    narrowing,
+                );
+            }
+            for added in new.names.iter().filter(|name| !old.names.contains(name)) {
+                push_change(
+                    changes,
+                    format!("{}.{}", path, added),
+                    "flag added".to_string(),
+                    widening,
+                );
+            }
+        }
+        (AnalysedType::Option(old), AnalysedType::Option(new)) => {
+            compare_types(path, &old.inner, &new.inner, position, changes)
+        }
+        (AnalysedType::List(old), AnalysedType::List(new)) => compare_types(
+            &format!("{}[]", path),
+            &old.inner,
+            &new.inner,
+            position,
+            changes,
+        ),
+        (AnalysedType::Tuple(old), AnalysedType::Tuple(new))
+            if old.items.len() == new.items.len() =>
+        {
+            for (idx, (old_item, new_item)) in old.items.iter().zip(&new.items).enumerate() {
+                compare_types(
+                    &format!("{}.{}", path, idx),
+                    old_item,
+                    new_item,
+                    position,
+                    changes,
+                );
+            }
+        }
+        (AnalysedType::Result(old), AnalysedType::Result(new)) => {
+            for (name, old_typ, new_typ) in [("ok", &old.ok, &new.ok), ("err", &old.err, &new.err)]
+            {
+                let case_path = format!("{}.{}", path, name);
+                match (old_typ, new_typ) {
+                    (Some(old_typ), Some(new_typ)) => {
+                        compare_types(&case_path, old_typ, new_typ, position, changes)
+                    }
+                    (None, None) => {}
+                    _ => push_change(
+                        changes,
+                        case_path,
+                        "result case payload changed".to_string(),
+                        ComponentChangeCompatibility::Breaking,
+                    ),
+                }
+            }
+        }
+        _ => push_change(
+            changes,
+            path.to_string(),
+            format!(
+                "type changed from {} to {}",
+                render_type(old),
+                render_type(new)
+            ),
+            ComponentChangeCompatibility::Breaking,
+        ),
+    }
+}
+
+fn push_change(
+    changes: &mut Vec<ComponentTypeChangeView>,
+    path: String,
+    description: String,
+    compatibility: ComponentChangeCompatibility,
+) {
+    changes.push(ComponentTypeChangeView {
+        path,
+        description,
+        compatibility,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::component_diff::{
+        ComponentChangeCompatibility, ComponentDiffView, ComponentFunctionChangeKind,
+    };
+    use assert2::{assert, check};
+    use golem_wasm_ast::analysis::analysed_type::{
+        case, field, r#enum, record, str, u32, unit_case, variant,
+    };
+    use golem_wasm_ast::analysis::{
+        AnalysedExport, AnalysedFunction, AnalysedFunctionParameter, AnalysedFunctionResult,
+        AnalysedInstance, AnalysedType,
+    };
+    use test_r::test;
+
+    use ComponentChangeCompatibility::{Breaking, Compatible};
+
+    fn exports(params: Vec<AnalysedType>, result: Option<AnalysedType>) -> Vec<AnalysedExport> {
+        vec![AnalysedExport::Instance(AnalysedInstance {
+            name: "app:comp/api".to_string(),
+            functions: vec![AnalysedFunction {
+                name: "run".to_string(),
+                parameters: params
+                    .into_iter()
+                    .enumerate()
+                    .map(|(idx, typ)| AnalysedFunctionParameter {
+                        name: format!("p{idx}"),
+                        typ,
+                    })
+                    .collect(),
+                results: result
+                    .into_iter()
+                    .map(|typ| AnalysedFunctionResult { name: None, typ })
+                    .collect(),
+            }],
+        })]
+    }
+
+    fn diff(old: Vec<AnalysedExport>, new: Vec<AnalysedExport>) -> ComponentDiffView {
+        ComponentDiffView::new(
+            "app:comp".into(),
+            "version 1".to_string(),
+            &old,
+            "version 2".to_string(),
+            &new,
+        )
+    }
+
+    // Path and compatibility of the type changes of the single changed function
+    fn type_changes(
+        old: Vec<AnalysedExport>,
+        new: Vec<AnalysedExport>,
+    ) -> (
+        ComponentChangeCompatibility,
+        Vec<(String, ComponentChangeCompatibility)>,
+    ) {
+        let diff = diff(old, new);
+        assert!(diff.changes.len() == 1);
+        let change = &diff.changes[0];
+        check!(change.kind == ComponentFunctionChangeKind::Changed);
+        (
+            change.compatibility,
+            change
+                .type_changes
+                .iter()
+                .map(|change| (change.path.clone(), change.compatibility))
+                .collect(),
+        )
+    }
+
+    fn user(with_email: bool) -> AnalysedType {
+        let mut fields = vec![field("name", str())];
+        if with_email {
+            fields.push(field("email", str()));
+        }
+        record(fields)
+    }
+
+    #[test]
+    fn unchanged_functions() {
+        let diff = diff(
+            exports(vec![user(false)], Some(u32())),
+            exports(vec![user(false)], Some(u32())),
+        );
+
+        check!(diff.changes.is_empty());
+        check!(!diff.has_breaking_changes());
+    }
+
+    #[test]
+    fn added_and_removed_functions() {
+        let diff = diff(exports(vec![], None), vec![]);
+        check!(diff.count(ComponentFunctionChangeKind::Removed) == 1);
+        check!(diff.breaking_count() == 1);
+
+        let diff = ComponentDiffView::new(
+            "app:comp".into(),
+            "version 1".to_string(),
+            &[],
+            "version 2".to_string(),
+            &exports(vec![], None),
+        );
+        check!(diff.count(ComponentFunctionChangeKind::Added) == 1);
+        check!(!diff.has_breaking_changes());
+    }
+
+    #[test]
+    fn record_field_added_to_param_is_breaking() {
+        check!(
+            type_changes(
+                exports(vec![user(false)], None),
+                exports(vec![user(true)], None)
+            ) == (Breaking, vec![("param p0.email".to_string(), Breaking)])
+        );
+    }
+
+    #[test]
+    fn record_field_added_to_result_is_compatible() {
+        check!(
+            type_changes(
+                exports(vec![], Some(user(false))),
+                exports(vec![], Some(user(true)))
+            ) == (Compatible, vec![("result.email".to_string(), Compatible)])
+        );
+    }
+
+    #[test]
+    fn record_field_removed_is_breaking() {
+        check!(
+            type_changes(
+                exports(vec![], Some(user(true))),
+                exports(vec![], Some(user(false)))
+            ) == (Breaking, vec![("result.email".to_string(), Breaking)])
+        );
+    }
+
+    #[test]
+    fn variant_case_added() {
+        let old = variant(vec![case("found", u32())]);
+        let new = variant(vec![case("found", u32()), unit_case("missing")]);
+
+        check!(
+            type_changes(
+                exports(vec![old.clone()], None),
+                exports(vec![new.clone()], None)
+            ) == (
+                Compatible,
+                vec![("param p0.missing".to_string(), Compatible)]
+            )
+        );
+        check!(
+            type_changes(exports(vec![], Some(old)), exports(vec![], Some(new)))
+                == (Breaking, vec![("result.missing".to_string(), Breaking)])
+        );
+    }
+
+    #[test]
+    fn enum_case_removed() {
+        let old = r#enum(&["red", "green"]);
+        let new = r#enum(&["red"]);
+
+        check!(
+            type_changes(
+                exports(vec![old.clone()], None),
+                exports(vec![new.clone()], None)
+            ) == (Breaking, vec![("param p0.green".to_string(), Breaking)])
+        );
+        check!(
+            type_changes(exports(vec![], Some(old)), exports(vec![], Some(new)))
+                == (Compatible, vec![("result.green".to_string(), Compatible)])
+        );
+    }
+
+    #[test]
+    fn param_count_changed() {
+        check!(
+            type_changes(
+                exports(vec![str()], None),
+                exports(vec![str(), u32()], None)
+            ) == (Breaking, vec![("params".to_string(), Breaking)])
+        );
+    }
+
+    #[test]
+    fn param_type_changed() {
+        check!(
+            type_changes(exports(vec![str()], None), exports(vec![u32()], None))
+                == (Breaking, vec![("param p0".to_string(), Breaking)])
+        );
+    }
+}
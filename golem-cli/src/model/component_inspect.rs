@@ -0,0 +1,249 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::model::component::show_exported_functions;
+use crate::wasm_rpc_stubgen::GOLEM_HOST_WIT_PACKAGES;
+use anyhow::anyhow;
+use golem_common::model::component_metadata::ComponentMetadata;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use wasmparser::{Parser, Payload};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ComponentImportKind {
+    /// Provided by the Golem host
+    Host,
+    /// Generated WASM RPC client interface, satisfied when deployed with a matching
+    /// dynamic WASM RPC dependency
+    DynamicLinking,
+    /// Not provided by the Golem host, instantiating the component will fail
+    Unsatisfied,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentImportView {
+    pub name: String,
+    pub kind: ComponentImportKind,
+}
+
+impl ComponentImportView {
+    fn new(name: String) -> Self {
+        // Interface import names are in the "namespace:package/interface@version" form, imports
+        // from packages not implemented by the host have to be satisfied by dynamic linking
+        let package = name.split(['/', '@']).next().unwrap_or_default();
+        let kind = if GOLEM_HOST_WIT_PACKAGES.contains(&package) {
+            ComponentImportKind::Host
+        } else if package.ends_with("-client") {
+            ComponentImportKind::DynamicLinking
+        } else {
+            ComponentImportKind::Unsatisfied
+        };
+        Self { name, kind }
+    }
+}
+
+/// Top level sections of the same kind, nested modules and components are counted as one section
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentSectionView {
+    pub name: String,
+    pub count: usize,
+    pub size: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentInspectView {
+    pub source: String,
+    /// Name metadata, as written by the build for the root package
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Version metadata, as written by the build for the root package
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    pub producers: Vec<String>,
+    pub size: u64,
+    pub exports: Vec<String>,
+    pub imports: Vec<ComponentImportView>,
+    pub sections: Vec<ComponentSectionView>,
+}
+
+impl ComponentInspectView {
+    pub fn new(source: String, wasm: &[u8]) -> anyhow::Result<Self> {
+        let metadata = ComponentMetadata::analyse_component(wasm)
+            .map_err(|err| anyhow!("Failed to analyse component {}: {}", source, err))?;
+
+        let wasm_metadata = wasm_metadata::Payload::from_binary(wasm)
+            .map_err(|err| anyhow!("Failed to read metadata of {}: {}", source, err))?;
+        let wasm_metadata = wasm_metadata.metadata();
+        let producers = wasm_metadata
+            .producers
+            .iter()
+            .flat_map(|producers| {
+                producers.iter().flat_map(|(field, values)| {
+                    values
+                        .iter()
+                        .map(move |(name, version)| format!("{field}: {name} {version}"))
+                })
+            })
+            .map(|producer| producer.trim_end().to_string())
+            .collect();
+
+        let (imports, sections) = read_imports_and_sections(wasm)
+            .map_err(|err| anyhow!("Failed to parse {}: {}", source, err))?;
+
+        Ok(Self {
+            source,
+            name: wasm_metadata.name.clone(),
+            version: wasm_metadata
+                .version
+                .as_ref()
+                .map(|version| version.to_string()),
+            producers,
+            size: wasm.len() as u64,
+            exports: show_exported_functions(&metadata.exports, true),
+            imports: imports.into_iter().map(ComponentImportView::new).collect(),
+            sections,
+        })
+    }
+
+    pub fn imports_of_kind(&self, kind: ComponentImportKind) -> Vec<&str> {
+        self.imports
+            .iter()
+            .filter(|import| import.kind == kind)
+            .map(|import| import.name.as_str())
+            .collect()
+    }
+
+    pub fn all_imports_satisfiable(&self) -> bool {
+        self.imports
+            .iter()
+            .all(|import| import.kind != ComponentImportKind::Unsatisfied)
+    }
+}
+
+fn read_imports_and_sections(
+    wasm: &[u8],
+) -> wasmparser::Result<(Vec<String>, Vec<ComponentSectionView>)> {
+    let mut imports = Vec::new();
+    let mut sections = BTreeMap::<String, (usize, u64)>::new();
+
+    // parse_all also visits the sections of nested modules and components,
+    // which are tracked by depth, so only the top level sections are collected
+    let mut depth = 0usize;
+    for payload in Parser::new(0).parse_all(wasm) {
+        let payload = payload?;
+
+        if depth == 0 {
+            if let Payload::ComponentImportSection(reader) = &payload {
+                for import in reader.clone() {
+                    imports.push(import?.name.0.to_string());
+                }
+            }
+
+            if let (Some(name), Some((_, range))) = (section_name(&payload), payload.as_section()) {
+                let (count, size) = sections.entry(name).or_default();
+                *count += 1;
+                *size += range.len() as u64;
+            }
+        }
+
+        match payload {
+            Payload::ModuleSection { .. } | Payload::ComponentSection { .. } => depth += 1,
+            Payload::End(_) => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+    }
+
+    let mut sections = sections
+        .into_iter()
+        .map(|(name, (count, size))| ComponentSectionView { name, count, size })
+        .collect::<Vec<_>>();
+    sections.sort_by(|a, b| b.size.cmp(&a.size).then_with(|| a.name.cmp(&b.name)));
+
+    Ok((imports, sections))
+}
+
+fn section_name(payload: &Payload) -> Option<String> {
+    let name = match payload {
+        Payload::CustomSection(reader) => return Some(format!("custom {}", reader.name())),
+        Payload::ModuleSection { .. } => "core module",
+        Payload::InstanceSection(_) => "core instance",
+        Payload::CoreTypeSection(_) => "core type",
+        Payload::ComponentSection { .. } => "component",
+        Payload::ComponentInstanceSection(_) => "component instance",
+        Payload::ComponentAliasSection(_) => "alias",
+        Payload::ComponentTypeSection(_) => "type",
+        Payload::ComponentCanonicalSection(_) => "canonical function",
+        Payload::ComponentStartSection { .. } => "start",
+        Payload::ComponentImportSection(_) => "import",
+        Payload::ComponentExportSection(_) => "export",
+        Payload::TypeSection(_) => "type",
+        Payload::ImportSection(_) => "import",
+        Payload::FunctionSection(_) => "function",
+        Payload::TableSection(_) => "table",
+        Payload::MemorySection(_) => "memory",
+        Payload::TagSection(_) => "tag",
+        Payload::GlobalSection(_) => "global",
+        Payload::ExportSection(_) => "export",
+        Payload::ElementSection(_) => "element",
+        Payload::DataSection(_) => "data",
+        Payload::StartSection { .. } => "start",
+        Payload::DataCountSection { .. } => "data count",
+        Payload::CodeSectionStart { .. } => "code",
+        Payload::UnknownSection { id, .. } => return Some(format!("unknown {}", id)),
+        _ => return None,
+    };
+    Some(name.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::component_inspect::{ComponentImportKind, ComponentImportView};
+    use assert2::check;
+    use test_r::test;
+
+    fn kind(name: &str) -> ComponentImportKind {
+        ComponentImportView::new(name.to_string()).kind
+    }
+
+    #[test]
+    fn host_imports() {
+        check!(kind("wasi:io/streams@0.2.0") == ComponentImportKind::Host);
+        check!(kind("wasi:http/outgoing-handler@0.2.0") == ComponentImportKind::Host);
+        check!(kind("golem:api/host@1.1.6") == ComponentImportKind::Host);
+        check!(kind("golem:rpc/types@0.2.0") == ComponentImportKind::Host);
+        check!(kind("golem:rdbms/postgres@0.0.1") == ComponentImportKind::Host);
+        check!(kind("golem:durability/durability@1.2.0") == ComponentImportKind::Host);
+    }
+
+    #[test]
+    fn dynamic_linking_imports() {
+        check!(kind("app:counter-client/counter-client") == ComponentImportKind::DynamicLinking);
+        check!(
+            kind("app:counter-client/counter-client@0.1.0") == ComponentImportKind::DynamicLinking
+        );
+    }
+
+    #[test]
+    fn unsatisfied_imports() {
+        check!(kind("app:counter/api") == ComponentImportKind::Unsatisfied);
+        check!(kind("wasi:nn/graph@0.2.0") == ComponentImportKind::Unsatisfied);
+        // Only the package is matched, not a prefix of it
+        check!(kind("golem:apix/host") == ComponentImportKind::Unsatisfied);
+        check!(kind("run") == ComponentImportKind::Unsatisfied);
+    }
+}
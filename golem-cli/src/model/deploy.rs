@@ -12,9 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::model::{ComponentName, WorkerName};
+use crate::model::{ComponentName, WorkerName, WorkerUpdateMode};
 use serde::{Deserialize, Serialize};
 
+pub const SAVE_SNAPSHOT_INTERFACE: &str = "golem:api/save-snapshot";
+pub const LOAD_SNAPSHOT_INTERFACE: &str = "golem:api/load-snapshot";
+
 #[derive(Clone, Default, PartialEq, Eq, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TryUpdateAllWorkersResult {
@@ -38,3 +41,234 @@ pub struct WorkerUpdateAttempt {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
 }
+
+/// Workers of a component grouped by their current component version, with the action
+/// decided by the compatibility check against the target version
+#[derive(Clone, Debug)]
+pub struct WorkerUpdatePlan {
+    pub component_name: ComponentName,
+    pub target_version: u64,
+    pub update_mode: WorkerUpdateMode,
+    pub entries: Vec<WorkerUpdatePlanEntry>,
+}
+
+impl WorkerUpdatePlan {
+    pub fn action(&self, source_version: u64) -> Option<&WorkerUpdatePlanAction> {
+        self.entries
+            .iter()
+            .find(|entry| entry.source_version == source_version)
+            .map(|entry| &entry.action)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct WorkerUpdatePlanEntry {
+    pub source_version: u64,
+    pub worker_count: usize,
+    pub action: WorkerUpdatePlanAction,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WorkerUpdatePlanAction {
+    /// The workers are already using the target version
+    UpToDate,
+    /// Breaking changes are only allowed for manual updates, where the state is migrated using snapshots
+    Update {
+        breaking_changes: usize,
+    },
+    Refuse {
+        reason: String,
+    },
+}
+
+impl WorkerUpdatePlanAction {
+    /// Decides the update of workers using a version other than the target. Manual updates always
+    /// migrate the worker state through snapshots, so they are refused without the snapshot
+    /// exports, even when there are no breaking changes.
+    pub fn new(
+        update_mode: WorkerUpdateMode,
+        source_version: u64,
+        target_version: u64,
+        breaking_changes: usize,
+        supports_snapshots: bool,
+    ) -> Self {
+        match update_mode {
+            WorkerUpdateMode::Automatic if breaking_changes > 0 => Self::Refuse {
+                reason: format!(
+                    "{} breaking export change(s), use manual update mode with a component supporting snapshots",
+                    breaking_changes
+                ),
+            },
+            WorkerUpdateMode::Manual if !supports_snapshots => Self::Refuse {
+                reason: format!(
+                    "manual update migrates the worker state with snapshots, and requires {} in version {} and {} in version {}",
+                    SAVE_SNAPSHOT_INTERFACE, source_version, LOAD_SNAPSHOT_INTERFACE, target_version
+                ),
+            },
+            _ => Self::Update { breaking_changes },
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ComponentRollbackAction {
+    /// The target is already the latest version
+    UpToDate,
+    /// The workers are updated to the target version directly, without publishing it again
+    Retarget,
+    /// The target version is published again, so it becomes the latest version
+    Publish,
+    /// Publishing would keep the initial files of the latest version, as the content of the
+    /// target version files cannot be downloaded
+    RefuseChangedFiles,
+}
+
+impl ComponentRollbackAction {
+    pub fn new(
+        target_version: u64,
+        latest_version: u64,
+        retarget: bool,
+        files_changed: bool,
+    ) -> Self {
+        if target_version == latest_version {
+            Self::UpToDate
+        } else if retarget {
+            Self::Retarget
+        } else if files_changed {
+            Self::RefuseChangedFiles
+        } else {
+            Self::Publish
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::deploy::{
+        ComponentRollbackAction, WorkerUpdatePlan, WorkerUpdatePlanAction, WorkerUpdatePlanEntry,
+    };
+    use crate::model::WorkerUpdateMode;
+    use assert2::{check, let_assert};
+    use test_r::test;
+
+    #[test]
+    fn automatic_update_is_refused_with_breaking_changes() {
+        check!(
+            WorkerUpdatePlanAction::new(WorkerUpdateMode::Automatic, 1, 2, 0, false)
+                == WorkerUpdatePlanAction::Update {
+                    breaking_changes: 0
+                }
+        );
+
+        let_assert!(
+            WorkerUpdatePlanAction::Refuse { reason } =
+                WorkerUpdatePlanAction::new(WorkerUpdateMode::Automatic, 1, 2, 3, true)
+        );
+        check!(reason.starts_with("3 breaking export change(s)"));
+    }
+
+    #[test]
+    fn manual_update_requires_snapshots() {
+        check!(
+            WorkerUpdatePlanAction::new(WorkerUpdateMode::Manual, 1, 2, 3, true)
+                == WorkerUpdatePlanAction::Update {
+                    breaking_changes: 3
+                }
+        );
+
+        // Snapshots are required even without breaking changes
+        for breaking_changes in [0, 3] {
+            let_assert!(
+                WorkerUpdatePlanAction::Refuse { reason } = WorkerUpdatePlanAction::new(
+                    WorkerUpdateMode::Manual,
+                    1,
+                    2,
+                    breaking_changes,
+                    false
+                )
+            );
+            check!(reason.contains("golem:api/save-snapshot in version 1"));
+            check!(reason.contains("golem:api/load-snapshot in version 2"));
+        }
+    }
+
+    #[test]
+    fn plan_actions_by_source_version() {
+        let plan = WorkerUpdatePlan {
+            component_name: "app:comp".into(),
+            target_version: 3,
+            update_mode: WorkerUpdateMode::Automatic,
+            entries: vec![
+                WorkerUpdatePlanEntry {
+                    source_version: 1,
+                    worker_count: 2,
+                    action: WorkerUpdatePlanAction::new(
+                        WorkerUpdateMode::Automatic,
+                        1,
+                        3,
+                        1,
+                        false,
+                    ),
+                },
+                WorkerUpdatePlanEntry {
+                    source_version: 2,
+                    worker_count: 1,
+                    action: WorkerUpdatePlanAction::new(
+                        WorkerUpdateMode::Automatic,
+                        2,
+                        3,
+                        0,
+                        false,
+                    ),
+                },
+                WorkerUpdatePlanEntry {
+                    source_version: 3,
+                    worker_count: 4,
+                    action: WorkerUpdatePlanAction::UpToDate,
+                },
+            ],
+        };
+
+        // Workers of version 1 are skipped
+        check!(matches!(
+            plan.action(1),
+            Some(WorkerUpdatePlanAction::Refuse { .. })
+        ));
+        check!(
+            plan.action(2)
+                == Some(&WorkerUpdatePlanAction::Update {
+                    breaking_changes: 0
+                })
+        );
+        check!(plan.action(3) == Some(&WorkerUpdatePlanAction::UpToDate));
+        check!(plan.action(4).is_none());
+    }
+
+    #[test]
+    fn rollback_to_latest_version_is_up_to_date() {
+        check!(
+            ComponentRollbackAction::new(3, 3, false, false) == ComponentRollbackAction::UpToDate
+        );
+        check!(ComponentRollbackAction::new(3, 3, true, true) == ComponentRollbackAction::UpToDate);
+    }
+
+    #[test]
+    fn rollback_with_retarget() {
+        check!(
+            ComponentRollbackAction::new(1, 3, true, false) == ComponentRollbackAction::Retarget
+        );
+        // Retargeting does not publish the files, so their changes do not matter
+        check!(ComponentRollbackAction::new(1, 3, true, true) == ComponentRollbackAction::Retarget);
+    }
+
+    #[test]
+    fn rollback_with_publish() {
+        check!(
+            ComponentRollbackAction::new(1, 3, false, false) == ComponentRollbackAction::Publish
+        );
+        check!(
+            ComponentRollbackAction::new(1, 3, false, true)
+                == ComponentRollbackAction::RefuseChangedFiles
+        );
+    }
+}
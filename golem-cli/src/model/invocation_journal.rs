@@ -0,0 +1,341 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::config::ProfileName;
+use crate::fs;
+use crate::model::{IdempotencyKey, WorkerName};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+
+// Oldest entries are dropped when the journal grows over this limit
+const MAX_JOURNAL_ENTRIES: usize = 1000;
+
+/// An invocation recorded in the local invocation journal, if it is enabled for the profile
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvocationJournalEntry {
+    pub id: u64,
+    pub profile_name: ProfileName,
+    /// Fully qualified worker name, which can be used for retrying the invocation
+    pub worker_name: WorkerName,
+    pub function_name: String,
+    pub arguments: Vec<String>,
+    pub idempotency_key: IdempotencyKey,
+    pub enqueue: bool,
+    pub started_at: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub finished_at: Option<DateTime<Utc>>,
+    pub outcome: InvocationJournalOutcome,
+}
+
+impl InvocationJournalEntry {
+    /// Checks if the worker or the function name contains the filter
+    pub fn matches_filter(&self, filter: &str) -> bool {
+        self.worker_name.0.contains(filter) || self.function_name.contains(filter)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum InvocationJournalOutcome {
+    /// The invocation did not finish, most likely because the CLI process was stopped
+    Pending,
+    Succeeded {
+        /// Result values in WAVE format, missing if they could not be formatted
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        result: Option<Vec<String>>,
+    },
+    Enqueued,
+    Failed {
+        error: String,
+    },
+}
+
+impl Display for InvocationJournalOutcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvocationJournalOutcome::Pending => write!(f, "Pending"),
+            InvocationJournalOutcome::Succeeded { .. } => write!(f, "Succeeded"),
+            InvocationJournalOutcome::Enqueued => write!(f, "Enqueued"),
+            InvocationJournalOutcome::Failed { .. } => write!(f, "Failed"),
+        }
+    }
+}
+
+/// Local journal of invocations of all profiles, stored in the config directory. All access
+/// goes through a lock file, so concurrent CLI processes get unique entry ids.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvocationJournal {
+    pub next_id: u64,
+    pub entries: Vec<InvocationJournalEntry>,
+}
+
+impl InvocationJournal {
+    fn path(config_dir: &Path) -> PathBuf {
+        config_dir.join("invocation-journal.json")
+    }
+
+    fn read<R>(config_dir: &Path, read: impl FnOnce(Self) -> R) -> anyhow::Result<R> {
+        fs::read_json_with_lock(Self::path(config_dir), read)
+    }
+
+    fn update<R>(config_dir: &Path, update: impl FnOnce(&mut Self) -> R) -> anyhow::Result<R> {
+        fs::update_json_with_lock(Self::path(config_dir), update)
+    }
+
+    /// Entries of the profile, the most recent first
+    pub fn for_profile(
+        config_dir: &Path,
+        profile_name: &ProfileName,
+    ) -> anyhow::Result<Vec<InvocationJournalEntry>> {
+        Self::read(config_dir, |journal| {
+            journal
+                .entries
+                .into_iter()
+                .rev()
+                .filter(|entry| &entry.profile_name == profile_name)
+                .collect()
+        })
+    }
+
+    pub fn get(
+        config_dir: &Path,
+        profile_name: &ProfileName,
+        id: u64,
+    ) -> anyhow::Result<Option<InvocationJournalEntry>> {
+        Self::read(config_dir, |journal| {
+            journal
+                .entries
+                .into_iter()
+                .find(|entry| &entry.profile_name == profile_name && entry.id == id)
+        })
+    }
+
+    /// Records a new pending invocation, returning the assigned entry id
+    pub fn start(
+        config_dir: &Path,
+        profile_name: ProfileName,
+        worker_name: WorkerName,
+        function_name: String,
+        arguments: Vec<String>,
+        idempotency_key: IdempotencyKey,
+        enqueue: bool,
+    ) -> anyhow::Result<u64> {
+        Self::update(config_dir, |journal| {
+            journal.next_id += 1;
+            let id = journal.next_id;
+
+            journal.entries.push(InvocationJournalEntry {
+                id,
+                profile_name,
+                worker_name,
+                function_name,
+                arguments,
+                idempotency_key,
+                enqueue,
+                started_at: Utc::now(),
+                finished_at: None,
+                outcome: InvocationJournalOutcome::Pending,
+            });
+            if journal.entries.len() > MAX_JOURNAL_ENTRIES {
+                let overflow = journal.entries.len() - MAX_JOURNAL_ENTRIES;
+                journal.entries.drain(..overflow);
+            }
+
+            id
+        })
+    }
+
+    pub fn finish(
+        config_dir: &Path,
+        id: u64,
+        outcome: InvocationJournalOutcome,
+    ) -> anyhow::Result<()> {
+        Self::update(config_dir, |journal| {
+            if let Some(entry) = journal.entries.iter_mut().find(|entry| entry.id == id) {
+                entry.finished_at = Some(Utc::now());
+                entry.outcome = outcome;
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::ProfileName;
+    use crate::model::invocation_journal::{
+        InvocationJournal, InvocationJournalEntry, InvocationJournalOutcome, MAX_JOURNAL_ENTRIES,
+    };
+    use crate::model::IdempotencyKey;
+    use assert2::check;
+    use std::path::Path;
+    use test_r::test;
+
+    fn start(config_dir: &Path, profile_name: ProfileName, worker_name: &str) -> u64 {
+        InvocationJournal::start(
+            config_dir,
+            profile_name,
+            worker_name.into(),
+            "api.{run}".to_string(),
+            vec!["1".to_string()],
+            IdempotencyKey::new(),
+            false,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn start_and_finish_invocations() {
+        let config_dir = tempfile::tempdir().unwrap();
+        let config_dir = config_dir.path();
+
+        let first = start(config_dir, ProfileName::local(), "app:comp/worker-1");
+        let second = start(config_dir, ProfileName::local(), "app:comp/worker-2");
+        check!(first == 1);
+        check!(second == 2);
+
+        let entry = InvocationJournal::get(config_dir, &ProfileName::local(), first)
+            .unwrap()
+            .unwrap();
+        check!(entry.outcome == InvocationJournalOutcome::Pending);
+        check!(entry.finished_at.is_none());
+
+        InvocationJournal::finish(
+            config_dir,
+            first,
+            InvocationJournalOutcome::Succeeded {
+                result: Some(vec!["42".to_string()]),
+            },
+        )
+        .unwrap();
+        // Finishing unknown entries is a NOP
+        InvocationJournal::finish(config_dir, 100, InvocationJournalOutcome::Enqueued).unwrap();
+
+        let entry = InvocationJournal::get(config_dir, &ProfileName::local(), first)
+            .unwrap()
+            .unwrap();
+        check!(
+            entry.outcome
+                == InvocationJournalOutcome::Succeeded {
+                    result: Some(vec!["42".to_string()])
+                }
+        );
+        check!(entry.finished_at.is_some());
+        let entry = InvocationJournal::get(config_dir, &ProfileName::local(), second)
+            .unwrap()
+            .unwrap();
+        check!(entry.outcome == InvocationJournalOutcome::Pending);
+    }
+
+    #[test]
+    fn history_is_filtered_by_profile_and_most_recent_first() {
+        let config_dir = tempfile::tempdir().unwrap();
+        let config_dir = config_dir.path();
+
+        start(config_dir, ProfileName::local(), "app:comp/worker-1");
+        start(config_dir, ProfileName::cloud(), "app:comp/worker-2");
+        start(config_dir, ProfileName::local(), "app:comp/worker-3");
+
+        let ids = |profile_name: ProfileName| {
+            InvocationJournal::for_profile(config_dir, &profile_name)
+                .unwrap()
+                .into_iter()
+                .map(|entry| entry.id)
+                .collect::<Vec<_>>()
+        };
+        check!(ids(ProfileName::local()) == vec![3, 1]);
+        check!(ids(ProfileName::cloud()) == vec![2]);
+        check!(ids(ProfileName("other".to_string())).is_empty());
+
+        check!(InvocationJournal::get(config_dir, &ProfileName::local(), 2)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn history_entries_match_worker_and_function_names() {
+        let config_dir = tempfile::tempdir().unwrap();
+        let config_dir = config_dir.path();
+
+        start(config_dir, ProfileName::local(), "app:comp/worker-1");
+        let entry = InvocationJournal::get(config_dir, &ProfileName::local(), 1)
+            .unwrap()
+            .unwrap();
+
+        check!(entry.matches_filter("worker-1"));
+        check!(entry.matches_filter("app:comp"));
+        check!(entry.matches_filter("{run}"));
+        check!(!entry.matches_filter("worker-2"));
+    }
+
+    #[test]
+    fn oldest_entries_are_dropped_over_the_limit() {
+        let config_dir = tempfile::tempdir().unwrap();
+        let config_dir = config_dir.path();
+
+        start(config_dir, ProfileName::local(), "app:comp/worker");
+        InvocationJournal::update(config_dir, |journal| {
+            let entry = journal.entries[0].clone();
+            for id in 2..=MAX_JOURNAL_ENTRIES as u64 {
+                journal.entries.push(InvocationJournalEntry {
+                    id,
+                    ..entry.clone()
+                });
+            }
+            journal.next_id = MAX_JOURNAL_ENTRIES as u64;
+        })
+        .unwrap();
+        start(config_dir, ProfileName::local(), "app:comp/worker");
+        start(config_dir, ProfileName::local(), "app:comp/worker");
+
+        let entries = InvocationJournal::for_profile(config_dir, &ProfileName::local()).unwrap();
+        check!(entries.len() == MAX_JOURNAL_ENTRIES);
+        check!(entries.first().unwrap().id == MAX_JOURNAL_ENTRIES as u64 + 2);
+        check!(entries.last().unwrap().id == 3);
+    }
+
+    #[test]
+    fn concurrent_starts_get_unique_ids() {
+        let config_dir = tempfile::tempdir().unwrap();
+        let config_dir = config_dir.path();
+
+        let mut ids = std::thread::scope(|scope| {
+            let threads = (0..8)
+                .map(|_| {
+                    scope.spawn(|| {
+                        (0..10)
+                            .map(|_| start(config_dir, ProfileName::local(), "app:comp/worker"))
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect::<Vec<_>>();
+            threads
+                .into_iter()
+                .flat_map(|thread| thread.join().unwrap())
+                .collect::<Vec<_>>()
+        });
+        ids.sort();
+
+        check!(ids == (1..=80).collect::<Vec<_>>());
+        check!(
+            InvocationJournal::for_profile(config_dir, &ProfileName::local())
+                .unwrap()
+                .len()
+                == 80
+        );
+    }
+}
@@ -0,0 +1,288 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::model::{IdempotencyKey, InvokeRequestFormat, WorkerName};
+use anyhow::anyhow;
+use golem_wasm_rpc::json::OptionallyTypeAnnotatedValueJson;
+use serde::Serialize;
+use serde_json::json;
+use std::collections::BTreeMap;
+use url::Url;
+use uuid::Uuid;
+
+/// HTTP request of the worker service performing an invocation, for calling workers without the CLI
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvokeHttpRequest {
+    pub method: String,
+    pub url: Url,
+    pub headers: BTreeMap<String, String>,
+    pub body: serde_json::Value,
+}
+
+impl InvokeHttpRequest {
+    pub fn new(
+        worker_service_url: &Url,
+        component_id: Uuid,
+        worker_name: Option<&WorkerName>,
+        function_name: &str,
+        params: Vec<OptionallyTypeAnnotatedValueJson>,
+        idempotency_key: &IdempotencyKey,
+        enqueue: bool,
+        auth_token: Option<String>,
+    ) -> anyhow::Result<Self> {
+        let component_id = component_id.to_string();
+        let action = if enqueue {
+            "invoke"
+        } else {
+            "invoke-and-await"
+        };
+
+        let mut url = worker_service_url.clone();
+        {
+            let mut segments = url
+                .path_segments_mut()
+                .map_err(|_| anyhow!("Invalid worker service URL: {}", worker_service_url))?;
+            segments.pop_if_empty();
+            segments.extend(["v1", "components", component_id.as_str()]);
+            if let Some(worker_name) = worker_name {
+                segments.extend(["workers", worker_name.0.as_str()]);
+            }
+            segments.push(action);
+        }
+        url.query_pairs_mut().append_pair("function", function_name);
+
+        let mut headers = BTreeMap::new();
+        headers.insert("Content-Type".to_string(), "application/json".to_string());
+        headers.insert("Idempotency-Key".to_string(), idempotency_key.0.clone());
+        if let Some(auth_token) = auth_token {
+            headers.insert(
+                "Authorization".to_string(),
+                format!("Bearer {}", auth_token),
+            );
+        }
+
+        Ok(Self {
+            method: "POST".to_string(),
+            url,
+            headers,
+            body: json!({ "params": params }),
+        })
+    }
+
+    pub fn render(&self, format: InvokeRequestFormat) -> anyhow::Result<String> {
+        let body = serde_json::to_string(&self.body)?;
+        match format {
+            InvokeRequestFormat::Curl => {
+                let mut lines = vec![format!(
+                    "curl -X {} {}",
+                    self.method,
+                    shell_quote(self.url.as_str())?
+                )];
+                for (name, value) in &self.headers {
+                    lines.push(format!(
+                        "  -H {}",
+                        shell_quote(&format!("{name}: {value}"))?
+                    ));
+                }
+                lines.push(format!("  --data-raw {}", shell_quote(&body)?));
+                Ok(lines.join(" \\\n"))
+            }
+            InvokeRequestFormat::Httpie => {
+                let mut lines = vec![format!(
+                    "http {} {}",
+                    self.method,
+                    shell_quote(self.url.as_str())?
+                )];
+                for (name, value) in &self.headers {
+                    lines.push(format!("  {}", shell_quote(&format!("{name}:{value}"))?));
+                }
+                lines.push(format!("  --raw {}", shell_quote(&body)?));
+                Ok(lines.join(" \\\n"))
+            }
+            InvokeRequestFormat::Json => Ok(serde_json::to_string_pretty(self)?),
+        }
+    }
+}
+
+fn shell_quote(value: &str) -> anyhow::Result<String> {
+    Ok(shlex::try_quote(value)
+        .map_err(|err| anyhow!("Failed to quote shell argument: {}", err))?
+        .into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::model::invoke_request::InvokeHttpRequest;
+    use crate::model::{IdempotencyKey, InvokeRequestFormat, WorkerName};
+    use assert2::{check, let_assert};
+    use url::Url;
+    use uuid::Uuid;
+
+    const COMPONENT_ID: &str = "5d1e3d7c-6a1f-4b5e-9f57-2a4c2f6a9b10";
+
+    fn request(
+        worker_service_url: &str,
+        worker_name: Option<&str>,
+        idempotency_key: &str,
+        enqueue: bool,
+        auth_token: Option<&str>,
+    ) -> InvokeHttpRequest {
+        InvokeHttpRequest::new(
+            &Url::parse(worker_service_url).unwrap(),
+            Uuid::parse_str(COMPONENT_ID).unwrap(),
+            worker_name.map(WorkerName::from).as_ref(),
+            "golem:it/api.{echo}",
+            vec![],
+            &IdempotencyKey(idempotency_key.to_string()),
+            enqueue,
+            auth_token.map(|token| token.to_string()),
+        )
+        .unwrap()
+    }
+
+    // Splits a rendered multi line command into its shell words
+    fn shell_words(command: &str) -> Vec<String> {
+        let_assert!(Some(words) = shlex::split(&command.replace(" \\\n", " ")));
+        words
+    }
+
+    #[test]
+    fn oss_url_for_invoke_and_await() {
+        let request = request("http://localhost:9881", Some("w1"), "key", false, None);
+
+        check!(request.method == "POST");
+        check!(
+            request.url.as_str()
+                == format!(
+                    "http://localhost:9881/v1/components/{COMPONENT_ID}/workers/w1/invoke-and-await?function=golem%3Ait%2Fapi.%7Becho%7D"
+                )
+        );
+    }
+
+    #[test]
+    fn cloud_url_for_enqueue_on_ephemeral_worker() {
+        let request = request(
+            "https://release.api.golem.cloud/",
+            None,
+            "key",
+            true,
+            Some("token"),
+        );
+
+        check!(
+            request.url.as_str()
+                == format!(
+                    "https://release.api.golem.cloud/v1/components/{COMPONENT_ID}/invoke?function=golem%3Ait%2Fapi.%7Becho%7D"
+                )
+        );
+    }
+
+    #[test]
+    fn url_keeps_base_path_and_escapes_worker_name() {
+        let request = request(
+            "https://golem.example.com/worker-service/",
+            Some("worker #1"),
+            "key",
+            false,
+            None,
+        );
+
+        check!(
+            request.url.as_str()
+                == format!(
+                    "https://golem.example.com/worker-service/v1/components/{COMPONENT_ID}/workers/worker%20%231/invoke-and-await?function=golem%3Ait%2Fapi.%7Becho%7D"
+                )
+        );
+    }
+
+    #[test]
+    fn headers_contain_idempotency_key_and_optional_auth() {
+        let without_auth = request("http://localhost:9881", Some("w1"), "key-1", false, None);
+        check!(without_auth.headers.get("Idempotency-Key") == Some(&"key-1".to_string()));
+        check!(without_auth.headers.get("Content-Type") == Some(&"application/json".to_string()));
+        check!(!without_auth.headers.contains_key("Authorization"));
+
+        let with_auth = request(
+            "http://localhost:9881",
+            Some("w1"),
+            "key-1",
+            false,
+            Some("token"),
+        );
+        check!(with_auth.headers.get("Authorization") == Some(&"Bearer token".to_string()));
+    }
+
+    #[test]
+    fn curl_quotes_special_characters() {
+        let idempotency_key = "it's a \"key\" with $HOME, `cmd` and \\ in it";
+        let request = request(
+            "http://localhost:9881",
+            Some("w1"),
+            idempotency_key,
+            false,
+            None,
+        );
+
+        let words = shell_words(&request.render(InvokeRequestFormat::Curl).unwrap());
+
+        check!(words[0..3] == ["curl", "-X", "POST"]);
+        check!(words[3] == request.url.as_str());
+        check!(words.contains(&format!("Idempotency-Key: {idempotency_key}")));
+        check!(words[words.len() - 2] == "--data-raw");
+        check!(words[words.len() - 1] == r#"{"params":[]}"#);
+    }
+
+    #[test]
+    fn httpie_quotes_special_characters() {
+        let idempotency_key = "key; rm -rf / && echo 'done'";
+        let request = request(
+            "http://localhost:9881",
+            Some("w1"),
+            idempotency_key,
+            true,
+            Some("token"),
+        );
+
+        let words = shell_words(&request.render(InvokeRequestFormat::Httpie).unwrap());
+
+        check!(words[0..2] == ["http", "POST"]);
+        check!(words.contains(&format!("Idempotency-Key:{idempotency_key}")));
+        check!(words.contains(&"Authorization:Bearer token".to_string()));
+        check!(words[words.len() - 1] == r#"{"params":[]}"#);
+    }
+
+    #[test]
+    fn unquotable_values_are_rejected() {
+        let request = request("http://localhost:9881", Some("w1"), "key\0", false, None);
+
+        check!(request.render(InvokeRequestFormat::Curl).is_err());
+        check!(request.render(InvokeRequestFormat::Httpie).is_err());
+    }
+
+    #[test]
+    fn json_contains_the_whole_request() {
+        let request = request("http://localhost:9881", Some("w1"), "key-1", false, None);
+
+        let json: serde_json::Value =
+            serde_json::from_str(&request.render(InvokeRequestFormat::Json).unwrap()).unwrap();
+
+        check!(json["method"] == "POST");
+        check!(json["url"] == request.url.as_str());
+        check!(json["headers"]["Idempotency-Key"] == "key-1");
+        check!(json["body"] == serde_json::json!({ "params": [] }));
+    }
+}
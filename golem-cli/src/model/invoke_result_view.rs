@@ -15,11 +15,14 @@
 use crate::model::component::{function_result_types, Component};
 use crate::model::text::fmt::log_error;
 use crate::model::wave::type_wave_compatible;
-use crate::model::IdempotencyKey;
+use crate::model::{IdempotencyKey, InvokeResultFormat};
 use anyhow::{anyhow, bail};
-use golem_client::model::{InvokeResult, TypeAnnotatedValue};
-use golem_wasm_rpc::{print_type_annotated_value, protobuf};
+use golem_client::model::{InvokeResult, PublicOplogEntry, TypeAnnotatedValue};
+use golem_wasm_ast::analysis::AnalysedType;
+use golem_wasm_rpc::{print_type_annotated_value, protobuf, Value, ValueAndType};
 use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use std::time::Duration;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct InvokeResultView {
@@ -28,6 +31,139 @@ pub struct InvokeResultView {
     pub result_json: Option<TypeAnnotatedValue>,
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub result_wave: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub metrics: Option<InvocationMetricsView>,
+}
+
+/// Timing and resource usage of an invocation, the server side metrics are only available
+/// if the invocation could be found in the oplog of the worker
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct InvocationMetricsView {
+    /// Client side wall time of the invocation request
+    pub wall_time_ms: u64,
+    /// Time between the start and the completion of the invocation on the server
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub execution_time_ms: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub consumed_fuel: Option<i64>,
+    /// Number of imported (host) functions called during the invocation
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub host_calls: Option<u64>,
+    /// True if the worker failed, was interrupted or suspended during the invocation,
+    /// so it had to be recovered by replaying its oplog
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub recovered: Option<bool>,
+}
+
+impl InvocationMetricsView {
+    pub fn new(
+        wall_time: Duration,
+        idempotency_key: &IdempotencyKey,
+        oplog: &[(u64, PublicOplogEntry)],
+    ) -> Self {
+        let idempotency_key = golem_common::model::IdempotencyKey::new(idempotency_key.0.clone());
+        let events = oplog
+            .iter()
+            .map(|(_, entry)| InvocationMetricsEvent::new(entry, &idempotency_key))
+            .collect::<Vec<_>>();
+        Self::from_events(wall_time, &events)
+    }
+
+    /// Checks if the oplog entry is the start of the invocation with the idempotency key
+    pub fn is_invocation_start(entry: &PublicOplogEntry, idempotency_key: &IdempotencyKey) -> bool {
+        let idempotency_key = golem_common::model::IdempotencyKey::new(idempotency_key.0.clone());
+        matches!(
+            InvocationMetricsEvent::new(entry, &idempotency_key),
+            InvocationMetricsEvent::Invoked { .. }
+        )
+    }
+
+    fn from_events(wall_time: Duration, events: &[InvocationMetricsEvent]) -> Self {
+        let mut metrics = Self {
+            wall_time_ms: wall_time.as_millis() as u64,
+            ..Self::default()
+        };
+
+        let Some((start, invoked_at_ms)) =
+            events
+                .iter()
+                .enumerate()
+                .rev()
+                .find_map(|(position, event)| match event {
+                    InvocationMetricsEvent::Invoked { timestamp_ms } => {
+                        Some((position, *timestamp_ms))
+                    }
+                    _ => None,
+                })
+        else {
+            return metrics;
+        };
+
+        let mut host_calls = 0;
+        let mut recovered = false;
+        for event in &events[start + 1..] {
+            match event {
+                InvocationMetricsEvent::HostCall => host_calls += 1,
+                InvocationMetricsEvent::Recovery => recovered = true,
+                InvocationMetricsEvent::Completed {
+                    timestamp_ms,
+                    consumed_fuel,
+                } => {
+                    metrics.execution_time_ms = Some(timestamp_ms.saturating_sub(invoked_at_ms));
+                    metrics.consumed_fuel = Some(*consumed_fuel);
+                    metrics.host_calls = Some(host_calls);
+                    metrics.recovered = Some(recovered);
+                    break;
+                }
+                InvocationMetricsEvent::Other => {}
+            }
+        }
+
+        metrics
+    }
+}
+
+/// Oplog entries as seen by the metrics of a single invocation
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum InvocationMetricsEvent {
+    /// Start of the measured invocation
+    Invoked {
+        timestamp_ms: u64,
+    },
+    HostCall,
+    /// The worker failed, was interrupted or suspended
+    Recovery,
+    Completed {
+        timestamp_ms: u64,
+        consumed_fuel: i64,
+    },
+    Other,
+}
+
+impl InvocationMetricsEvent {
+    fn new(
+        entry: &PublicOplogEntry,
+        idempotency_key: &golem_common::model::IdempotencyKey,
+    ) -> Self {
+        match entry {
+            PublicOplogEntry::ExportedFunctionInvoked(params)
+                if &params.idempotency_key == idempotency_key =>
+            {
+                Self::Invoked {
+                    timestamp_ms: params.timestamp.to_millis(),
+                }
+            }
+            PublicOplogEntry::ImportedFunctionInvoked(_) => Self::HostCall,
+            PublicOplogEntry::Error(_)
+            | PublicOplogEntry::Interrupted(_)
+            | PublicOplogEntry::Suspend(_) => Self::Recovery,
+            PublicOplogEntry::ExportedFunctionCompleted(params) => Self::Completed {
+                timestamp_ms: params.timestamp.to_millis(),
+                consumed_fuel: params.consumed_fuel,
+            },
+            _ => Self::Other,
+        }
+    }
 }
 
 impl InvokeResultView {
@@ -49,6 +185,7 @@ impl InvokeResultView {
             idempotency_key: idempotency_key.0,
             result_json: Some(result.result),
             result_wave: wave,
+            metrics: None,
         }
     }
 
@@ -57,10 +194,57 @@ impl InvokeResultView {
             idempotency_key: idempotency_key.0,
             result_json: None,
             result_wave: None,
+            metrics: None,
+        }
+    }
+
+    pub fn with_metrics(self, metrics: InvocationMetricsView) -> Self {
+        Self {
+            metrics: Some(metrics),
+            ..self
+        }
+    }
+
+    /// Formats only the result values of an invocation, to be used as the single output
+    /// of the command for scripting
+    pub fn format_result(
+        result: &InvokeResult,
+        result_format: InvokeResultFormat,
+        component: &Component,
+        function: &str,
+    ) -> anyhow::Result<String> {
+        match result_format {
+            InvokeResultFormat::Wave => {
+                Ok(Self::try_parse_wave(&result.result, component, function)?.join("\n"))
+            }
+            InvokeResultFormat::PlainJson => {
+                let mut results = result_values(result)?
+                    .into_iter()
+                    .map(|(value, typ)| to_plain_json(&value, &typ))
+                    .collect::<Vec<_>>();
+                let json = match results.len() {
+                    0 => JsonValue::Null,
+                    1 => results.remove(0),
+                    _ => JsonValue::Array(results),
+                };
+                Ok(serde_json::to_string_pretty(&json)?)
+            }
+            InvokeResultFormat::TypeAnnotated => Ok(serde_json::to_string_pretty(&result.result)?),
         }
     }
 
-    fn try_parse_wave(
+    /// Checks if any of the returned values is a result::err
+    pub fn is_err_result(result: &InvokeResult) -> bool {
+        result_values(result)
+            .map(|results| {
+                results
+                    .iter()
+                    .any(|(value, _)| matches!(value, Value::Result(Err(_))))
+            })
+            .unwrap_or(false)
+    }
+
+    pub fn try_parse_wave(
         result: &TypeAnnotatedValue,
         component: &Component,
         function: &str,
@@ -105,3 +289,395 @@ impl InvokeResultView {
         }
     }
 }
+
+fn result_values(result: &InvokeResult) -> anyhow::Result<Vec<(Value, AnalysedType)>> {
+    let result: ValueAndType = result
+        .result
+        .clone()
+        .try_into()
+        .map_err(|err| anyhow!("Failed to convert invocation result: {err}"))?;
+
+    match (result.value, result.typ) {
+        (Value::Tuple(values), AnalysedType::Tuple(typ)) => {
+            Ok(values.into_iter().zip(typ.items).collect())
+        }
+        (value, typ) => Ok(vec![(value, typ)]),
+    }
+}
+
+fn to_plain_json(value: &Value, typ: &AnalysedType) -> JsonValue {
+    fn tagged(tag: &str, value: Option<JsonValue>) -> JsonValue {
+        let mut object = serde_json::Map::new();
+        object.insert("tag".to_string(), JsonValue::String(tag.to_string()));
+        if let Some(value) = value {
+            object.insert("value".to_string(), value);
+        }
+        JsonValue::Object(object)
+    }
+
+    fn float(value: f64) -> JsonValue {
+        serde_json::Number::from_f64(value)
+            .map(JsonValue::Number)
+            .unwrap_or(JsonValue::Null)
+    }
+
+    fn optional(value: &Option<Box<Value>>, typ: &Option<Box<AnalysedType>>) -> JsonValue {
+        match (value, typ) {
+            (Some(value), Some(typ)) => to_plain_json(value, typ),
+            _ => JsonValue::Null,
+        }
+    }
+
+    match (value, typ) {
+        (Value::Bool(value), _) => JsonValue::Bool(*value),
+        (Value::U8(value), _) => JsonValue::from(*value),
+        (Value::U16(value), _) => JsonValue::from(*value),
+        (Value::U32(value), _) => JsonValue::from(*value),
+        (Value::U64(value), _) => JsonValue::from(*value),
+        (Value::S8(value), _) => JsonValue::from(*value),
+        (Value::S16(value), _) => JsonValue::from(*value),
+        (Value::S32(value), _) => JsonValue::from(*value),
+        (Value::S64(value), _) => JsonValue::from(*value),
+        (Value::F32(value), _) => float(*value as f64),
+        (Value::F64(value), _) => float(*value),
+        (Value::Char(value), _) => JsonValue::String(value.to_string()),
+        (Value::String(value), _) => JsonValue::String(value.clone()),
+        (Value::List(values), AnalysedType::List(typ)) => JsonValue::Array(
+            values
+                .iter()
+                .map(|value| to_plain_json(value, &typ.inner))
+                .collect(),
+        ),
+        (Value::Tuple(values), AnalysedType::Tuple(typ)) => JsonValue::Array(
+            values
+                .iter()
+                .zip(&typ.items)
+                .map(|(value, typ)| to_plain_json(value, typ))
+                .collect(),
+        ),
+        (Value::Record(values), AnalysedType::Record(typ)) => JsonValue::Object(
+            values
+                .iter()
+                .zip(&typ.fields)
+                .map(|(value, field)| (field.name.clone(), to_plain_json(value, &field.typ)))
+                .collect(),
+        ),
+        (
+            Value::Variant {
+                case_idx,
+                case_value,
+            },
+            AnalysedType::Variant(typ),
+        ) => match typ.cases.get(*case_idx as usize) {
+            Some(case) => tagged(
+                &case.name,
+                case_value
+                    .as_ref()
+                    .zip(case.typ.as_ref())
+                    .map(|(value, typ)| to_plain_json(value, typ)),
+            ),
+            None => JsonValue::Null,
+        },
+        (Value::Enum(case_idx), AnalysedType::Enum(typ)) => match typ.cases.get(*case_idx as usize)
+        {
+            Some(case) => tagged(case, None),
+            None => JsonValue::Null,
+        },
+        (Value::Flags(flags), AnalysedType::Flags(typ)) => JsonValue::Array(
+            flags
+                .iter()
+                .zip(&typ.names)
+                .filter(|(is_set, _)| **is_set)
+                .map(|(_, name)| JsonValue::String(name.clone()))
+                .collect(),
+        ),
+        (Value::Option(value), AnalysedType::Option(typ)) => match value {
+            Some(value) => to_plain_json(value, &typ.inner),
+            None => JsonValue::Null,
+        },
+        (Value::Result(result), AnalysedType::Result(typ)) => {
+            let mut object = serde_json::Map::new();
+            match result {
+                Ok(value) => object.insert("ok".to_string(), optional(value, &typ.ok)),
+                Err(value) => object.insert("err".to_string(), optional(value, &typ.err)),
+            };
+            JsonValue::Object(object)
+        }
+        (Value::Handle { uri, resource_id }, _) => {
+            JsonValue::String(format!("{uri}/{resource_id}"))
+        }
+        // The value does not match its type
+        _ => JsonValue::Null,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::invoke_result_view::{
+        to_plain_json, InvocationMetricsEvent, InvocationMetricsView, InvokeResultView,
+    };
+    use assert2::check;
+    use golem_client::model::{InvokeResult, TypeAnnotatedValue};
+    use golem_wasm_ast::analysis::analysed_type::{
+        case, field, flags, list, option, r#enum, record, result, str, tuple, u32, unit_case,
+        variant,
+    };
+    use golem_wasm_ast::analysis::{analysed_type, AnalysedType};
+    use golem_wasm_rpc::{Value, ValueAndType};
+    use serde_json::json;
+    use std::time::Duration;
+    use test_r::test;
+
+    fn invoke_result(results: Vec<(Value, AnalysedType)>) -> InvokeResult {
+        let (values, types): (Vec<_>, Vec<_>) = results.into_iter().unzip();
+        InvokeResult {
+            result: TypeAnnotatedValue::try_from(&ValueAndType {
+                value: Value::Tuple(values),
+                typ: tuple(types),
+            })
+            .unwrap(),
+        }
+    }
+
+    #[test]
+    fn metrics_without_the_invocation_in_the_oplog() {
+        let metrics = InvocationMetricsView::from_events(
+            Duration::from_millis(120),
+            &[
+                InvocationMetricsEvent::HostCall,
+                InvocationMetricsEvent::Other,
+            ],
+        );
+
+        check!(
+            metrics
+                == InvocationMetricsView {
+                    wall_time_ms: 120,
+                    ..InvocationMetricsView::default()
+                }
+        );
+    }
+
+    #[test]
+    fn metrics_of_completed_invocation() {
+        let metrics = InvocationMetricsView::from_events(
+            Duration::from_millis(120),
+            &[
+                // Host calls of earlier invocations are not counted
+                InvocationMetricsEvent::HostCall,
+                InvocationMetricsEvent::Invoked { timestamp_ms: 1000 },
+                InvocationMetricsEvent::HostCall,
+                InvocationMetricsEvent::Other,
+                InvocationMetricsEvent::HostCall,
+                InvocationMetricsEvent::Completed {
+                    timestamp_ms: 1075,
+                    consumed_fuel: 300,
+                },
+                InvocationMetricsEvent::HostCall,
+            ],
+        );
+
+        check!(
+            metrics
+                == InvocationMetricsView {
+                    wall_time_ms: 120,
+                    execution_time_ms: Some(75),
+                    consumed_fuel: Some(300),
+                    host_calls: Some(2),
+                    recovered: Some(false),
+                }
+        );
+    }
+
+    #[test]
+    fn metrics_of_recovered_invocation() {
+        let metrics = InvocationMetricsView::from_events(
+            Duration::from_millis(500),
+            &[
+                InvocationMetricsEvent::Invoked { timestamp_ms: 1000 },
+                InvocationMetricsEvent::Recovery,
+                InvocationMetricsEvent::Completed {
+                    timestamp_ms: 1400,
+                    consumed_fuel: 10,
+                },
+            ],
+        );
+
+        check!(metrics.execution_time_ms == Some(400));
+        check!(metrics.host_calls == Some(0));
+        check!(metrics.recovered == Some(true));
+    }
+
+    #[test]
+    fn metrics_of_the_last_invocation_with_the_same_key() {
+        let metrics = InvocationMetricsView::from_events(
+            Duration::from_millis(500),
+            &[
+                InvocationMetricsEvent::Invoked { timestamp_ms: 1000 },
+                InvocationMetricsEvent::Completed {
+                    timestamp_ms: 1100,
+                    consumed_fuel: 10,
+                },
+                InvocationMetricsEvent::Invoked { timestamp_ms: 2000 },
+                InvocationMetricsEvent::Completed {
+                    timestamp_ms: 2050,
+                    consumed_fuel: 20,
+                },
+            ],
+        );
+
+        check!(metrics.execution_time_ms == Some(50));
+        check!(metrics.consumed_fuel == Some(20));
+    }
+
+    #[test]
+    fn metrics_of_unfinished_invocation() {
+        let metrics = InvocationMetricsView::from_events(
+            Duration::from_millis(500),
+            &[
+                InvocationMetricsEvent::Invoked { timestamp_ms: 1000 },
+                InvocationMetricsEvent::HostCall,
+                InvocationMetricsEvent::Recovery,
+            ],
+        );
+
+        check!(
+            metrics
+                == InvocationMetricsView {
+                    wall_time_ms: 500,
+                    ..InvocationMetricsView::default()
+                }
+        );
+    }
+
+    #[test]
+    fn plain_json_primitives_and_collections() {
+        check!(to_plain_json(&Value::U32(7), &u32()) == json!(7));
+        check!(to_plain_json(&Value::F64(1.5), &analysed_type::f64()) == json!(1.5));
+        check!(to_plain_json(&Value::F64(f64::NAN), &analysed_type::f64()) == json!(null));
+        check!(to_plain_json(&Value::String("a".to_string()), &str()) == json!("a"));
+        check!(
+            to_plain_json(
+                &Value::List(vec![Value::U32(1), Value::U32(2)]),
+                &list(u32())
+            ) == json!([1, 2])
+        );
+        check!(
+            to_plain_json(
+                &Value::Tuple(vec![Value::U32(1), Value::String("b".to_string())]),
+                &tuple(vec![u32(), str()])
+            ) == json!([1, "b"])
+        );
+        check!(
+            to_plain_json(
+                &Value::Flags(vec![true, false, true]),
+                &flags(&["read", "write", "exec"])
+            ) == json!(["read", "exec"])
+        );
+    }
+
+    #[test]
+    fn plain_json_record() {
+        let typ = record(vec![field("name", str()), field("tags", list(str()))]);
+        let value = Value::Record(vec![
+            Value::String("cart".to_string()),
+            Value::List(vec![Value::String("a".to_string())]),
+        ]);
+
+        check!(to_plain_json(&value, &typ) == json!({"name": "cart", "tags": ["a"]}));
+    }
+
+    #[test]
+    fn plain_json_variant_and_enum() {
+        let typ = variant(vec![case("amount", u32()), unit_case("empty")]);
+
+        check!(
+            to_plain_json(
+                &Value::Variant {
+                    case_idx: 0,
+                    case_value: Some(Box::new(Value::U32(3))),
+                },
+                &typ
+            ) == json!({"tag": "amount", "value": 3})
+        );
+        check!(
+            to_plain_json(
+                &Value::Variant {
+                    case_idx: 1,
+                    case_value: None,
+                },
+                &typ
+            ) == json!({"tag": "empty"})
+        );
+        check!(
+            to_plain_json(
+                &Value::Variant {
+                    case_idx: 2,
+                    case_value: None,
+                },
+                &typ
+            ) == json!(null)
+        );
+
+        let typ = r#enum(&["red", "green"]);
+        check!(to_plain_json(&Value::Enum(1), &typ) == json!({"tag": "green"}));
+        check!(to_plain_json(&Value::Enum(5), &typ) == json!(null));
+    }
+
+    #[test]
+    fn plain_json_option_and_result() {
+        check!(
+            to_plain_json(
+                &Value::Option(Some(Box::new(Value::U32(1)))),
+                &option(u32())
+            ) == json!(1)
+        );
+        check!(to_plain_json(&Value::Option(None), &option(u32())) == json!(null));
+
+        let typ = result(u32(), str());
+        check!(
+            to_plain_json(&Value::Result(Ok(Some(Box::new(Value::U32(1))))), &typ)
+                == json!({"ok": 1})
+        );
+        check!(
+            to_plain_json(
+                &Value::Result(Err(Some(Box::new(Value::String("failed".to_string()))))),
+                &typ
+            ) == json!({"err": "failed"})
+        );
+    }
+
+    #[test]
+    fn plain_json_value_not_matching_type() {
+        check!(to_plain_json(&Value::Enum(0), &u32()) == json!(null));
+    }
+
+    #[test]
+    fn is_err_result_checks_every_returned_value() {
+        let typ = result(u32(), str());
+        let ok = Value::Result(Ok(Some(Box::new(Value::U32(1)))));
+        let err = Value::Result(Err(Some(Box::new(Value::String("failed".to_string())))));
+
+        check!(!InvokeResultView::is_err_result(&invoke_result(vec![])));
+        check!(!InvokeResultView::is_err_result(&invoke_result(vec![(
+            ok.clone(),
+            typ.clone()
+        )])));
+        check!(InvokeResultView::is_err_result(&invoke_result(vec![(
+            err.clone(),
+            typ.clone()
+        )])));
+        check!(InvokeResultView::is_err_result(&invoke_result(vec![
+            (Value::U32(1), u32()),
+            (err, typ),
+        ])));
+        // Only results are checked, not variants with an "err" case
+        check!(!InvokeResultView::is_err_result(&invoke_result(vec![(
+            Value::Variant {
+                case_idx: 0,
+                case_value: None,
+            },
+            variant(vec![unit_case("err")]),
+        )])));
+    }
+}
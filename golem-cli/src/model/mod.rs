@@ -14,11 +14,18 @@
 
 pub mod app;
 pub mod app_raw;
+pub mod completion_cache;
 pub mod component;
+pub mod component_diff;
+pub mod component_inspect;
 pub mod deploy;
+pub mod invocation_journal;
+pub mod invoke_request;
 pub mod invoke_result_view;
+pub mod oplog_diff;
 pub mod plugin_manifest;
 pub mod project;
+pub mod scheduled;
 pub mod template;
 pub mod text;
 pub mod to_cloud;
@@ -30,6 +37,7 @@ use crate::cloud::{AccountId, ProjectId};
 use crate::command::shared_args::StreamArgs;
 use crate::config::{
     CloudProfile, NamedProfile, OssProfile, Profile, ProfileConfig, ProfileKind, ProfileName,
+    StreamConfig,
 };
 use crate::model::to_oss::ToOss;
 use anyhow::{anyhow, Context};
@@ -41,11 +49,13 @@ use clap_verbosity_flag::Verbosity;
 use colored::control::SHOULD_COLORIZE;
 use golem_client::model::{
     ApiDefinitionInfo, ApiSite, PluginDefinitionDefaultPluginOwnerDefaultPluginScope,
-    PluginTypeSpecificDefinition, Provider,
+    PluginTypeSpecificDefinition, Provider, PublicOplogEntry,
 };
 use golem_cloud_client::model::PluginDefinitionCloudPluginOwnerCloudPluginScope;
 use golem_common::model::trim_date::TrimDateTime;
+use golem_common::model::{ComponentFilePermissions, LogLevel, Timestamp};
 use golem_templates::model::{GuestLanguage, GuestLanguageTier, Template, TemplateName};
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::{BTreeMap, HashMap};
@@ -54,6 +64,7 @@ use std::fmt::{Debug, Display, Formatter};
 use std::io::Read;
 use std::path::PathBuf;
 use std::str::FromStr;
+use std::time::Duration;
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
 use url::Url;
@@ -140,7 +151,7 @@ impl Display for ComponentName {
     }
 }
 
-#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
 pub struct WorkerName(pub String);
 
 impl From<&str> for WorkerName {
@@ -360,6 +371,7 @@ pub struct TemplateDescription {
     pub language: GuestLanguage,
     pub tier: GuestLanguageTier,
     pub description: String,
+    pub source: String,
 }
 
 impl TemplateDescription {
@@ -369,6 +381,7 @@ impl TemplateDescription {
             language: template.language,
             description: template.description.clone(),
             tier: template.language.tier(),
+            source: template.source.to_string(),
         }
     }
 }
@@ -583,6 +596,507 @@ impl TrimDateTime for WorkersMetadataResponseView {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkerWatchRowView {
+    pub component_name: ComponentName,
+    pub worker_name: WorkerName,
+    pub status: golem_client::model::WorkerStatus,
+    pub component_version: u64,
+    pub pending_invocation_count: u64,
+    pub retry_count: u64,
+    pub total_linear_memory_size: u64,
+    pub status_changed: bool,
+    pub version_changed: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkersWatchView {
+    pub refreshed_at: DateTime<Utc>,
+    pub interval_seconds: u64,
+    pub workers: Vec<WorkerWatchRowView>,
+}
+
+impl WorkersWatchView {
+    /// Creates a new snapshot, marking status and version changes compared to the previous one.
+    /// Workers that were not part of the previous snapshot are marked as changed.
+    pub fn new(
+        previous: Option<&WorkersWatchView>,
+        interval_seconds: u64,
+        workers: Vec<WorkerMetadataView>,
+    ) -> Self {
+        let previous_by_name = previous
+            .map(|previous| {
+                previous
+                    .workers
+                    .iter()
+                    .map(|row| ((&row.component_name, &row.worker_name), row))
+                    .collect::<HashMap<_, _>>()
+            })
+            .unwrap_or_default();
+
+        let workers = workers
+            .into_iter()
+            .map(|worker| {
+                let (status_changed, version_changed) = match previous {
+                    Some(_) => {
+                        match previous_by_name.get(&(&worker.component_name, &worker.worker_name)) {
+                            Some(previous_row) => (
+                                previous_row.status != worker.status,
+                                previous_row.component_version != worker.component_version,
+                            ),
+                            None => (true, true),
+                        }
+                    }
+                    None => (false, false),
+                };
+
+                WorkerWatchRowView {
+                    component_name: worker.component_name,
+                    worker_name: worker.worker_name,
+                    status: worker.status,
+                    component_version: worker.component_version,
+                    pending_invocation_count: worker.pending_invocation_count,
+                    retry_count: worker.retry_count,
+                    total_linear_memory_size: worker.total_linear_memory_size,
+                    status_changed,
+                    version_changed,
+                }
+            })
+            .collect();
+
+        Self {
+            refreshed_at: Utc::now(),
+            interval_seconds,
+            workers,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RevertedInvocationView {
+    pub oplog_index: u64,
+    pub timestamp: Timestamp,
+    pub function_name: String,
+    pub idempotency_key: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkerRevertPreviewView {
+    pub component_name: ComponentName,
+    pub worker_name: WorkerName,
+    pub revert_to_oplog_index: u64,
+    pub invocations: Vec<RevertedInvocationView>,
+    pub entries: Vec<(u64, PublicOplogEntry)>,
+}
+
+impl WorkerRevertPreviewView {
+    /// Selects the tail of the oplog that a revert would undo, mirroring the worker service:
+    /// reverting N invocations reverts to the entry before the N-th last invocation start.
+    pub fn new(
+        component_name: ComponentName,
+        worker_name: WorkerName,
+        oplog: Vec<(u64, PublicOplogEntry)>,
+        last_oplog_index: Option<u64>,
+        number_of_invocations: Option<u64>,
+    ) -> Self {
+        let revert_to_oplog_index = revert_to_oplog_index(
+            oplog.last().map(|(idx, _)| *idx).unwrap_or_default(),
+            oplog
+                .iter()
+                .filter(|(_, entry)| matches!(entry, PublicOplogEntry::ExportedFunctionInvoked(_)))
+                .map(|(idx, _)| *idx),
+            last_oplog_index,
+            number_of_invocations,
+        );
+
+        let entries = oplog
+            .into_iter()
+            .filter(|(idx, _)| *idx > revert_to_oplog_index)
+            .collect::<Vec<_>>();
+
+        let invocations = entries
+            .iter()
+            .filter_map(|(idx, entry)| match entry {
+                PublicOplogEntry::ExportedFunctionInvoked(params) => Some(RevertedInvocationView {
+                    oplog_index: *idx,
+                    timestamp: params.timestamp,
+                    function_name: params.function_name.clone(),
+                    idempotency_key: params.idempotency_key.to_string(),
+                }),
+                _ => None,
+            })
+            .collect();
+
+        Self {
+            component_name,
+            worker_name,
+            revert_to_oplog_index,
+            invocations,
+            entries,
+        }
+    }
+}
+
+/// The oplog index a revert would go back to, based on the last index of the oplog and the
+/// oplog indexes of the invocation starts, in increasing order
+fn revert_to_oplog_index(
+    last_index: u64,
+    invocation_indexes: impl DoubleEndedIterator<Item = u64>,
+    last_oplog_index: Option<u64>,
+    number_of_invocations: Option<u64>,
+) -> u64 {
+    match (last_oplog_index, number_of_invocations) {
+        (Some(last_oplog_index), _) => last_oplog_index,
+        (None, Some(number_of_invocations)) if number_of_invocations > 0 => invocation_indexes
+            .rev()
+            .take(number_of_invocations as usize)
+            .last()
+            .map(|idx| idx.saturating_sub(1))
+            .unwrap_or(last_index),
+        _ => last_index,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum WorkerInvocationOutcome {
+    Running,
+    Completed,
+    Failed,
+    Interrupted,
+}
+
+impl Display for WorkerInvocationOutcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let outcome = match self {
+            WorkerInvocationOutcome::Running => "running",
+            WorkerInvocationOutcome::Completed => "completed",
+            WorkerInvocationOutcome::Failed => "failed",
+            WorkerInvocationOutcome::Interrupted => "interrupted",
+        };
+        write!(f, "{}", outcome)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkerInvocationView {
+    pub oplog_index: u64,
+    pub timestamp: Timestamp,
+    pub function_name: String,
+    pub idempotency_key: String,
+    pub outcome: WorkerInvocationOutcome,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub completed_at: Option<Timestamp>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub error: Option<String>,
+}
+
+impl WorkerInvocationView {
+    /// Collects the invocations and their outcomes from the oplog, keeping only the last ones.
+    ///
+    /// Errors are attributed to the invocation in progress, and a later completion (after retries)
+    /// overrides them.
+    pub fn from_oplog(entries: &[(u64, PublicOplogEntry)], last: usize) -> Vec<Self> {
+        let mut invocations = Vec::<WorkerInvocationView>::new();
+
+        for (idx, entry) in entries {
+            let in_progress = invocations
+                .last_mut()
+                .filter(|invocation| invocation.outcome != WorkerInvocationOutcome::Completed);
+
+            match entry {
+                PublicOplogEntry::ExportedFunctionInvoked(params) => {
+                    invocations.push(WorkerInvocationView {
+                        oplog_index: *idx,
+                        timestamp: params.timestamp,
+                        function_name: params.function_name.clone(),
+                        idempotency_key: params.idempotency_key.to_string(),
+                        outcome: WorkerInvocationOutcome::Running,
+                        completed_at: None,
+                        error: None,
+                    });
+                }
+                PublicOplogEntry::ExportedFunctionCompleted(params) => {
+                    if let Some(invocation) = in_progress {
+                        invocation.outcome = WorkerInvocationOutcome::Completed;
+                        invocation.completed_at = Some(params.timestamp);
+                        invocation.error = None;
+                    }
+                }
+                PublicOplogEntry::Error(params) => {
+                    if let Some(invocation) = in_progress {
+                        invocation.outcome = WorkerInvocationOutcome::Failed;
+                        invocation.error = Some(params.error.clone());
+                    }
+                }
+                PublicOplogEntry::Interrupted(_) => {
+                    if let Some(invocation) = in_progress {
+                        invocation.outcome = WorkerInvocationOutcome::Interrupted;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        invocations.split_off(invocations.len().saturating_sub(last))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkerDescribeView {
+    pub metadata: WorkerMetadataView,
+    pub latest_component_version: u64,
+    /// Exported functions of the component version used by the worker
+    pub exports: Vec<String>,
+    pub exports_added_in_latest: Vec<String>,
+    pub exports_removed_in_latest: Vec<String>,
+    pub invocations: Vec<WorkerInvocationView>,
+    pub pending_updates: Vec<golem_client::model::UpdateRecord>,
+    pub failed_updates: Vec<golem_client::model::UpdateRecord>,
+}
+
+impl WorkerDescribeView {
+    pub fn new(
+        metadata: WorkerMetadataView,
+        latest_component_version: u64,
+        exports: Vec<String>,
+        latest_exports: Vec<String>,
+        invocations: Vec<WorkerInvocationView>,
+    ) -> Self {
+        let exports_added_in_latest = latest_exports
+            .iter()
+            .filter(|export| !exports.contains(export))
+            .cloned()
+            .collect();
+        let exports_removed_in_latest = exports
+            .iter()
+            .filter(|export| !latest_exports.contains(export))
+            .cloned()
+            .collect();
+
+        let pending_updates = metadata
+            .updates
+            .iter()
+            .filter(|update| matches!(update, golem_client::model::UpdateRecord::PendingUpdate(_)))
+            .cloned()
+            .collect();
+        let failed_updates = metadata
+            .updates
+            .iter()
+            .filter(|update| matches!(update, golem_client::model::UpdateRecord::FailedUpdate(_)))
+            .cloned()
+            .collect();
+
+        Self {
+            metadata,
+            latest_component_version,
+            exports,
+            exports_added_in_latest,
+            exports_removed_in_latest,
+            invocations,
+            pending_updates,
+            failed_updates,
+        }
+    }
+}
+
+/// Upper bounds of the latency histogram buckets of `worker bench`, in milliseconds
+pub const WORKER_BENCH_HISTOGRAM_BOUNDS_MS: &[u64] = &[
+    1, 2, 5, 10, 20, 50, 100, 200, 500, 1000, 2000, 5000, 10000, 30000, 60000,
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkerBenchErrorView {
+    pub kind: String,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkerBenchLatencyView {
+    pub min_ms: f64,
+    pub mean_ms: f64,
+    pub p50_ms: f64,
+    pub p90_ms: f64,
+    pub p95_ms: f64,
+    pub p99_ms: f64,
+    pub max_ms: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkerBenchHistogramBucketView {
+    /// Inclusive upper bound of the bucket, missing for the last, unbounded bucket
+    pub upper_bound_ms: Option<u64>,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkerBenchView {
+    pub component_name: ComponentName,
+    pub function_name: String,
+    pub workers: u64,
+    pub concurrency: u64,
+    pub target_rate: Option<f64>,
+    pub duration_ms: u64,
+    pub invocations: usize,
+    pub successful: usize,
+    pub failed: usize,
+    /// Successful invocations per second
+    pub throughput: f64,
+    pub errors: Vec<WorkerBenchErrorView>,
+    /// Latency of the successful invocations, missing if there were none
+    pub latency: Option<WorkerBenchLatencyView>,
+    pub histogram: Vec<WorkerBenchHistogramBucketView>,
+}
+
+impl WorkerBenchView {
+    pub fn new(
+        component_name: ComponentName,
+        function_name: String,
+        workers: u64,
+        concurrency: u64,
+        target_rate: Option<f64>,
+        elapsed: Duration,
+        mut latencies: Vec<Duration>,
+        errors: Vec<String>,
+    ) -> Self {
+        fn to_ms(duration: Duration) -> f64 {
+            duration.as_secs_f64() * 1000.0
+        }
+
+        let successful = latencies.len();
+        let failed = errors.len();
+
+        let throughput = if elapsed.is_zero() {
+            0.0
+        } else {
+            successful as f64 / elapsed.as_secs_f64()
+        };
+
+        let mut error_counts = BTreeMap::<String, usize>::new();
+        for kind in errors {
+            *error_counts.entry(kind).or_default() += 1;
+        }
+        let mut errors = error_counts
+            .into_iter()
+            .map(|(kind, count)| WorkerBenchErrorView { kind, count })
+            .collect::<Vec<_>>();
+        errors.sort_by(|a, b| b.count.cmp(&a.count));
+
+        latencies.sort();
+
+        let latency = (!latencies.is_empty()).then(|| {
+            // Nearest-rank percentile
+            let percentile = |p: f64| {
+                let rank = (p / 100.0 * latencies.len() as f64).ceil() as usize;
+                to_ms(latencies[rank.clamp(1, latencies.len()) - 1])
+            };
+            WorkerBenchLatencyView {
+                min_ms: to_ms(latencies[0]),
+                mean_ms: latencies.iter().map(|latency| to_ms(*latency)).sum::<f64>()
+                    / latencies.len() as f64,
+                p50_ms: percentile(50.0),
+                p90_ms: percentile(90.0),
+                p95_ms: percentile(95.0),
+                p99_ms: percentile(99.0),
+                max_ms: to_ms(latencies[latencies.len() - 1]),
+            }
+        });
+
+        let mut bucket_counts = vec![0usize; WORKER_BENCH_HISTOGRAM_BOUNDS_MS.len() + 1];
+        for latency in &latencies {
+            let latency_ms = to_ms(*latency);
+            let bucket = WORKER_BENCH_HISTOGRAM_BOUNDS_MS
+                .iter()
+                .position(|bound| latency_ms <= *bound as f64)
+                .unwrap_or(WORKER_BENCH_HISTOGRAM_BOUNDS_MS.len());
+            bucket_counts[bucket] += 1;
+        }
+        let histogram = match (
+            bucket_counts.iter().position(|count| *count > 0),
+            bucket_counts.iter().rposition(|count| *count > 0),
+        ) {
+            (Some(first), Some(last)) => (first..=last)
+                .map(|bucket| WorkerBenchHistogramBucketView {
+                    upper_bound_ms: WORKER_BENCH_HISTOGRAM_BOUNDS_MS.get(bucket).copied(),
+                    count: bucket_counts[bucket],
+                })
+                .collect(),
+            _ => vec![],
+        };
+
+        Self {
+            component_name,
+            function_name,
+            workers,
+            concurrency,
+            target_rate,
+            duration_ms: elapsed.as_millis() as u64,
+            invocations: successful + failed,
+            successful,
+            failed,
+            throughput,
+            errors,
+            latency,
+            histogram,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkerFileView {
+    pub name: String,
+    pub is_directory: bool,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub permissions: Option<ComponentFilePermissions>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub size: Option<u64>,
+    pub last_modified: DateTime<Utc>,
+}
+
+impl WorkerFileView {
+    pub fn from_oss(value: golem_client::model::FlatComponentFileSystemNode) -> Self {
+        WorkerFileView {
+            name: value.name,
+            is_directory: matches!(
+                value.kind,
+                golem_client::model::FlatComponentFileSystemNodeKind::Directory
+            ),
+            permissions: value.permissions,
+            size: value.size,
+            last_modified: Self::last_modified(value.last_modified),
+        }
+    }
+
+    pub fn from_cloud(value: golem_cloud_client::model::FlatComponentFileSystemNode) -> Self {
+        WorkerFileView {
+            name: value.name,
+            is_directory: matches!(
+                value.kind,
+                golem_cloud_client::model::FlatComponentFileSystemNodeKind::Directory
+            ),
+            permissions: value.permissions,
+            size: value.size,
+            last_modified: Self::last_modified(value.last_modified),
+        }
+    }
+
+    // The worker service reports modification times as seconds since the Unix epoch
+    fn last_modified(seconds: u64) -> DateTime<Utc> {
+        DateTime::from_timestamp(seconds as i64, 0).unwrap_or_default()
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ApiDeployment {
     #[serde(rename = "apiDefinitions")]
@@ -659,19 +1173,108 @@ pub trait HasVerbosity {
     fn verbosity(&self) -> Verbosity;
 }
 
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[clap(rename_all = "kebab_case")]
+pub enum StreamLogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+    Critical,
+}
+
+impl From<LogLevel> for StreamLogLevel {
+    fn from(value: LogLevel) -> Self {
+        match value {
+            LogLevel::Trace => Self::Trace,
+            LogLevel::Debug => Self::Debug,
+            LogLevel::Info => Self::Info,
+            LogLevel::Warn => Self::Warn,
+            LogLevel::Error => Self::Error,
+            LogLevel::Critical => Self::Critical,
+        }
+    }
+}
+
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+#[clap(rename_all = "kebab_case")]
+pub enum InvokeResultFormat {
+    /// One WAVE encoded value per line
+    Wave,
+    /// JSON without type annotations: records as objects, variants and enums as tagged objects,
+    /// options as the value or null, results as {"ok": ..} or {"err": ..}
+    PlainJson,
+    /// Type annotated JSON, as returned by the worker service
+    TypeAnnotated,
+}
+
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+#[clap(rename_all = "kebab_case")]
+pub enum InvokeRequestFormat {
+    /// curl command
+    Curl,
+    /// HTTPie command
+    Httpie,
+    /// JSON object with the method, URL, headers and body of the request
+    Json,
+}
+
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+#[clap(rename_all = "kebab_case")]
+pub enum StreamOutputFileFormat {
+    Text,
+    JsonLines,
+}
+
 #[derive(Debug, Clone)]
 pub struct WorkerConnectOptions {
     pub colors: bool,
     pub show_timestamp: bool,
     pub show_level: bool,
-}
+    pub min_level: Option<StreamLogLevel>,
+    pub context_filter: Option<Regex>,
+    pub message_filter: Option<Regex>,
+    /// Prefix for every line of the stream, used when streaming multiple workers
+    pub worker_label: Option<String>,
+    pub connection: StreamConfig,
+}
+
+impl WorkerConnectOptions {
+    /// Creates connect options from the stream flags, using the profile's stream config
+    /// for connection settings not overridden by flags
+    pub fn new(args: StreamArgs, stream_config: &StreamConfig) -> Self {
+        let connection = StreamConfig {
+            ping_interval_ms: args
+                .stream_ping_interval_ms
+                .unwrap_or(stream_config.ping_interval_ms),
+            reconnect_initial_delay_ms: args
+                .stream_reconnect_initial_delay_ms
+                .unwrap_or(stream_config.reconnect_initial_delay_ms),
+            reconnect_max_delay_ms: args
+                .stream_reconnect_max_delay_ms
+                .unwrap_or(stream_config.reconnect_max_delay_ms),
+            // Same as in the profile config, 0 means unlimited attempts and disabled idle timeout
+            reconnect_max_attempts: args
+                .stream_reconnect_max_attempts
+                .map(|attempts| (attempts > 0).then_some(attempts))
+                .unwrap_or(stream_config.reconnect_max_attempts),
+            reconnect_jitter: !args.stream_no_reconnect_jitter && stream_config.reconnect_jitter,
+            idle_timeout_ms: args
+                .stream_idle_timeout_ms
+                .map(|idle_timeout_ms| (idle_timeout_ms > 0).then_some(idle_timeout_ms))
+                .unwrap_or(stream_config.idle_timeout_ms),
+        };
 
-impl From<StreamArgs> for WorkerConnectOptions {
-    fn from(args: StreamArgs) -> Self {
         WorkerConnectOptions {
             colors: SHOULD_COLORIZE.should_colorize(),
             show_timestamp: !args.stream_no_timestamp,
             show_level: !args.stream_no_log_level,
+            min_level: args.stream_min_level,
+            context_filter: args.stream_context,
+            message_filter: args.stream_grep,
+            worker_label: None,
+            connection,
         }
     }
 }
@@ -757,6 +1360,28 @@ pub struct WorkerNameMatch {
     pub worker_name: Option<WorkerName>,
 }
 
+impl WorkerNameMatch {
+    /// Worker name including the account, project and component, resolving to the same worker
+    /// independently of the current application context
+    pub fn qualified_worker_name(&self) -> WorkerName {
+        let mut segments = Vec::with_capacity(4);
+        if let Some(account_id) = &self.account_id {
+            segments.push(account_id.0.as_str());
+        }
+        if let Some(project) = &self.project {
+            segments.push(project.project_name.0.as_str());
+        }
+        segments.push(self.component_name.0.as_str());
+        segments.push(
+            self.worker_name
+                .as_ref()
+                .map(|worker_name| worker_name.0.as_str())
+                .unwrap_or("-"),
+        );
+        segments.join("/").into()
+    }
+}
+
 pub struct SelectedComponents {
     pub account_id: Option<AccountId>,
     pub project: Option<ProjectNameAndId>,
@@ -1062,3 +1687,313 @@ impl From<PluginDefinitionCloudPluginOwnerCloudPluginScope> for PluginDefinition
         plugin_definition
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{
+        revert_to_oplog_index, WorkerBenchView, WorkerDescribeView, WorkerMetadataView,
+        WorkersWatchView,
+    };
+    use assert2::check;
+    use chrono::Utc;
+    use golem_client::model::{
+        FailedUpdate, PendingUpdate, SuccessfulUpdate, UpdateRecord, WorkerStatus,
+    };
+    use std::collections::HashMap;
+    use std::time::Duration;
+    use test_r::test;
+
+    fn worker_metadata_view(updates: Vec<UpdateRecord>) -> WorkerMetadataView {
+        WorkerMetadataView {
+            component_name: "app:component".into(),
+            worker_name: "worker".into(),
+            account_id: None,
+            args: vec![],
+            env: HashMap::new(),
+            status: WorkerStatus::Idle,
+            component_version: 1,
+            retry_count: 0,
+            pending_invocation_count: 0,
+            updates,
+            created_at: Utc::now(),
+            last_error: None,
+            component_size: 0,
+            total_linear_memory_size: 0,
+            owned_resources: HashMap::new(),
+        }
+    }
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    fn watched_worker(
+        component_name: &str,
+        worker_name: &str,
+        status: WorkerStatus,
+        component_version: u64,
+    ) -> WorkerMetadataView {
+        WorkerMetadataView {
+            component_name: component_name.into(),
+            worker_name: worker_name.into(),
+            status,
+            component_version,
+            ..worker_metadata_view(vec![])
+        }
+    }
+
+    fn watch_changes(view: &WorkersWatchView) -> Vec<(String, bool, bool)> {
+        view.workers
+            .iter()
+            .map(|row| {
+                (
+                    format!("{}/{}", row.component_name.0, row.worker_name.0),
+                    row.status_changed,
+                    row.version_changed,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn workers_watch_view_marks_changes_compared_to_previous_snapshot() {
+        let first = WorkersWatchView::new(
+            None,
+            2,
+            vec![
+                watched_worker("comp", "w1", WorkerStatus::Idle, 1),
+                watched_worker("comp", "w2", WorkerStatus::Running, 1),
+            ],
+        );
+        // The first snapshot has nothing to be compared to
+        check!(
+            watch_changes(&first)
+                == vec![
+                    ("comp/w1".to_string(), false, false),
+                    ("comp/w2".to_string(), false, false)
+                ]
+        );
+        check!(first.interval_seconds == 2);
+
+        let second = WorkersWatchView::new(
+            Some(&first),
+            2,
+            vec![
+                watched_worker("comp", "w1", WorkerStatus::Running, 1),
+                watched_worker("comp", "w2", WorkerStatus::Running, 2),
+                watched_worker("comp", "w3", WorkerStatus::Idle, 1),
+                watched_worker("other", "w1", WorkerStatus::Idle, 1),
+            ],
+        );
+        check!(
+            watch_changes(&second)
+                == vec![
+                    ("comp/w1".to_string(), true, false),
+                    ("comp/w2".to_string(), false, true),
+                    ("comp/w3".to_string(), true, true),
+                    ("other/w1".to_string(), true, true)
+                ]
+        );
+
+        let third = WorkersWatchView::new(
+            Some(&second),
+            2,
+            vec![watched_worker("comp", "w1", WorkerStatus::Running, 1)],
+        );
+        check!(watch_changes(&third) == vec![("comp/w1".to_string(), false, false)]);
+    }
+
+    #[test]
+    fn describe_view_compares_exports_with_latest_version() {
+        let view = WorkerDescribeView::new(
+            worker_metadata_view(vec![]),
+            2,
+            strings(&["api.{get}", "api.{delete}"]),
+            strings(&["api.{get}", "api.{add}"]),
+            vec![],
+        );
+
+        check!(view.exports_added_in_latest == strings(&["api.{add}"]));
+        check!(view.exports_removed_in_latest == strings(&["api.{delete}"]));
+        check!(view.pending_updates.is_empty());
+        check!(view.failed_updates.is_empty());
+    }
+
+    #[test]
+    fn describe_view_separates_pending_and_failed_updates() {
+        let view = WorkerDescribeView::new(
+            worker_metadata_view(vec![
+                UpdateRecord::SuccessfulUpdate(SuccessfulUpdate {
+                    timestamp: Utc::now(),
+                    target_version: 1,
+                }),
+                UpdateRecord::FailedUpdate(FailedUpdate {
+                    timestamp: Utc::now(),
+                    target_version: 2,
+                    details: Some("missing export".to_string()),
+                }),
+                UpdateRecord::PendingUpdate(PendingUpdate {
+                    timestamp: Utc::now(),
+                    target_version: 3,
+                }),
+            ]),
+            3,
+            vec![],
+            vec![],
+            vec![],
+        );
+
+        let target_versions = |updates: &[UpdateRecord]| {
+            updates
+                .iter()
+                .map(|update| match update {
+                    UpdateRecord::PendingUpdate(update) => update.target_version,
+                    UpdateRecord::SuccessfulUpdate(update) => update.target_version,
+                    UpdateRecord::FailedUpdate(update) => update.target_version,
+                })
+                .collect::<Vec<_>>()
+        };
+        check!(target_versions(&view.pending_updates) == vec![3]);
+        check!(target_versions(&view.failed_updates) == vec![2]);
+        check!(view.metadata.updates.len() == 3);
+    }
+
+    #[test]
+    fn revert_to_explicit_oplog_index() {
+        check!(revert_to_oplog_index(12, [2, 5, 9].into_iter(), Some(7), None) == 7);
+        check!(revert_to_oplog_index(12, [2, 5, 9].into_iter(), Some(7), Some(1)) == 7);
+        check!(revert_to_oplog_index(0, [].into_iter(), Some(7), None) == 7);
+    }
+
+    #[test]
+    fn revert_last_invocations() {
+        let revert = |number_of_invocations| {
+            revert_to_oplog_index(12, [2, 5, 9].into_iter(), None, Some(number_of_invocations))
+        };
+
+        check!(revert(1) == 8);
+        check!(revert(2) == 4);
+        check!(revert(3) == 1);
+        // Reverting more invocations than available reverts all of them
+        check!(revert(5) == 1);
+        check!(revert(0) == 12);
+    }
+
+    #[test]
+    fn revert_without_invocations() {
+        check!(revert_to_oplog_index(12, [].into_iter(), None, Some(2)) == 12);
+        check!(revert_to_oplog_index(12, [].into_iter(), None, None) == 12);
+        check!(revert_to_oplog_index(3, [0].into_iter(), None, Some(1)) == 0);
+    }
+
+    fn bench_view(elapsed: Duration, latencies_ms: &[u64], errors: &[&str]) -> WorkerBenchView {
+        WorkerBenchView::new(
+            "component".into(),
+            "api.{run}".to_string(),
+            1,
+            1,
+            None,
+            elapsed,
+            latencies_ms
+                .iter()
+                .map(|latency_ms| Duration::from_millis(*latency_ms))
+                .collect(),
+            errors.iter().map(|error| error.to_string()).collect(),
+        )
+    }
+
+    fn approx_eq(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn bench_without_samples() {
+        let view = bench_view(Duration::ZERO, &[], &["timeout", "trap", "timeout"]);
+
+        check!(view.invocations == 3);
+        check!(view.successful == 0);
+        check!(view.failed == 3);
+        check!(view.throughput == 0.0);
+        check!(view.latency.is_none());
+        check!(view.histogram.is_empty());
+        check!(view.errors[0].kind == "timeout");
+        check!(view.errors[0].count == 2);
+        check!(view.errors[1].kind == "trap");
+    }
+
+    #[test]
+    fn bench_with_single_sample() {
+        let view = bench_view(Duration::from_secs(2), &[7], &[]);
+        let latency = view.latency.unwrap();
+
+        check!(approx_eq(view.throughput, 0.5));
+        for value in [
+            latency.min_ms,
+            latency.mean_ms,
+            latency.p50_ms,
+            latency.p90_ms,
+            latency.p99_ms,
+            latency.max_ms,
+        ] {
+            check!(approx_eq(value, 7.0));
+        }
+        check!(view.histogram.len() == 1);
+        check!(view.histogram[0].upper_bound_ms == Some(10));
+        check!(view.histogram[0].count == 1);
+    }
+
+    #[test]
+    fn bench_percentiles_use_nearest_rank() {
+        let latencies = (1..=100).rev().collect::<Vec<_>>();
+        let view = bench_view(Duration::from_secs(10), &latencies, &[]);
+        let latency = view.latency.unwrap();
+
+        check!(approx_eq(view.throughput, 10.0));
+        check!(approx_eq(latency.min_ms, 1.0));
+        check!(approx_eq(latency.mean_ms, 50.5));
+        check!(approx_eq(latency.p50_ms, 50.0));
+        check!(approx_eq(latency.p90_ms, 90.0));
+        check!(approx_eq(latency.p95_ms, 95.0));
+        check!(approx_eq(latency.p99_ms, 99.0));
+        check!(approx_eq(latency.max_ms, 100.0));
+
+        // One more sample moves the p50 and p99 ranks up
+        let latencies = (1..=101).collect::<Vec<_>>();
+        let latency = bench_view(Duration::from_secs(10), &latencies, &[])
+            .latency
+            .unwrap();
+
+        check!(approx_eq(latency.p50_ms, 51.0));
+        check!(approx_eq(latency.p99_ms, 100.0));
+    }
+
+    #[test]
+    fn bench_histogram_is_trimmed_to_used_buckets() {
+        let view = bench_view(Duration::from_secs(1), &[3, 5, 40, 120_000], &[]);
+
+        let buckets = view
+            .histogram
+            .iter()
+            .map(|bucket| (bucket.upper_bound_ms, bucket.count))
+            .collect::<Vec<_>>();
+        check!(
+            buckets
+                == vec![
+                    (Some(5), 2),
+                    (Some(10), 0),
+                    (Some(20), 0),
+                    (Some(50), 1),
+                    (Some(100), 0),
+                    (Some(200), 0),
+                    (Some(500), 0),
+                    (Some(1000), 0),
+                    (Some(2000), 0),
+                    (Some(5000), 0),
+                    (Some(10000), 0),
+                    (Some(30000), 0),
+                    (Some(60000), 0),
+                    (None, 1),
+                ]
+        );
+    }
+}
@@ -0,0 +1,465 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use golem_client::model::PublicOplogEntry;
+use golem_common::model::public_oplog::PublicWorkerInvocation;
+use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
+use golem_wasm_rpc::{print_type_annotated_value, ValueAndType};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+// Above this (after trimming the common prefix and suffix) the entries are paired by position
+// instead of computing the longest common subsequence, to keep memory usage bounded
+const MAX_ALIGNED_CELLS: usize = 16 * 1024 * 1024;
+
+/// Inclusive range of oplog indexes, both ends are optional
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OplogIndexRange {
+    pub from: Option<u64>,
+    pub to: Option<u64>,
+}
+
+impl OplogIndexRange {
+    pub fn contains(&self, oplog_index: u64) -> bool {
+        self.from.is_none_or(|from| from <= oplog_index)
+            && self.to.is_none_or(|to| oplog_index <= to)
+    }
+}
+
+impl Display for OplogIndexRange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(from) = self.from {
+            write!(f, "{}", from)?;
+        }
+        write!(f, "..")?;
+        if let Some(to) = self.to {
+            write!(f, "{}", to)?;
+        }
+        Ok(())
+    }
+}
+
+/// Comparable summary of an oplog entry, without timestamps and idempotency keys,
+/// which are expected to differ between workers
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OplogDiffEntryView {
+    pub oplog_index: u64,
+    pub kind: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub function_name: Option<String>,
+    /// Arguments, responses, errors and other compared details
+    pub details: Vec<String>,
+}
+
+impl OplogDiffEntryView {
+    pub fn new(oplog_index: u64, entry: &PublicOplogEntry) -> Self {
+        let (kind, function_name, details) = match entry {
+            PublicOplogEntry::Create(params) => (
+                "CREATE",
+                None,
+                vec![
+                    format!("component version: {}", params.component_version),
+                    format!("args: {}", params.args.join(", ")),
+                ],
+            ),
+            PublicOplogEntry::ImportedFunctionInvoked(params) => (
+                "CALL",
+                Some(params.function_name.clone()),
+                vec![
+                    format!("input: {}", value_to_string(&params.request)),
+                    format!("result: {}", value_to_string(&params.response)),
+                ],
+            ),
+            PublicOplogEntry::ExportedFunctionInvoked(params) => (
+                "INVOKE",
+                Some(params.function_name.clone()),
+                params
+                    .request
+                    .iter()
+                    .map(|param| format!("input: {}", value_to_string(param)))
+                    .collect(),
+            ),
+            PublicOplogEntry::ExportedFunctionCompleted(params) => (
+                "INVOKE COMPLETED",
+                None,
+                vec![format!("result: {}", value_to_string(&params.response))],
+            ),
+            PublicOplogEntry::Error(params) => {
+                ("ERROR", None, vec![format!("error: {}", params.error)])
+            }
+            PublicOplogEntry::PendingWorkerInvocation(params) => match &params.invocation {
+                PublicWorkerInvocation::ExportedFunction(params) => (
+                    "ENQUEUED INVOCATION",
+                    Some(params.full_function_name.clone()),
+                    params
+                        .function_input
+                        .iter()
+                        .flatten()
+                        .map(|param| format!("input: {}", value_to_string(param)))
+                        .collect(),
+                ),
+                PublicWorkerInvocation::ManualUpdate(params) => (
+                    "ENQUEUED MANUAL UPDATE",
+                    None,
+                    vec![format!("target version: {}", params.target_version)],
+                ),
+            },
+            PublicOplogEntry::FailedUpdate(params) => (
+                "FAILED UPDATE",
+                None,
+                vec![
+                    format!("target version: {}", params.target_version),
+                    format!("details: {}", params.details.as_deref().unwrap_or("-")),
+                ],
+            ),
+            PublicOplogEntry::Log(params) => (
+                "LOG",
+                None,
+                vec![
+                    format!("level: {:?}", params.level),
+                    format!("message: {}", params.message),
+                ],
+            ),
+            // The remaining entries are only compared by their kind
+            PublicOplogEntry::Suspend(_) => ("SUSPEND", None, vec![]),
+            PublicOplogEntry::NoOp(_) => ("NOP", None, vec![]),
+            PublicOplogEntry::Jump(_) => ("JUMP", None, vec![]),
+            PublicOplogEntry::Interrupted(_) => ("INTERRUPTED", None, vec![]),
+            PublicOplogEntry::Exited(_) => ("EXITED", None, vec![]),
+            PublicOplogEntry::ChangeRetryPolicy(_) => ("CHANGE RETRY POLICY", None, vec![]),
+            PublicOplogEntry::BeginAtomicRegion(_) => ("BEGIN ATOMIC REGION", None, vec![]),
+            PublicOplogEntry::EndAtomicRegion(_) => ("END ATOMIC REGION", None, vec![]),
+            PublicOplogEntry::BeginRemoteWrite(_) => ("BEGIN REMOTE WRITE", None, vec![]),
+            PublicOplogEntry::EndRemoteWrite(_) => ("END REMOTE WRITE", None, vec![]),
+            PublicOplogEntry::PendingUpdate(_) => ("ENQUEUED UPDATE", None, vec![]),
+            PublicOplogEntry::SuccessfulUpdate(_) => ("SUCCESSFUL UPDATE", None, vec![]),
+            PublicOplogEntry::GrowMemory(_) => ("GROW MEMORY", None, vec![]),
+            PublicOplogEntry::CreateResource(_) => ("CREATE RESOURCE", None, vec![]),
+            PublicOplogEntry::DropResource(_) => ("DROP RESOURCE", None, vec![]),
+            PublicOplogEntry::DescribeResource(_) => ("DESCRIBE RESOURCE", None, vec![]),
+            PublicOplogEntry::Restart(_) => ("RESTART", None, vec![]),
+            PublicOplogEntry::ActivatePlugin(_) => ("ACTIVATE PLUGIN", None, vec![]),
+            PublicOplogEntry::DeactivatePlugin(_) => ("DEACTIVATE PLUGIN", None, vec![]),
+            PublicOplogEntry::Revert(_) => ("REVERT", None, vec![]),
+            PublicOplogEntry::CancelInvocation(_) => ("CANCEL INVOCATION", None, vec![]),
+            PublicOplogEntry::StartSpan(_) => ("START SPAN", None, vec![]),
+            PublicOplogEntry::FinishSpan(_) => ("FINISH SPAN", None, vec![]),
+            PublicOplogEntry::SetSpanAttribute(_) => ("SET SPAN ATTRIBUTE", None, vec![]),
+            PublicOplogEntry::ChangePersistenceLevel(_) => {
+                ("CHANGE PERSISTENCE LEVEL", None, vec![])
+            }
+        };
+
+        Self {
+            oplog_index,
+            kind: kind.to_string(),
+            function_name,
+            details,
+        }
+    }
+
+    // Entries with the same key are aligned, and then compared by their details
+    fn alignment_key(&self) -> (&str, Option<&str>) {
+        (self.kind.as_str(), self.function_name.as_deref())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum OplogDiffLineKind {
+    Equal,
+    Changed,
+    Removed,
+    Added,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OplogDiffLineView {
+    pub kind: OplogDiffLineKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub left: Option<OplogDiffEntryView>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub right: Option<OplogDiffEntryView>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkerOplogDiffView {
+    pub left_name: String,
+    pub right_name: String,
+    pub equal: usize,
+    pub changed: usize,
+    pub removed: usize,
+    pub added: usize,
+    pub lines: Vec<OplogDiffLineView>,
+    /// Show the equal entries in the text view, not only the context of the differences
+    #[serde(skip)]
+    pub show_all_entries: bool,
+}
+
+impl WorkerOplogDiffView {
+    pub fn new(
+        left_name: String,
+        left: &[(u64, PublicOplogEntry)],
+        right_name: String,
+        right: &[(u64, PublicOplogEntry)],
+        show_all_entries: bool,
+    ) -> Self {
+        let left = left
+            .iter()
+            .map(|(idx, entry)| OplogDiffEntryView::new(*idx, entry))
+            .collect::<Vec<_>>();
+        let right = right
+            .iter()
+            .map(|(idx, entry)| OplogDiffEntryView::new(*idx, entry))
+            .collect::<Vec<_>>();
+
+        let lines = align(left, right)
+            .into_iter()
+            .map(|(left, right)| {
+                let kind = match (&left, &right) {
+                    (Some(left), Some(right)) if left.details == right.details => {
+                        OplogDiffLineKind::Equal
+                    }
+                    (Some(_), Some(_)) => OplogDiffLineKind::Changed,
+                    (Some(_), None) => OplogDiffLineKind::Removed,
+                    (None, _) => OplogDiffLineKind::Added,
+                };
+                OplogDiffLineView { kind, left, right }
+            })
+            .collect::<Vec<_>>();
+
+        let count = |kind: OplogDiffLineKind| lines.iter().filter(|line| line.kind == kind).count();
+
+        Self {
+            left_name,
+            right_name,
+            equal: count(OplogDiffLineKind::Equal),
+            changed: count(OplogDiffLineKind::Changed),
+            removed: count(OplogDiffLineKind::Removed),
+            added: count(OplogDiffLineKind::Added),
+            lines,
+            show_all_entries,
+        }
+    }
+
+    pub fn has_differences(&self) -> bool {
+        self.changed + self.removed + self.added > 0
+    }
+}
+
+type AlignedEntries = Vec<(Option<OplogDiffEntryView>, Option<OplogDiffEntryView>)>;
+
+// Aligns the entries by the longest common subsequence of their alignment keys
+fn align(left: Vec<OplogDiffEntryView>, right: Vec<OplogDiffEntryView>) -> AlignedEntries {
+    let prefix = left
+        .iter()
+        .zip(&right)
+        .take_while(|(left, right)| left.alignment_key() == right.alignment_key())
+        .count();
+    let suffix = left[prefix..]
+        .iter()
+        .rev()
+        .zip(right[prefix..].iter().rev())
+        .take_while(|(left, right)| left.alignment_key() == right.alignment_key())
+        .count();
+
+    let left_middle = &left[prefix..left.len() - suffix];
+    let right_middle = &right[prefix..right.len() - suffix];
+    let (n, m) = (left_middle.len(), right_middle.len());
+
+    let mut pairs = Vec::<(Option<usize>, Option<usize>)>::new();
+    pairs.extend((0..prefix).map(|idx| (Some(idx), Some(idx))));
+
+    if n.saturating_mul(m) > MAX_ALIGNED_CELLS {
+        pairs.extend((0..n.max(m)).map(|idx| {
+            (
+                (idx < n).then_some(prefix + idx),
+                (idx < m).then_some(prefix + idx),
+            )
+        }));
+    } else {
+        // lcs[i][j] is the LCS length of left_middle[i..] and right_middle[j..]
+        let mut lcs = vec![0u32; (n + 1) * (m + 1)];
+        let cell = |i: usize, j: usize| i * (m + 1) + j;
+        for i in (0..n).rev() {
+            for j in (0..m).rev() {
+                lcs[cell(i, j)] =
+                    if left_middle[i].alignment_key() == right_middle[j].alignment_key() {
+                        lcs[cell(i + 1, j + 1)] + 1
+                    } else {
+                        lcs[cell(i + 1, j)].max(lcs[cell(i, j + 1)])
+                    };
+            }
+        }
+
+        let (mut i, mut j) = (0, 0);
+        while i < n || j < m {
+            if i < n && j < m && left_middle[i].alignment_key() == right_middle[j].alignment_key() {
+                pairs.push((Some(prefix + i), Some(prefix + j)));
+                i += 1;
+                j += 1;
+            } else if j == m || (i < n && lcs[cell(i + 1, j)] >= lcs[cell(i, j + 1)]) {
+                pairs.push((Some(prefix + i), None));
+                i += 1;
+            } else {
+                pairs.push((None, Some(prefix + j)));
+                j += 1;
+            }
+        }
+    }
+
+    pairs.extend((0..suffix).map(|idx| {
+        (
+            Some(left.len() - suffix + idx),
+            Some(right.len() - suffix + idx),
+        )
+    }));
+
+    let mut left = left.into_iter().map(Some).collect::<Vec<_>>();
+    let mut right = right.into_iter().map(Some).collect::<Vec<_>>();
+    pairs
+        .into_iter()
+        .map(|(left_idx, right_idx)| {
+            (
+                left_idx.and_then(|idx| left[idx].take()),
+                right_idx.and_then(|idx| right[idx].take()),
+            )
+        })
+        .collect()
+}
+
+fn value_to_string(value: &ValueAndType) -> String {
+    TypeAnnotatedValue::try_from(value)
+        .ok()
+        .and_then(|value| print_type_annotated_value(&value).ok())
+        .unwrap_or_else(|| format!("{:?}", value.value))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::oplog_diff::{align, OplogDiffEntryView, MAX_ALIGNED_CELLS};
+    use assert2::check;
+    use test_r::test;
+
+    fn entries(first_index: u64, kinds: &[&str]) -> Vec<OplogDiffEntryView> {
+        kinds
+            .iter()
+            .enumerate()
+            .map(|(idx, kind)| OplogDiffEntryView {
+                oplog_index: first_index + idx as u64,
+                kind: kind.to_string(),
+                function_name: None,
+                details: vec![],
+            })
+            .collect()
+    }
+
+    fn aligned_indexes(left: &[&str], right: &[&str]) -> Vec<(Option<u64>, Option<u64>)> {
+        align(entries(1, left), entries(1, right))
+            .into_iter()
+            .map(|(left, right)| {
+                (
+                    left.map(|entry| entry.oplog_index),
+                    right.map(|entry| entry.oplog_index),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn align_equal_oplogs() {
+        check!(
+            aligned_indexes(&["A", "B", "C"], &["A", "B", "C"])
+                == vec![(Some(1), Some(1)), (Some(2), Some(2)), (Some(3), Some(3))]
+        );
+        check!(aligned_indexes(&[], &[]).is_empty());
+    }
+
+    #[test]
+    fn align_entries_with_same_kind_and_function_only() {
+        let mut right = entries(1, &["A", "A"]);
+        right[1].function_name = Some("api.{run}".to_string());
+        right[1].details = vec!["changed".to_string()];
+
+        let aligned = align(entries(1, &["A", "A"]), right)
+            .into_iter()
+            .map(|(left, right)| (left.is_some(), right.is_some()))
+            .collect::<Vec<_>>();
+
+        check!(aligned == vec![(true, true), (true, false), (false, true)]);
+    }
+
+    #[test]
+    fn align_divergent_oplogs() {
+        check!(
+            aligned_indexes(&["A", "B", "C"], &["A", "X", "C"])
+                == vec![
+                    (Some(1), Some(1)),
+                    (Some(2), None),
+                    (None, Some(2)),
+                    (Some(3), Some(3))
+                ]
+        );
+        check!(
+            aligned_indexes(&["A", "X", "B", "Y"], &["A", "B", "Z", "Y"])
+                == vec![
+                    (Some(1), Some(1)),
+                    (Some(2), None),
+                    (Some(3), Some(2)),
+                    (None, Some(3)),
+                    (Some(4), Some(4))
+                ]
+        );
+    }
+
+    #[test]
+    fn align_oplogs_of_different_length() {
+        check!(
+            aligned_indexes(&["A", "B", "C"], &["A", "C"])
+                == vec![(Some(1), Some(1)), (Some(2), None), (Some(3), Some(2))]
+        );
+        check!(
+            aligned_indexes(&["A"], &["A", "B", "C"])
+                == vec![(Some(1), Some(1)), (None, Some(2)), (None, Some(3))]
+        );
+        check!(aligned_indexes(&[], &["A", "B"]) == vec![(None, Some(1)), (None, Some(2))]);
+    }
+
+    #[test]
+    fn align_by_position_above_the_cell_limit() {
+        let size = MAX_ALIGNED_CELLS.isqrt() + 1;
+        let left = vec!["A"; size];
+        let mut right = vec!["B"; size + 2];
+        // The common prefix and suffix are still aligned
+        let mut left_with_ends = vec!["START"];
+        left_with_ends.extend(left);
+        left_with_ends.push("END");
+        right.insert(0, "START");
+        right.push("END");
+
+        let aligned = aligned_indexes(&left_with_ends, &right);
+
+        check!(aligned.len() == size + 4);
+        check!(aligned[0] == (Some(1), Some(1)));
+        // Without the limit all left entries would be removed and all right entries added
+        check!(aligned[1] == (Some(2), Some(2)));
+        check!(aligned[size] == (Some(size as u64 + 1), Some(size as u64 + 1)));
+        check!(aligned[size + 1] == (None, Some(size as u64 + 2)));
+        check!(aligned[size + 2] == (None, Some(size as u64 + 3)));
+        check!(aligned[size + 3] == (Some(size as u64 + 2), Some(size as u64 + 4)));
+    }
+}
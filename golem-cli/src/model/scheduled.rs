@@ -0,0 +1,279 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::cloud::AccountId;
+use crate::config::ProfileName;
+use crate::fs;
+use crate::model::{ComponentName, IdempotencyKey, ProjectName, WorkerName};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// An invocation scheduled with `worker invoke --at` or `--after`, which is enqueued by the
+/// waiting CLI process once the scheduled time is reached. The scheduling is bound to that
+/// process, if it gets stopped, the due invocations can be enqueued by `worker scheduled run`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduledInvocation {
+    pub idempotency_key: IdempotencyKey,
+    pub profile_name: ProfileName,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<AccountId>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_name: Option<ProjectName>,
+    pub component_name: ComponentName,
+    pub worker_name: Option<WorkerName>,
+    pub function_name: String,
+    pub arguments: Vec<String>,
+    pub scheduled_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ScheduledInvocation {
+    /// The scheduled time already passed, but the invocation was not enqueued, most likely
+    /// because the waiting process was stopped
+    pub fn is_overdue(&self) -> bool {
+        self.scheduled_at < Utc::now()
+    }
+
+    /// The worker name in the `[ACCOUNT]/[PROJECT]/<COMPONENT>/<WORKER>` form used by the
+    /// worker commands, "-" standing for ephemeral workers
+    pub fn qualified_worker_name(&self) -> WorkerName {
+        let mut segments = Vec::with_capacity(4);
+        if let Some(project_name) = &self.project_name {
+            if let Some(account_id) = &self.account_id {
+                segments.push(account_id.0.as_str());
+            }
+            segments.push(project_name.0.as_str());
+        }
+        segments.push(self.component_name.0.as_str());
+        segments.push(
+            self.worker_name
+                .as_ref()
+                .map(|worker_name| worker_name.0.as_str())
+                .unwrap_or("-"),
+        );
+        segments.join("/").into()
+    }
+}
+
+/// Pending scheduled invocations of all profiles, stored in the config directory.
+/// Every access holds the lock of the file, as multiple waiting CLI processes use it.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduledInvocations {
+    pub invocations: Vec<ScheduledInvocation>,
+}
+
+impl ScheduledInvocations {
+    fn path(config_dir: &Path) -> PathBuf {
+        config_dir.join("scheduled-invocations.json")
+    }
+
+    fn read<R>(config_dir: &Path, read: impl FnOnce(Self) -> R) -> anyhow::Result<R> {
+        fs::read_json_with_lock(Self::path(config_dir), read)
+    }
+
+    fn update<R>(config_dir: &Path, update: impl FnOnce(&mut Self) -> R) -> anyhow::Result<R> {
+        fs::update_json_with_lock(Self::path(config_dir), update)
+    }
+
+    pub fn for_profile(
+        config_dir: &Path,
+        profile_name: &ProfileName,
+    ) -> anyhow::Result<Vec<ScheduledInvocation>> {
+        Self::read(config_dir, |scheduled| {
+            scheduled
+                .invocations
+                .into_iter()
+                .filter(|invocation| &invocation.profile_name == profile_name)
+                .collect()
+        })
+    }
+
+    pub fn add(config_dir: &Path, invocation: ScheduledInvocation) -> anyhow::Result<()> {
+        Self::update(config_dir, |scheduled| {
+            scheduled.invocations.retain(|existing| {
+                existing.profile_name != invocation.profile_name
+                    || existing.idempotency_key != invocation.idempotency_key
+            });
+            scheduled.invocations.push(invocation);
+            scheduled
+                .invocations
+                .sort_by_key(|invocation| invocation.scheduled_at);
+        })
+    }
+
+    pub fn contains(
+        config_dir: &Path,
+        profile_name: &ProfileName,
+        idempotency_key: &IdempotencyKey,
+    ) -> anyhow::Result<bool> {
+        Self::read(config_dir, |scheduled| {
+            scheduled.invocations.iter().any(|invocation| {
+                &invocation.profile_name == profile_name
+                    && &invocation.idempotency_key == idempotency_key
+            })
+        })
+    }
+
+    /// Removes the invocation, returning it if it was still pending
+    pub fn remove(
+        config_dir: &Path,
+        profile_name: &ProfileName,
+        idempotency_key: &IdempotencyKey,
+    ) -> anyhow::Result<Option<ScheduledInvocation>> {
+        Self::update(config_dir, |scheduled| {
+            let position = scheduled.invocations.iter().position(|invocation| {
+                &invocation.profile_name == profile_name
+                    && &invocation.idempotency_key == idempotency_key
+            })?;
+            Some(scheduled.invocations.remove(position))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::cloud::AccountId;
+    use crate::config::ProfileName;
+    use crate::model::scheduled::{ScheduledInvocation, ScheduledInvocations};
+    use crate::model::IdempotencyKey;
+    use assert2::{check, let_assert};
+    use chrono::{DateTime, Duration, Utc};
+
+    fn invocation(
+        profile_name: &str,
+        idempotency_key: &str,
+        scheduled_at: DateTime<Utc>,
+    ) -> ScheduledInvocation {
+        ScheduledInvocation {
+            idempotency_key: IdempotencyKey(idempotency_key.to_string()),
+            profile_name: ProfileName::from(profile_name),
+            account_id: None,
+            project_name: None,
+            component_name: "app:component".into(),
+            worker_name: Some("worker-1".into()),
+            function_name: "app:component/api.{run}".to_string(),
+            arguments: vec!["1".to_string()],
+            scheduled_at,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn store_and_list_sorted_by_scheduled_time() {
+        let config_dir = tempfile::tempdir().unwrap();
+        let now = Utc::now();
+
+        check!(
+            ScheduledInvocations::for_profile(config_dir.path(), &"local".into())
+                .unwrap()
+                .is_empty()
+        );
+
+        let later = invocation("local", "key-1", now + Duration::hours(2));
+        let sooner = invocation("local", "key-2", now + Duration::hours(1));
+        ScheduledInvocations::add(config_dir.path(), later.clone()).unwrap();
+        ScheduledInvocations::add(config_dir.path(), sooner.clone()).unwrap();
+
+        let invocations =
+            ScheduledInvocations::for_profile(config_dir.path(), &"local".into()).unwrap();
+        check!(invocations == vec![sooner, later]);
+    }
+
+    #[test]
+    fn add_replaces_invocation_with_same_key() {
+        let config_dir = tempfile::tempdir().unwrap();
+        let now = Utc::now();
+
+        ScheduledInvocations::add(
+            config_dir.path(),
+            invocation("local", "key-1", now + Duration::hours(1)),
+        )
+        .unwrap();
+        let updated = invocation("local", "key-1", now + Duration::hours(3));
+        ScheduledInvocations::add(config_dir.path(), updated.clone()).unwrap();
+
+        let invocations =
+            ScheduledInvocations::for_profile(config_dir.path(), &"local".into()).unwrap();
+        check!(invocations == vec![updated]);
+    }
+
+    #[test]
+    fn invocations_are_separated_by_profile() {
+        let config_dir = tempfile::tempdir().unwrap();
+        let now = Utc::now();
+        let key = IdempotencyKey("key-1".to_string());
+
+        let local = invocation("local", "key-1", now + Duration::hours(1));
+        ScheduledInvocations::add(config_dir.path(), local.clone()).unwrap();
+        ScheduledInvocations::add(
+            config_dir.path(),
+            invocation("cloud", "key-1", now + Duration::hours(2)),
+        )
+        .unwrap();
+
+        check!(
+            ScheduledInvocations::for_profile(config_dir.path(), &"local".into()).unwrap()
+                == vec![local]
+        );
+        check!(ScheduledInvocations::contains(config_dir.path(), &"cloud".into(), &key).unwrap());
+        check!(!ScheduledInvocations::contains(config_dir.path(), &"other".into(), &key).unwrap());
+    }
+
+    #[test]
+    fn cancel_removes_only_once() {
+        let config_dir = tempfile::tempdir().unwrap();
+        let key = IdempotencyKey("key-1".to_string());
+
+        let scheduled = invocation("local", "key-1", Utc::now() + Duration::hours(1));
+        ScheduledInvocations::add(config_dir.path(), scheduled.clone()).unwrap();
+
+        let removed =
+            ScheduledInvocations::remove(config_dir.path(), &"local".into(), &key).unwrap();
+        let_assert!(Some(removed) = removed);
+        check!(removed == scheduled);
+        check!(!ScheduledInvocations::contains(config_dir.path(), &"local".into(), &key).unwrap());
+        check!(
+            ScheduledInvocations::remove(config_dir.path(), &"local".into(), &key)
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn overdue_invocations() {
+        let now = Utc::now();
+        check!(invocation("local", "key-1", now - Duration::minutes(1)).is_overdue());
+        check!(!invocation("local", "key-1", now + Duration::minutes(1)).is_overdue());
+    }
+
+    #[test]
+    fn qualified_worker_name() {
+        let mut scheduled = invocation("local", "key-1", Utc::now());
+        check!(scheduled.qualified_worker_name().0 == "app:component/worker-1");
+
+        scheduled.worker_name = None;
+        check!(scheduled.qualified_worker_name().0 == "app:component/-");
+
+        scheduled.project_name = Some("project".into());
+        check!(scheduled.qualified_worker_name().0 == "project/app:component/-");
+
+        scheduled.account_id = Some(AccountId("account".to_string()));
+        check!(scheduled.qualified_worker_name().0 == "account/project/app:component/-");
+    }
+}
@@ -153,6 +153,9 @@ impl<C: Serialize> Template<C> for app_raw::ComponentProperties {
             clean: self.clean.render(env, ctx)?,
             component_type: self.component_type,
             files: self.files.clone(),
+            variables: self.variables.render(env, ctx)?,
+            strip_sections: self.strip_sections.clone(),
+            max_size: self.max_size,
         })
     }
 }
@@ -12,12 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::model::component::ComponentView;
+use crate::log::logln;
+use crate::model::component::{ComponentFileChangeKind, ComponentFilesDiff, ComponentView};
+use crate::model::component_diff::{
+    ComponentChangeCompatibility, ComponentDiffView, ComponentFunctionChangeKind,
+};
+use crate::model::component_inspect::{ComponentImportKind, ComponentInspectView};
 use crate::model::text::fmt::*;
 use crate::model::ComponentName;
 use cli_table::{format::Justify, Table};
+use colored::Colorize;
+use itertools::Itertools;
 
 use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 
 // TODO: review columns and formats
 #[derive(Table)]
@@ -154,3 +162,249 @@ impl MessageWithFields for ComponentReplStartedView {
         true
     }
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentDownloadView {
+    pub component_name: ComponentName,
+    pub component_version: u64,
+    pub path: PathBuf,
+    pub size: u64,
+    /// BLAKE3 hash of the downloaded binary
+    pub hash: String,
+    /// What the download was verified against
+    pub verified: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub files_manifest: Option<PathBuf>,
+}
+
+impl MessageWithFields for ComponentDownloadView {
+    fn message(&self) -> String {
+        format!(
+            "Downloaded component {} version {}",
+            format_message_highlight(&self.component_name),
+            format_message_highlight(&self.component_version),
+        )
+    }
+
+    fn fields(&self) -> Vec<(String, String)> {
+        let mut fields = FieldsBuilder::new();
+
+        fields
+            .fmt_field("Path", &self.path.display(), format_main_id)
+            .fmt_field("Size", &self.size, format_binary_size)
+            .fmt_field("BLAKE3 hash", &self.hash, format_id)
+            .fmt_field("Verified", &self.verified, format_id)
+            .fmt_field_option("Files manifest", &self.files_manifest, |path| {
+                format_id(&path.display())
+            });
+
+        fields.build()
+    }
+}
+
+impl TextView for ComponentFilesDiff {
+    fn log(&self) {
+        logln(format!(
+            "Component files changes for {}:",
+            format_main_id(&self.component_name)
+        ));
+        for change in &self.changes {
+            let marker = match change.kind {
+                ComponentFileChangeKind::Added => "+".green(),
+                ComponentFileChangeKind::Changed => "~".yellow(),
+                ComponentFileChangeKind::Removed => "-".red(),
+            };
+            logln(format!("  {} {}", marker, format_id(&change.path)));
+        }
+        logln(format!(
+            "{} added, {} changed, {} removed, {} unchanged",
+            format_id(&self.count(ComponentFileChangeKind::Added)),
+            format_id(&self.count(ComponentFileChangeKind::Changed)),
+            format_id(&self.count(ComponentFileChangeKind::Removed)),
+            format_id(&self.unchanged),
+        ));
+    }
+}
+
+impl MessageWithFields for ComponentInspectView {
+    fn message(&self) -> String {
+        format!(
+            "Inspected component {}",
+            format_message_highlight(&self.source)
+        )
+    }
+
+    fn fields(&self) -> Vec<(String, String)> {
+        let host_imports = self.imports_of_kind(ComponentImportKind::Host);
+        let dynamic_linking_imports = self.imports_of_kind(ComponentImportKind::DynamicLinking);
+        let unsatisfied_imports = self.imports_of_kind(ComponentImportKind::Unsatisfied);
+
+        let mut fields = FieldsBuilder::new();
+
+        fields
+            .fmt_field_option("Name", &self.name, format_main_id)
+            .fmt_field_option("Version", &self.version, format_id)
+            .fmt_field_optional(
+                "Producers",
+                &self.producers,
+                !self.producers.is_empty(),
+                |producers| producers.join("\n"),
+            )
+            .fmt_field("Size", &self.size, format_binary_size)
+            .fmt_field("Exports", &self.exports, |e| format_exports(e.as_slice()))
+            .fmt_field_optional(
+                "Host imports",
+                &host_imports,
+                !host_imports.is_empty(),
+                |imports| {
+                    imports
+                        .iter()
+                        .map(|import| format_export(import))
+                        .join("\n")
+                },
+            )
+            .fmt_field_optional(
+                "Dynamic linking candidates",
+                &dynamic_linking_imports,
+                !dynamic_linking_imports.is_empty(),
+                |imports| {
+                    imports
+                        .iter()
+                        .map(|import| format_export(import))
+                        .join("\n")
+                },
+            )
+            .fmt_field_optional(
+                "Unsatisfied imports",
+                &unsatisfied_imports,
+                !unsatisfied_imports.is_empty(),
+                |imports| imports.iter().map(format_warn).join("\n"),
+            )
+            .fmt_field("Host compatibility", &unsatisfied_imports, |imports| {
+                if imports.is_empty() {
+                    "all imports are provided by the Golem host or dynamic linking".to_string()
+                } else {
+                    format_warn(&format!(
+                        "{} import(s) are not provided by the Golem host",
+                        imports.len()
+                    ))
+                }
+            })
+            .fmt_field("Sections", &self.sections, |sections| {
+                let padding = sections
+                    .iter()
+                    .map(|section| section.name.len())
+                    .max()
+                    .unwrap_or_default()
+                    + 1;
+                sections
+                    .iter()
+                    .map(|section| {
+                        format!(
+                            "{:<padding$} {:>10} {}",
+                            format!("{}:", section.name),
+                            format_binary_size(&section.size),
+                            format!("({})", section.count).black()
+                        )
+                    })
+                    .join("\n")
+            });
+
+        fields.build()
+    }
+
+    fn nest_ident_fields() -> bool {
+        true
+    }
+}
+
+impl TextView for ComponentDiffView {
+    fn log(&self) {
+        logln(format!(
+            "{} {} {}",
+            "---".red(),
+            format_main_id(&self.component_name),
+            self.old_source
+        ));
+        logln(format!(
+            "{} {} {}",
+            "+++".green(),
+            format_main_id(&self.component_name),
+            self.new_source
+        ));
+        logln("");
+
+        if self.changes.is_empty() {
+            logln("No changes in the exported functions.");
+            return;
+        }
+
+        for change in &self.changes {
+            let compatibility = match change.compatibility {
+                ComponentChangeCompatibility::Compatible => "compatible".green(),
+                ComponentChangeCompatibility::Breaking => "breaking".red().bold(),
+            };
+            match change.kind {
+                ComponentFunctionChangeKind::Added => logln(format!(
+                    "{} {} ({})",
+                    "+".green(),
+                    format_export(change.new_signature.as_deref().unwrap_or_default()),
+                    compatibility
+                )),
+                ComponentFunctionChangeKind::Removed => logln(format!(
+                    "{} {} ({})",
+                    "-".red(),
+                    format_export(change.old_signature.as_deref().unwrap_or_default()),
+                    compatibility
+                )),
+                ComponentFunctionChangeKind::Changed => {
+                    logln(format!(
+                        "{} {} ({})",
+                        "~".yellow(),
+                        format_export(&change.function_name),
+                        compatibility
+                    ));
+                    if let Some(old_signature) = &change.old_signature {
+                        logln(format!(
+                            "    {} {}",
+                            "-".red(),
+                            format_export(old_signature)
+                        ));
+                    }
+                    if let Some(new_signature) = &change.new_signature {
+                        logln(format!(
+                            "    {} {}",
+                            "+".green(),
+                            format_export(new_signature)
+                        ));
+                    }
+                    for type_change in &change.type_changes {
+                        let description = match type_change.compatibility {
+                            ComponentChangeCompatibility::Compatible => {
+                                type_change.description.clone()
+                            }
+                            ComponentChangeCompatibility::Breaking => {
+                                format_warn(&type_change.description)
+                            }
+                        };
+                        logln(format!(
+                            "      {}: {}",
+                            format_id(&type_change.path),
+                            description
+                        ));
+                    }
+                }
+            }
+        }
+
+        logln("");
+        logln(format!(
+            "{} added, {} removed, {} changed, {} breaking",
+            format_id(&self.count(ComponentFunctionChangeKind::Added)),
+            format_id(&self.count(ComponentFunctionChangeKind::Removed)),
+            format_id(&self.count(ComponentFunctionChangeKind::Changed)),
+            format_id(&self.breaking_count()),
+        ));
+    }
+}
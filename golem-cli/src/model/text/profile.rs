@@ -85,6 +85,51 @@ impl TextView for ProfileConfig {
         logln(format!(
             "Default output format: {}",
             format_message_highlight(&self.default_format),
-        ))
+        ));
+        logln(format!(
+            "Stream ping interval: {}",
+            format_message_highlight(&format!("{}ms", self.stream.ping_interval_ms)),
+        ));
+        logln(format!(
+            "Stream reconnect delay: {}",
+            format_message_highlight(&format!(
+                "{}ms - {}ms{}",
+                self.stream.reconnect_initial_delay_ms,
+                self.stream.reconnect_max_delay_ms,
+                if self.stream.reconnect_jitter {
+                    ", with jitter"
+                } else {
+                    ""
+                }
+            )),
+        ));
+        logln(format!(
+            "Stream reconnect max attempts: {}",
+            format_message_highlight(
+                &self
+                    .stream
+                    .reconnect_max_attempts
+                    .map(|attempts| attempts.to_string())
+                    .unwrap_or_else(|| "unlimited".to_string())
+            ),
+        ));
+        logln(format!(
+            "Stream idle timeout: {}",
+            format_message_highlight(
+                &self
+                    .stream
+                    .idle_timeout_ms
+                    .map(|timeout| format!("{timeout}ms"))
+                    .unwrap_or_else(|| "disabled".to_string())
+            ),
+        ));
+        logln(format!(
+            "Invocation journal: {}",
+            format_message_highlight(if self.invocation_journal {
+                "enabled"
+            } else {
+                "disabled"
+            }),
+        ));
     }
 }
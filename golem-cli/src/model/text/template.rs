@@ -27,6 +27,8 @@ pub struct TemplateDescriptionTableView {
     pub tier: GuestLanguageTier,
     #[table(title = "Description")]
     pub description: String,
+    #[table(title = "Source")]
+    pub source: String,
 }
 
 impl From<&TemplateDescription> for TemplateDescriptionTableView {
@@ -36,6 +38,7 @@ impl From<&TemplateDescription> for TemplateDescriptionTableView {
             language: value.language,
             tier: value.tier.clone(),
             description: textwrap::wrap(&value.description, 30).join("\n"),
+            source: value.source.clone(),
         }
     }
 }
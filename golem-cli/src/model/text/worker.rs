@@ -13,12 +13,19 @@
 // limitations under the License.
 
 use crate::log::{logln, LogColorize};
-use crate::model::deploy::TryUpdateAllWorkersResult;
-use crate::model::invoke_result_view::InvokeResultView;
+use crate::model::deploy::{TryUpdateAllWorkersResult, WorkerUpdatePlan, WorkerUpdatePlanAction};
+use crate::model::invocation_journal::{InvocationJournalEntry, InvocationJournalOutcome};
+use crate::model::invoke_result_view::{InvocationMetricsView, InvokeResultView};
+use crate::model::oplog_diff::{
+    OplogDiffEntryView, OplogDiffLineKind, OplogDiffLineView, WorkerOplogDiffView,
+};
+use crate::model::scheduled::ScheduledInvocation;
 use crate::model::text::fmt::*;
 use crate::model::{
-    ComponentName, IdempotencyKey, WorkerMetadata, WorkerMetadataView, WorkerName,
-    WorkersMetadataResponseView,
+    ComponentName, IdempotencyKey, RevertedInvocationView, WorkerBenchView, WorkerDescribeView,
+    WorkerFileView, WorkerInvocationOutcome, WorkerInvocationView, WorkerMetadata,
+    WorkerMetadataView, WorkerName, WorkerRevertPreviewView, WorkerWatchRowView,
+    WorkersMetadataResponseView, WorkersWatchView, WORKER_BENCH_HISTOGRAM_BOUNDS_MS,
 };
 use base64::prelude::BASE64_STANDARD;
 use base64::Engine;
@@ -30,10 +37,11 @@ use golem_common::model::public_oplog::{
     PluginInstallationDescription, PublicAttributeValue, PublicUpdateDescription,
     PublicWorkerInvocation, StringAttributeValue,
 };
+use golem_common::model::ComponentFilePermissions;
 use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
 use golem_wasm_rpc::{print_type_annotated_value, ValueAndType};
 use indoc::{formatdoc, indoc};
-use itertools::Itertools;
+use itertools::{EitherOrBoth, Itertools};
 use serde::{Deserialize, Serialize};
 use std::fmt::Write;
 
@@ -75,6 +83,66 @@ impl MessageWithFields for WorkerCreateView {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkerCreateFailureView {
+    pub worker_name: WorkerName,
+    pub error: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkerCreateMultipleView {
+    pub component_name: ComponentName,
+    pub created: Vec<WorkerName>,
+    pub skipped: Vec<WorkerName>,
+    pub failed: Vec<WorkerCreateFailureView>,
+}
+
+impl MessageWithFields for WorkerCreateMultipleView {
+    fn message(&self) -> String {
+        format!(
+            "Created {} new worker(s) for component {}",
+            format_message_highlight(&self.created.len()),
+            format_message_highlight(&self.component_name)
+        )
+    }
+
+    fn fields(&self) -> Vec<(String, String)> {
+        let mut fields = FieldsBuilder::new();
+
+        fields
+            .fmt_field("Created", &self.created.len(), format_id)
+            .fmt_field("Skipped (already existing)", &self.skipped.len(), format_id)
+            .fmt_field("Failed", &self.failed.len(), |failed| {
+                if *failed > 0 {
+                    format_warn(failed)
+                } else {
+                    failed.to_string()
+                }
+            })
+            .fmt_field_optional(
+                "Failures",
+                &self.failed,
+                !self.failed.is_empty(),
+                |failed| {
+                    failed
+                        .iter()
+                        .map(|failure| {
+                            format!("{}: {}", format_warn(&failure.worker_name), failure.error)
+                        })
+                        .join("\n")
+                },
+            );
+
+        fields.build()
+    }
+
+    fn nest_ident_fields() -> bool {
+        true
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WorkerGetView(pub WorkerMetadataView);
 
@@ -232,6 +300,551 @@ impl TextView for WorkersMetadataResponseView {
     }
 }
 
+#[derive(Table)]
+struct WorkerWatchTableView {
+    #[table(title = "Component name")]
+    pub component_name: ComponentName,
+    #[table(title = "Worker name")]
+    pub worker_name: WorkerName,
+    #[table(title = "Component\nversion", justify = "Justify::Right")]
+    pub component_version: String,
+    #[table(title = "Status", justify = "Justify::Right")]
+    pub status: String,
+    #[table(title = "Pending\ninvocations", justify = "Justify::Right")]
+    pub pending_invocation_count: u64,
+    #[table(title = "Retry\ncount", justify = "Justify::Right")]
+    pub retry_count: String,
+    #[table(title = "Memory", justify = "Justify::Right")]
+    pub total_linear_memory_size: String,
+}
+
+impl From<&WorkerWatchRowView> for WorkerWatchTableView {
+    fn from(value: &WorkerWatchRowView) -> Self {
+        Self {
+            component_name: value.component_name.clone(),
+            worker_name: value.worker_name.clone(),
+            component_version: if value.version_changed {
+                value
+                    .component_version
+                    .to_string()
+                    .yellow()
+                    .bold()
+                    .to_string()
+            } else {
+                value.component_version.to_string()
+            },
+            status: if value.status_changed {
+                value.status.to_string().bold().reversed().to_string()
+            } else {
+                format_status(&value.status)
+            },
+            pending_invocation_count: value.pending_invocation_count,
+            retry_count: format_retry_count(&value.retry_count),
+            total_linear_memory_size: format_binary_size(&value.total_linear_memory_size),
+        }
+    }
+}
+
+impl TextView for WorkersWatchView {
+    fn log(&self) {
+        logln(format!(
+            "Refreshed at {}, every {}s, press {} to stop",
+            self.refreshed_at
+                .format("%Y-%m-%d %H:%M:%S")
+                .to_string()
+                .log_color_highlight(),
+            self.interval_seconds,
+            "Ctrl+C".log_color_highlight(),
+        ));
+        logln("");
+
+        if self.workers.is_empty() {
+            logln("No workers found");
+        } else {
+            log_table::<_, WorkerWatchTableView>(&self.workers);
+        }
+    }
+}
+
+#[derive(Table)]
+struct WorkerFileTableView {
+    #[table(title = "Name")]
+    pub name: String,
+    #[table(title = "Permissions")]
+    pub permissions: String,
+    #[table(title = "Size", justify = "Justify::Right")]
+    pub size: String,
+    #[table(title = "Last modified")]
+    pub last_modified: DateTime<Utc>,
+}
+
+impl From<&WorkerFileView> for WorkerFileTableView {
+    fn from(value: &WorkerFileView) -> Self {
+        Self {
+            name: if value.is_directory {
+                format!("{}/", value.name).blue().bold().to_string()
+            } else {
+                value.name.clone()
+            },
+            permissions: match value.permissions {
+                Some(ComponentFilePermissions::ReadOnly) => "read-only".to_string(),
+                Some(ComponentFilePermissions::ReadWrite) => "read-write".to_string(),
+                None => "".to_string(),
+            },
+            size: value
+                .size
+                .as_ref()
+                .map(format_binary_size)
+                .unwrap_or_default(),
+            last_modified: value.last_modified,
+        }
+    }
+}
+
+impl TextView for Vec<WorkerFileView> {
+    fn log(&self) {
+        if self.is_empty() {
+            logln("No files found");
+        } else {
+            log_table::<_, WorkerFileTableView>(self);
+        }
+    }
+}
+
+#[derive(Table)]
+struct ScheduledInvocationTableView {
+    #[table(title = "Idempotency key")]
+    pub idempotency_key: String,
+    #[table(title = "Component name")]
+    pub component_name: ComponentName,
+    #[table(title = "Worker name")]
+    pub worker_name: String,
+    #[table(title = "Function")]
+    pub function_name: String,
+    #[table(title = "Scheduled at")]
+    pub scheduled_at: String,
+}
+
+impl From<&ScheduledInvocation> for ScheduledInvocationTableView {
+    fn from(value: &ScheduledInvocation) -> Self {
+        Self {
+            idempotency_key: value.idempotency_key.0.clone(),
+            component_name: value.component_name.clone(),
+            worker_name: value
+                .worker_name
+                .as_ref()
+                .map(|worker_name| worker_name.0.clone())
+                .unwrap_or_else(|| "-".to_string()),
+            function_name: value.function_name.clone(),
+            scheduled_at: if value.is_overdue() {
+                format!("{} (overdue)", value.scheduled_at)
+                    .yellow()
+                    .to_string()
+            } else {
+                value.scheduled_at.to_string()
+            },
+        }
+    }
+}
+
+impl TextView for Vec<ScheduledInvocation> {
+    fn log(&self) {
+        if self.is_empty() {
+            logln("No scheduled invocations found");
+        } else {
+            log_table::<_, ScheduledInvocationTableView>(self);
+        }
+    }
+}
+
+#[derive(Table)]
+struct InvocationJournalEntryTableView {
+    #[table(title = "Entry", justify = "Justify::Right")]
+    pub id: u64,
+    #[table(title = "Started at")]
+    pub started_at: String,
+    #[table(title = "Worker name")]
+    pub worker_name: WorkerName,
+    #[table(title = "Function")]
+    pub function_name: String,
+    #[table(title = "Idempotency key")]
+    pub idempotency_key: String,
+    #[table(title = "Outcome")]
+    pub outcome: String,
+}
+
+impl From<&InvocationJournalEntry> for InvocationJournalEntryTableView {
+    fn from(value: &InvocationJournalEntry) -> Self {
+        Self {
+            id: value.id,
+            started_at: value.started_at.to_string(),
+            worker_name: value.worker_name.clone(),
+            function_name: value.function_name.clone(),
+            idempotency_key: value.idempotency_key.0.clone(),
+            outcome: match &value.outcome {
+                InvocationJournalOutcome::Failed { .. } => value.outcome.to_string().red(),
+                InvocationJournalOutcome::Pending => value.outcome.to_string().yellow(),
+                _ => value.outcome.to_string().normal(),
+            }
+            .to_string(),
+        }
+    }
+}
+
+impl TextView for Vec<InvocationJournalEntry> {
+    fn log(&self) {
+        if self.is_empty() {
+            logln("No invocations found in the journal");
+        } else {
+            log_table::<_, InvocationJournalEntryTableView>(self);
+        }
+    }
+}
+
+impl MessageWithFields for InvocationJournalEntry {
+    fn message(&self) -> String {
+        format!(
+            "Invocation journal entry {}",
+            format_message_highlight(&self.id)
+        )
+    }
+
+    fn fields(&self) -> Vec<(String, String)> {
+        let mut fields = FieldsBuilder::new();
+
+        fields
+            .fmt_field("Worker name", &self.worker_name, format_main_id)
+            .fmt_field("Function", self.function_name.as_str(), format_export)
+            .fmt_field_optional(
+                "Arguments",
+                &self.arguments,
+                !self.arguments.is_empty(),
+                |arguments| arguments.join("\n"),
+            )
+            .fmt_field("Idempotency key", &self.idempotency_key.0, format_id)
+            .fmt_field("Enqueued", &self.enqueue, |enqueue| enqueue.to_string())
+            .fmt_field("Started at", &self.started_at, |started_at| {
+                started_at.to_string()
+            })
+            .fmt_field_option("Finished at", &self.finished_at, |finished_at| {
+                finished_at.to_string()
+            })
+            .fmt_field("Outcome", &self.outcome, |outcome| match outcome {
+                InvocationJournalOutcome::Failed { .. } => format_error(&outcome.to_string()),
+                InvocationJournalOutcome::Pending => format_warn(outcome),
+                _ => outcome.to_string(),
+            });
+
+        match &self.outcome {
+            InvocationJournalOutcome::Succeeded {
+                result: Some(result),
+            } => {
+                fields.fmt_field_optional("Result", result, !result.is_empty(), |result| {
+                    result.join("\n")
+                });
+            }
+            InvocationJournalOutcome::Failed { error } => {
+                fields.fmt_field("Error", error.as_str(), format_stack);
+            }
+            _ => {}
+        }
+
+        fields.build()
+    }
+
+    fn nest_ident_fields() -> bool {
+        true
+    }
+}
+
+#[derive(Table)]
+struct RevertedInvocationTableView {
+    #[table(title = "Oplog index", justify = "Justify::Right")]
+    pub oplog_index: u64,
+    #[table(title = "Timestamp")]
+    pub timestamp: String,
+    #[table(title = "Function")]
+    pub function_name: String,
+    #[table(title = "Idempotency key")]
+    pub idempotency_key: String,
+}
+
+impl From<&RevertedInvocationView> for RevertedInvocationTableView {
+    fn from(value: &RevertedInvocationView) -> Self {
+        Self {
+            oplog_index: value.oplog_index,
+            timestamp: value.timestamp.to_string(),
+            function_name: value.function_name.clone(),
+            idempotency_key: value.idempotency_key.clone(),
+        }
+    }
+}
+
+impl TextView for WorkerRevertPreviewView {
+    fn log(&self) {
+        if self.entries.is_empty() {
+            logln(format!(
+                "Reverting worker {}/{} to oplog index {} would not undo any oplog entries",
+                format_id(&self.component_name),
+                format_main_id(&self.worker_name),
+                format_id(&self.revert_to_oplog_index),
+            ));
+            return;
+        }
+
+        logln(format!(
+            "Reverting worker {}/{} to oplog index {} would undo {} oplog entries and {} invocation(s)",
+            format_id(&self.component_name),
+            format_main_id(&self.worker_name),
+            format_id(&self.revert_to_oplog_index),
+            format_warn(&self.entries.len()),
+            format_warn(&self.invocations.len()),
+        ));
+
+        if !self.invocations.is_empty() {
+            logln("");
+            logln(format_message_highlight("Invocations to be undone:"));
+            log_table::<_, RevertedInvocationTableView>(&self.invocations);
+        }
+
+        logln("");
+        logln(format_message_highlight("Oplog entries to be undone:"));
+        self.entries.log();
+    }
+}
+
+#[derive(Table)]
+struct WorkerInvocationTableView {
+    #[table(title = "Oplog index", justify = "Justify::Right")]
+    pub oplog_index: u64,
+    #[table(title = "Started at")]
+    pub timestamp: String,
+    #[table(title = "Function")]
+    pub function_name: String,
+    #[table(title = "Idempotency key")]
+    pub idempotency_key: String,
+    #[table(title = "Outcome")]
+    pub outcome: String,
+}
+
+impl From<&WorkerInvocationView> for WorkerInvocationTableView {
+    fn from(value: &WorkerInvocationView) -> Self {
+        Self {
+            oplog_index: value.oplog_index,
+            timestamp: value.timestamp.to_string(),
+            function_name: value.function_name.clone(),
+            idempotency_key: value.idempotency_key.clone(),
+            outcome: match value.outcome {
+                WorkerInvocationOutcome::Running => value.outcome.to_string().cyan().to_string(),
+                WorkerInvocationOutcome::Completed => value.outcome.to_string().green().to_string(),
+                WorkerInvocationOutcome::Failed => value.outcome.to_string().red().to_string(),
+                WorkerInvocationOutcome::Interrupted => {
+                    value.outcome.to_string().yellow().to_string()
+                }
+            },
+        }
+    }
+}
+
+impl TextView for WorkerDescribeView {
+    fn log(&self) {
+        WorkerGetView::from(self.metadata.clone()).log();
+
+        logln("");
+        logln(format_message_highlight("Component version:"));
+        if self.metadata.component_version == self.latest_component_version {
+            logln(format!(
+                "  {} (latest)",
+                format_id(&self.metadata.component_version)
+            ));
+        } else {
+            logln(format!(
+                "  {}, latest is {}",
+                format_warn(&self.metadata.component_version),
+                format_id(&self.latest_component_version)
+            ));
+            for export in &self.exports_added_in_latest {
+                logln(format!("  {} {}", "+".green(), export));
+            }
+            for export in &self.exports_removed_in_latest {
+                logln(format!("  {} {}", "-".red(), export));
+            }
+        }
+
+        if !self.pending_updates.is_empty() {
+            logln("");
+            logln(format_message_highlight("Pending updates:"));
+            for update in &self.pending_updates {
+                if let UpdateRecord::PendingUpdate(update) = update {
+                    logln(format!(
+                        "  - to version {}, requested at {}",
+                        format_id(&update.target_version),
+                        update.timestamp
+                    ));
+                }
+            }
+        }
+
+        if !self.failed_updates.is_empty() {
+            logln("");
+            logln(format_message_highlight("Failed updates:"));
+            for update in &self.failed_updates {
+                if let UpdateRecord::FailedUpdate(update) = update {
+                    logln(format!(
+                        "  - to version {}, failed at {}{}",
+                        format_warn(&update.target_version),
+                        update.timestamp,
+                        update
+                            .details
+                            .as_ref()
+                            .map(|details| format!(": {}", format_error(details)))
+                            .unwrap_or_default()
+                    ));
+                }
+            }
+        }
+
+        logln("");
+        logln(format_message_highlight("Exports:"));
+        for export in &self.exports {
+            logln(format!("  - {}", export));
+        }
+
+        if !self.metadata.owned_resources.is_empty() {
+            logln("");
+            logln(format_message_highlight("Owned resources:"));
+            for (resource_id, resource) in self
+                .metadata
+                .owned_resources
+                .iter()
+                .sorted_by_key(|(resource_id, _)| *resource_id)
+            {
+                logln(format!(
+                    "  - {}, created at {}",
+                    format_id(resource_id),
+                    resource.created_at
+                ));
+            }
+        }
+
+        logln("");
+        logln(format_message_highlight("Recent invocations:"));
+        if self.invocations.is_empty() {
+            logln("  No invocations found");
+        } else {
+            log_table::<_, WorkerInvocationTableView>(&self.invocations);
+            for invocation in &self.invocations {
+                if let Some(error) = &invocation.error {
+                    logln("");
+                    logln(format!(
+                        "Error of invocation {}:",
+                        format_id(&invocation.idempotency_key)
+                    ));
+                    logln(format_error(error));
+                }
+            }
+        }
+    }
+}
+
+impl MessageWithFields for WorkerBenchView {
+    fn message(&self) -> String {
+        format!(
+            "Benchmark results for {}/{}",
+            format_message_highlight(&self.component_name),
+            format_message_highlight(&self.function_name)
+        )
+    }
+
+    fn fields(&self) -> Vec<(String, String)> {
+        let mut fields = FieldsBuilder::new();
+
+        fields
+            .fmt_field("Workers", &self.workers, format_id)
+            .fmt_field("Concurrency", &self.concurrency, format_id)
+            .fmt_field_option("Target rate", &self.target_rate, |rate| {
+                format!("{} invocation(s)/s", format_id(rate))
+            })
+            .fmt_field("Duration", &self.duration_ms, |ms| {
+                format!("{:.2} s", *ms as f64 / 1000.0)
+            })
+            .fmt_field("Invocations", &self.invocations, format_id)
+            .fmt_field("Successful", &self.successful, format_id)
+            .fmt_field("Failed", &self.failed, |failed| {
+                if *failed > 0 {
+                    format_warn(failed)
+                } else {
+                    failed.to_string()
+                }
+            })
+            .fmt_field("Throughput", &self.throughput, |throughput| {
+                format!(
+                    "{} invocation(s)/s",
+                    format_main_id(&format!("{throughput:.2}"))
+                )
+            })
+            .fmt_field_optional("Errors", &self.errors, !self.errors.is_empty(), |errors| {
+                errors
+                    .iter()
+                    .map(|error| {
+                        format!(
+                            "{}  {}",
+                            format_warn(&format!("{:>8}", error.count)),
+                            error.kind
+                        )
+                    })
+                    .join("\n")
+            })
+            .fmt_field_option("Latency", &self.latency, |latency| {
+                [
+                    ("min", latency.min_ms),
+                    ("mean", latency.mean_ms),
+                    ("p50", latency.p50_ms),
+                    ("p90", latency.p90_ms),
+                    ("p95", latency.p95_ms),
+                    ("p99", latency.p99_ms),
+                    ("max", latency.max_ms),
+                ]
+                .iter()
+                .map(|(name, ms)| format!("{:<5} {:>10.2} ms", name, ms))
+                .join("\n")
+            })
+            .fmt_field_optional(
+                "Latency histogram",
+                &self.histogram,
+                !self.histogram.is_empty(),
+                |histogram| {
+                    let max_count = histogram
+                        .iter()
+                        .map(|bucket| bucket.count)
+                        .max()
+                        .unwrap_or(0)
+                        .max(1);
+                    histogram
+                        .iter()
+                        .map(|bucket| {
+                            let bound = match bucket.upper_bound_ms {
+                                Some(bound) => format!("<= {bound} ms"),
+                                None => format!(
+                                    "> {} ms",
+                                    WORKER_BENCH_HISTOGRAM_BOUNDS_MS.last().unwrap_or(&0)
+                                ),
+                            };
+                            let bar = "█".repeat(bucket.count * 40 / max_count);
+                            format!("{:>12} {} {}", bound, bar.green(), bucket.count)
+                        })
+                        .join("\n")
+                },
+            );
+
+        fields.build()
+    }
+
+    fn nest_ident_fields() -> bool {
+        true
+    }
+}
+
 impl TextView for IdempotencyKey {
     fn log(&self) {
         logln(formatdoc!(
@@ -254,6 +867,38 @@ impl TextView for TryUpdateAllWorkersResult {
     }
 }
 
+impl TextView for WorkerUpdatePlan {
+    fn log(&self) {
+        logln(format!(
+            "Update plan for component {} to version {} using {} update mode:",
+            format_main_id(&self.component_name),
+            format_id(&self.target_version),
+            format_id(&self.update_mode),
+        ));
+        for entry in &self.entries {
+            let action = match &entry.action {
+                WorkerUpdatePlanAction::UpToDate => "up to date".to_string(),
+                WorkerUpdatePlanAction::Update { breaking_changes } if *breaking_changes > 0 => {
+                    format!(
+                        "update, with {} breaking export change(s)",
+                        format_warn(breaking_changes)
+                    )
+                }
+                WorkerUpdatePlanAction::Update { .. } => "update".to_string(),
+                WorkerUpdatePlanAction::Refuse { reason } => {
+                    format!("{}: {}", "refused".red().bold(), reason)
+                }
+            };
+            logln(format!(
+                "  - version {}: {} worker(s), {}",
+                format_id(&entry.source_version),
+                format_id(&entry.worker_count),
+                action
+            ));
+        }
+    }
+}
+
 impl TextView for InvokeResultView {
     fn log(&self) {
         fn log_results_format(format: &str) {
@@ -263,10 +908,6 @@ impl TextView for InvokeResultView {
             ))
         }
 
-        if self.result_wave.is_none() && self.result_json.is_none() {
-            return;
-        }
-
         if let Some(wave_values) = &self.result_wave {
             if wave_values.is_empty() {
                 logln("Empty result.")
@@ -287,6 +928,154 @@ impl TextView for InvokeResultView {
             log_results_format("JSON");
             logln(serde_json::to_string_pretty(json).unwrap());
         }
+
+        if let Some(metrics) = &self.metrics {
+            log_invocation_metrics(metrics);
+        }
+    }
+}
+
+fn log_invocation_metrics(metrics: &InvocationMetricsView) {
+    let format_ms = |ms: &u64| format!("{} ms", format_id(ms));
+
+    logln("");
+    logln("Invocation metrics:");
+    logln(format!(
+        "  Wall time:      {}",
+        format_ms(&metrics.wall_time_ms)
+    ));
+    if let Some(execution_time_ms) = &metrics.execution_time_ms {
+        logln(format!(
+            "  Execution time: {}",
+            format_ms(execution_time_ms)
+        ));
+    }
+    if let Some(consumed_fuel) = &metrics.consumed_fuel {
+        logln(format!("  Consumed fuel:  {}", format_id(consumed_fuel)));
+    }
+    if let Some(host_calls) = &metrics.host_calls {
+        logln(format!("  Host calls:     {}", format_id(host_calls)));
+    }
+    if let Some(recovered) = &metrics.recovered {
+        logln(format!(
+            "  Recovered:      {}",
+            if *recovered {
+                format_warn("yes")
+            } else {
+                format_id("no")
+            }
+        ));
+    }
+}
+
+impl TextView for WorkerOplogDiffView {
+    fn log(&self) {
+        // Number of equal entries shown around the differences
+        const CONTEXT: usize = 2;
+
+        logln(format!(
+            "{} {}",
+            "---".red(),
+            format_main_id(&self.left_name)
+        ));
+        logln(format!(
+            "{} {}",
+            "+++".green(),
+            format_main_id(&self.right_name)
+        ));
+        logln("");
+
+        let is_visible = |idx: usize| {
+            self.show_all_entries
+                || (idx.saturating_sub(CONTEXT)..=idx + CONTEXT)
+                    .filter_map(|idx| self.lines.get(idx))
+                    .any(|line| line.kind != OplogDiffLineKind::Equal)
+        };
+        let log_hidden = |hidden: usize| {
+            if hidden > 0 {
+                logln(
+                    format!("  ... {} equal entries", hidden)
+                        .dimmed()
+                        .to_string(),
+                );
+            }
+        };
+
+        let mut hidden = 0;
+        for (idx, line) in self.lines.iter().enumerate() {
+            if !is_visible(idx) {
+                hidden += 1;
+                continue;
+            }
+            log_hidden(hidden);
+            hidden = 0;
+            log_oplog_diff_line(line);
+        }
+        log_hidden(hidden);
+
+        logln("");
+        if !self.has_differences() {
+            logln(format_message_highlight("No differences found"));
+        }
+        logln(format!(
+            "Equal: {}, changed: {}, removed: {}, added: {}",
+            format_id(&self.equal),
+            format_warn(&self.changed),
+            self.removed.to_string().red(),
+            self.added.to_string().green(),
+        ));
+    }
+}
+
+fn log_oplog_diff_line(line: &OplogDiffLineView) {
+    let index = |entry: &Option<OplogDiffEntryView>| {
+        entry
+            .as_ref()
+            .map(|entry| format!("#{:0>5}", entry.oplog_index))
+            .unwrap_or_else(|| " ".repeat(6))
+    };
+    let Some(entry) = line.left.as_ref().or(line.right.as_ref()) else {
+        return;
+    };
+
+    let header = format!(
+        "{} {} {}",
+        index(&line.left),
+        index(&line.right),
+        match &entry.function_name {
+            Some(function_name) => format!("{} {}", entry.kind, function_name),
+            None => entry.kind.clone(),
+        }
+    );
+    let removed = |detail: &str| logln(format!("-     {}", detail).red().to_string());
+    let added = |detail: &str| logln(format!("+     {}", detail).green().to_string());
+
+    match line.kind {
+        OplogDiffLineKind::Equal => logln(format!("  {}", header)),
+        OplogDiffLineKind::Changed => {
+            logln(format!("~ {}", header).yellow().to_string());
+            let left = line.left.iter().flat_map(|entry| &entry.details);
+            let right = line.right.iter().flat_map(|entry| &entry.details);
+            for details in left.zip_longest(right) {
+                match details {
+                    EitherOrBoth::Both(left, right) if left == right => {}
+                    EitherOrBoth::Both(left, right) => {
+                        removed(left);
+                        added(right);
+                    }
+                    EitherOrBoth::Left(left) => removed(left),
+                    EitherOrBoth::Right(right) => added(right),
+                }
+            }
+        }
+        OplogDiffLineKind::Removed => {
+            logln(format!("- {}", header).red().to_string());
+            entry.details.iter().for_each(|detail| removed(detail));
+        }
+        OplogDiffLineKind::Added => {
+            logln(format!("+ {}", header).green().to_string());
+            entry.details.iter().for_each(|detail| added(detail));
+        }
     }
 }
 
@@ -14,15 +14,22 @@
 
 use crate::fuzzy::{Error, FuzzySearch, Match};
 use crate::model::component::show_exported_functions;
+use crate::model::WorkerName;
+use anyhow::{anyhow, bail};
 use golem_wasm_ast::analysis::AnalysedExport;
 use rib::{ParsedFunctionName, ParsedFunctionReference};
+use std::collections::HashSet;
+
+/// Function names which can be used for invocation, including the syntax sugar variants
+pub fn invocable_function_names(exports: &[AnalysedExport]) -> Vec<String> {
+    duplicate_names_with_syntax_sugar(show_exported_functions(exports, false))
+}
 
 pub fn fuzzy_match_function_name(
     provided_function_name: &str,
     exports: &[AnalysedExport],
 ) -> crate::fuzzy::Result {
-    let component_function_names =
-        duplicate_names_with_syntax_sugar(show_exported_functions(exports, false));
+    let component_function_names = invocable_function_names(exports);
 
     // First see if the function name is a valid function name
     let (normalized_function_name, parsed_function_name) =
@@ -295,9 +302,120 @@ fn duplicate_names_with_syntax_sugar(names: Vec<String>) -> Vec<String> {
     result
 }
 
+/// Worker to be created by `worker new --from-csv` or `worker new --name-pattern`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewWorkerSpec {
+    pub worker_name: WorkerName,
+    pub args: Vec<String>,
+    pub env: Vec<(String, String)>,
+}
+
+/// Parses the workers to be created from CSV content. The header row is required and must
+/// contain the "name" column, the "args" and "env" columns are optional. Args are separated by
+/// whitespace and support shell style quoting, env variables are separated by semicolons.
+pub fn parse_new_workers_csv(content: &str) -> anyhow::Result<Vec<NewWorkerSpec>> {
+    let mut lines = content
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| (idx + 1, line.trim_end_matches('\r')))
+        .filter(|(_, line)| !line.trim().is_empty());
+
+    let Some((header_line_no, header)) = lines.next() else {
+        bail!("The CSV file is empty, expected a header row with name, args and env columns");
+    };
+    let header = parse_csv_row(header)
+        .map_err(|err| anyhow!("Invalid CSV header at line {header_line_no}: {err}"))?;
+    let column = |name: &str| header.iter().position(|column| column.trim() == name);
+    let name_column =
+        column("name").ok_or_else(|| anyhow!("Missing \"name\" column in the CSV header"))?;
+    let args_column = column("args");
+    let env_column = column("env");
+
+    let mut worker_names = HashSet::new();
+    let mut specs = Vec::new();
+    for (line_no, line) in lines {
+        let row = parse_csv_row(line)
+            .map_err(|err| anyhow!("Invalid CSV row at line {line_no}: {err}"))?;
+        let cell = |column: Option<usize>| {
+            column
+                .and_then(|column| row.get(column))
+                .map(|cell| cell.trim())
+                .unwrap_or_default()
+        };
+
+        let worker_name = cell(Some(name_column));
+        if worker_name.is_empty() {
+            bail!("Missing worker name at line {line_no}");
+        }
+        if !worker_names.insert(worker_name.to_string()) {
+            bail!("Duplicated worker name at line {line_no}: {worker_name}");
+        }
+
+        let args = shlex::split(cell(args_column))
+            .ok_or_else(|| anyhow!("Invalid args at line {line_no}: {}", cell(args_column)))?;
+
+        let env = cell(env_column)
+            .split(';')
+            .map(|var| var.trim())
+            .filter(|var| !var.is_empty())
+            .map(|var| {
+                var.split_once('=')
+                    .map(|(key, value)| (key.trim().to_string(), value.to_string()))
+                    .filter(|(key, _)| !key.is_empty())
+                    .ok_or_else(|| {
+                        anyhow!("Invalid env variable at line {line_no}, expected KEY=VALUE: {var}")
+                    })
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        specs.push(NewWorkerSpec {
+            worker_name: worker_name.to_string().into(),
+            args,
+            env,
+        });
+    }
+
+    Ok(specs)
+}
+
+// Splits a single CSV row, supporting double-quoted cells with "" as escaped quote,
+// quoted cells cannot span multiple lines
+fn parse_csv_row(line: &str) -> Result<Vec<String>, String> {
+    let mut cells = Vec::new();
+    let mut cell = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(char) = chars.next() {
+        match char {
+            '"' if in_quotes => {
+                if chars.peek() == Some(&'"') {
+                    chars.next();
+                    cell.push('"');
+                } else {
+                    in_quotes = false;
+                }
+            }
+            '"' if cell.trim().is_empty() => {
+                cell.clear();
+                in_quotes = true;
+            }
+            ',' if !in_quotes => cells.push(std::mem::take(&mut cell)),
+            char => cell.push(char),
+        }
+    }
+
+    if in_quotes {
+        return Err("unterminated quoted cell".to_string());
+    }
+    cells.push(cell);
+
+    Ok(cells)
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::model::worker::fuzzy_match_function_name;
+    use crate::model::worker::{fuzzy_match_function_name, parse_new_workers_csv, NewWorkerSpec};
     use golem_wasm_ast::analysis::analysed_type::{
         case, f32, field, handle, list, record, str, u32, variant,
     };
@@ -305,6 +423,7 @@ mod tests {
         AnalysedExport, AnalysedFunction, AnalysedFunctionParameter, AnalysedFunctionResult,
         AnalysedInstance, AnalysedResourceId, AnalysedResourceMode,
     };
+    use indoc::indoc;
     use test_r::test;
 
     #[test]
@@ -600,6 +719,66 @@ mod tests {
         .is_err());
     }
 
+    #[test]
+    fn test_parse_new_workers_csv() {
+        let specs = parse_new_workers_csv(indoc! {r#"
+            name,args,env
+            tenant-1,,
+            tenant-2,"a 'b c'",A=1;B=x=y
+
+            "tenant-3","say ""hi""",C=3
+        "#})
+        .unwrap();
+
+        assert_eq!(
+            specs,
+            vec![
+                NewWorkerSpec {
+                    worker_name: "tenant-1".to_string().into(),
+                    args: vec![],
+                    env: vec![],
+                },
+                NewWorkerSpec {
+                    worker_name: "tenant-2".to_string().into(),
+                    args: vec!["a".to_string(), "b c".to_string()],
+                    env: vec![
+                        ("A".to_string(), "1".to_string()),
+                        ("B".to_string(), "x=y".to_string())
+                    ],
+                },
+                NewWorkerSpec {
+                    worker_name: "tenant-3".to_string().into(),
+                    args: vec!["say".to_string(), "hi".to_string()],
+                    env: vec![("C".to_string(), "3".to_string())],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_new_workers_csv_name_only_with_reordered_columns() {
+        let specs = parse_new_workers_csv("env,name\nX=1,w1\n").unwrap();
+
+        assert_eq!(
+            specs,
+            vec![NewWorkerSpec {
+                worker_name: "w1".to_string().into(),
+                args: vec![],
+                env: vec![("X".to_string(), "1".to_string())],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_new_workers_csv_errors() {
+        assert!(parse_new_workers_csv("").is_err());
+        assert!(parse_new_workers_csv("args,env\na,b\n").is_err());
+        assert!(parse_new_workers_csv("name\nw1\nw1\n").is_err());
+        assert!(parse_new_workers_csv("name,env\nw1,INVALID\n").is_err());
+        assert!(parse_new_workers_csv("name\n\"w1\n").is_err());
+        assert!(parse_new_workers_csv("name,args\n,a\n").is_err());
+    }
+
     fn example_exported_global() -> Vec<AnalysedExport> {
         vec![AnalysedExport::Instance(AnalysedInstance {
             name: "golem:it/api".to_string(),
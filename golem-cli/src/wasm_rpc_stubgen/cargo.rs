@@ -19,7 +19,8 @@ use crate::wasm_rpc_stubgen::naming;
 use crate::wasm_rpc_stubgen::stub::StubDefinition;
 use crate::wasm_rpc_stubgen::wit_resolve::ResolvedWitDir;
 use crate::wasm_rpc_stubgen::{
-    GOLEM_API_VERSION, GOLEM_RPC_WIT_VERSION, WASI_WIT_VERSION, WIT_BINDGEN_VERSION,
+    GOLEM_API_VERSION, GOLEM_HOST_WIT_PACKAGES, GOLEM_RPC_WIT_VERSION, WASI_WIT_VERSION,
+    WIT_BINDGEN_VERSION,
 };
 use anyhow::{anyhow, Context};
 use cargo_toml::{
@@ -406,22 +407,8 @@ pub fn regenerate_cargo_package_component(
 
     dependencies.clear();
 
-    let wit_packages_in_golem_rust: HashSet<&'static str> = HashSet::from_iter([
-        "golem:api",
-        "golem:durability",
-        "golem:rdbms",
-        "golem:rpc",
-        "wasi:clocks",
-        "wasi:io",
-        "wasi:http",
-        "wasi:random",
-        "wasi:cli",
-        "wasi:filesystem",
-        "wasi:sockets",
-        "wasi:blobstore",
-        "wasi:keyvalue",
-        "wasi:logging",
-    ]);
+    let wit_packages_in_golem_rust: HashSet<&'static str> =
+        HashSet::from_iter(GOLEM_HOST_WIT_PACKAGES.iter().copied());
     let mut bind_to_golem_rust = Vec::new();
 
     let wit_dir = ResolvedWitDir::new(wit_path)?;
@@ -26,3 +26,21 @@ pub const WIT_BINDGEN_VERSION: &str = "0.40.0";
 pub const WASI_WIT_VERSION: &str = "0.2.0";
 pub const GOLEM_RPC_WIT_VERSION: &str = "0.2.0";
 pub const GOLEM_API_VERSION: &str = "1.1.6";
+
+/// WIT packages implemented by the Golem worker executor, and also bound by golem-rust
+pub const GOLEM_HOST_WIT_PACKAGES: &[&str] = &[
+    "golem:api",
+    "golem:durability",
+    "golem:rdbms",
+    "golem:rpc",
+    "wasi:clocks",
+    "wasi:io",
+    "wasi:http",
+    "wasi:random",
+    "wasi:cli",
+    "wasi:filesystem",
+    "wasi:sockets",
+    "wasi:blobstore",
+    "wasi:keyvalue",
+    "wasi:logging",
+];
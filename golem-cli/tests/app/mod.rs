@@ -342,6 +342,61 @@ fn basic_ifs_deploy(_tracing: &Tracing) {
     check!(outputs.stdout_contains("ro /src/lib.rs"));
 }
 
+#[test]
+fn ifs_rules_validation(_tracing: &Tracing) {
+    let mut ctx = TestContext::new();
+    let app_name = "test-app-name";
+
+    let outputs = ctx.cli([cmd::APP, cmd::NEW, app_name, "rust"]);
+    assert!(outputs.success());
+
+    ctx.cd(app_name);
+
+    let outputs = ctx.cli([cmd::COMPONENT, cmd::NEW, "rust", "app:rust"]);
+    assert!(outputs.success());
+
+    fs::write_str(
+        ctx.cwd_path_join(
+            Path::new("components-rust")
+                .join("app-rust")
+                .join("golem.yaml"),
+        ),
+        indoc! {"
+            components:
+              app:rust:
+                template: rust
+                variables:
+                  greeting: hello
+                profiles:
+                  debug:
+                    files:
+                    - sourcePath: src/*.rs
+                      targetPath: /src
+                      exclude:
+                      - \"[invalid\"
+                      permissionRules:
+                      - pattern: \"**/lib.rs\"
+                        permissions: read-write
+                    - sourcePath: https://example.com/data.txt
+                      targetPath: /data.txt
+                      template: true
+                      exclude:
+                      - \"*.tmp\"
+                    - sourcePath: Cargo.toml
+                      targetPath: /Cargo.toml
+                      sizeLimit: 0
+
+        "},
+    )
+    .unwrap();
+
+    let outputs = ctx.cli([cmd::APP]);
+    assert!(!outputs.success());
+    check!(outputs.stderr_contains("Invalid glob pattern"));
+    check!(outputs.stderr_contains("are only supported for local sources"));
+    check!(outputs.stderr_contains("must be greater than zero"));
+}
+
 #[test]
 fn custom_app_subcommand_with_builtin_name() {
     let mut ctx = TestContext::new();
@@ -15,9 +15,9 @@
 use crate::model::{
     ComposableAppGroupName, GuestLanguage, PackageName, TargetExistsResolveDecision,
     TargetExistsResolveMode, Template, TemplateKind, TemplateMetadata, TemplateName,
-    TemplateParameters,
+    TemplateParameters, TemplateSource,
 };
-use anyhow::Context;
+use anyhow::{bail, Context};
 use include_dir::{include_dir, Dir, DirEntry};
 use itertools::Itertools;
 use std::borrow::Cow;
@@ -34,40 +34,178 @@ static TEMPLATES: Dir<'_> = include_dir!("$CARGO_MANIFEST_DIR/templates");
 static ADAPTERS: Dir<'_> = include_dir!("$OUT_DIR/golem-wit/adapters");
 static WIT: Dir<'_> = include_dir!("$OUT_DIR/golem-wit/wit/deps");
 
-fn all_templates() -> Vec<Template> {
-    let mut result: Vec<Template> = vec![];
-    for entry in TEMPLATES.entries() {
-        if let Some(lang_dir) = entry.as_dir() {
-            let lang_dir_name = lang_dir.path().file_name().unwrap().to_str().unwrap();
-            if let Some(lang) = GuestLanguage::from_string(lang_dir_name) {
-                let adapters_path =
-                    Path::new(lang.tier().name()).join("wasi_snapshot_preview1.wasm");
-
-                for sub_entry in lang_dir.entries() {
-                    if let Some(template_dir) = sub_entry.as_dir() {
-                        let template_dir_name =
-                            template_dir.path().file_name().unwrap().to_str().unwrap();
-                        if template_dir_name != "INSTRUCTIONS"
-                            && !template_dir_name.starts_with('.')
-                        {
-                            let template = parse_template(
-                                lang,
-                                lang_dir.path(),
-                                Path::new("INSTRUCTIONS"),
-                                &adapters_path,
-                                template_dir.path(),
-                            );
-
-                            result.push(template);
-                        }
+/// Source of the template files, either the builtin catalog or a local template repository
+enum Catalog<'a> {
+    Builtin(&'a Dir<'a>),
+    Local(&'a Path),
+}
+
+enum CatalogEntry {
+    Dir(PathBuf),
+    File(PathBuf),
+}
+
+impl CatalogEntry {
+    fn path(&self) -> &Path {
+        match self {
+            CatalogEntry::Dir(path) => path,
+            CatalogEntry::File(path) => path,
+        }
+    }
+}
+
+impl<'a> Catalog<'a> {
+    fn of_template(template: &'a Template) -> Self {
+        match &template.source {
+            TemplateSource::Builtin => Catalog::Builtin(&TEMPLATES),
+            TemplateSource::Local { root, .. } => Catalog::Local(root),
+        }
+    }
+
+    // Entry paths are relative to the root of the catalog, same as the requested path
+    fn entries(&self, path: &Path) -> io::Result<Vec<CatalogEntry>> {
+        match self {
+            Catalog::Builtin(dir) => {
+                let dir = if path.as_os_str().is_empty() {
+                    Some(*dir)
+                } else {
+                    dir.get_dir(path)
+                };
+                Ok(dir
+                    .ok_or_else(|| {
+                        io::Error::other(format!("Could not find entry {}", path.display()))
+                    })?
+                    .entries()
+                    .iter()
+                    .map(|entry| match entry {
+                        DirEntry::Dir(dir) => CatalogEntry::Dir(dir.path().to_path_buf()),
+                        DirEntry::File(file) => CatalogEntry::File(file.path().to_path_buf()),
+                    })
+                    .collect())
+            }
+            Catalog::Local(root) => {
+                let mut entries = vec![];
+                for entry in fs::read_dir(root.join(path))? {
+                    let entry = entry?;
+                    let entry_path = path.join(entry.file_name());
+                    if entry.file_type()?.is_dir() {
+                        entries.push(CatalogEntry::Dir(entry_path));
+                    } else {
+                        entries.push(CatalogEntry::File(entry_path));
                     }
                 }
-            } else {
-                panic!("Invalid guest language name: {lang_dir_name}");
+                entries.sort_by(|a, b| a.path().cmp(b.path()));
+                Ok(entries)
             }
         }
     }
-    result
+
+    fn contents(&self, path: &Path) -> io::Result<Cow<'a, [u8]>> {
+        match self {
+            Catalog::Builtin(dir) => Ok(Cow::Borrowed(
+                dir.get_file(path)
+                    .ok_or_else(|| {
+                        io::Error::other(format!("Could not find entry {}", path.display()))
+                    })?
+                    .contents(),
+            )),
+            Catalog::Local(root) => Ok(Cow::Owned(fs::read(root.join(path))?)),
+        }
+    }
+}
+
+// Directories which are not named after a guest language are skipped and reported in the warnings
+fn catalog_templates(
+    catalog: &Catalog<'_>,
+    source: &TemplateSource,
+    warnings: &mut Vec<String>,
+) -> anyhow::Result<Vec<Template>> {
+    let mut result: Vec<Template> = vec![];
+    for entry in catalog.entries(Path::new(""))? {
+        let CatalogEntry::Dir(lang_dir) = entry else {
+            continue;
+        };
+        let lang_dir_name = entry_name(&lang_dir);
+        if lang_dir_name.starts_with('.') {
+            continue;
+        }
+        let Some(lang) = GuestLanguage::from_string(lang_dir_name) else {
+            warnings.push(format!(
+                "Skipped directory {lang_dir_name}, it is not a guest language name"
+            ));
+            continue;
+        };
+
+        let adapters_path = Path::new(lang.tier().name()).join("wasi_snapshot_preview1.wasm");
+
+        for sub_entry in catalog.entries(&lang_dir)? {
+            let CatalogEntry::Dir(template_dir) = sub_entry else {
+                continue;
+            };
+            let template_dir_name = entry_name(&template_dir);
+            if template_dir_name != "INSTRUCTIONS" && !template_dir_name.starts_with('.') {
+                let template = parse_template(
+                    catalog,
+                    source,
+                    lang,
+                    &lang_dir,
+                    Path::new("INSTRUCTIONS"),
+                    &adapters_path,
+                    &template_dir,
+                )
+                .with_context(|| format!("Failed to parse template {}", template_dir.display()))?;
+
+                result.push(template);
+            }
+        }
+    }
+    Ok(result)
+}
+
+fn entry_name(path: &Path) -> &str {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or_default()
+}
+
+fn all_templates() -> Vec<Template> {
+    let mut warnings = vec![];
+    let templates = catalog_templates(
+        &Catalog::Builtin(&TEMPLATES),
+        &TemplateSource::Builtin,
+        &mut warnings,
+    )
+    .unwrap_or_else(|err| panic!("{err:#}"));
+    if !warnings.is_empty() {
+        panic!("{}", warnings.join("\n"));
+    }
+    templates
+}
+
+/// Loads the templates of a local template repository, which uses the same layout
+/// as the builtin templates: `<language>/<template>/metadata.json`. Returns the templates
+/// and the warnings about the skipped directories.
+pub fn local_templates(
+    source_name: &str,
+    root: &Path,
+) -> anyhow::Result<(Vec<Template>, Vec<String>)> {
+    if !root.is_dir() {
+        bail!(
+            "Template repository directory not found: {}",
+            root.display()
+        );
+    }
+
+    let mut warnings = vec![];
+    let templates = catalog_templates(
+        &Catalog::Local(root),
+        &TemplateSource::Local {
+            name: source_name.to_string(),
+            root: root.to_path_buf(),
+        },
+        &mut warnings,
+    )?;
+    Ok((templates, warnings))
 }
 
 pub fn all_standalone_templates() -> Vec<Template> {
@@ -83,16 +221,45 @@ pub struct ComposableAppTemplate {
     pub components: BTreeMap<TemplateName, Template>,
 }
 
-pub fn all_composable_app_templates(
-) -> BTreeMap<GuestLanguage, BTreeMap<ComposableAppGroupName, ComposableAppTemplate>> {
-    let mut templates =
-        BTreeMap::<GuestLanguage, BTreeMap<ComposableAppGroupName, ComposableAppTemplate>>::new();
+pub type ComposableAppTemplates =
+    BTreeMap<GuestLanguage, BTreeMap<ComposableAppGroupName, ComposableAppTemplate>>;
+
+pub fn all_composable_app_templates() -> ComposableAppTemplates {
+    let mut templates = ComposableAppTemplates::new();
 
+    for template in all_templates() {
+        if let Err(err) = add_composable_app_template(&mut templates, template) {
+            panic!("{err:#}");
+        }
+    }
+
+    templates
+}
+
+/// Adds the composable app templates of a local template repository next to the already
+/// loaded ones. Unknown directories and templates conflicting with existing ones are skipped,
+/// and returned as warnings.
+pub fn add_local_composable_app_templates(
+    templates: &mut ComposableAppTemplates,
+    source_name: &str,
+    root: &Path,
+) -> anyhow::Result<Vec<String>> {
+    let (local_templates, mut warnings) = local_templates(source_name, root)?;
+    warnings.extend(
+        local_templates
+            .into_iter()
+            .filter_map(|template| add_composable_app_template(templates, template).err())
+            .map(|err| format!("{err:#}")),
+    );
+    Ok(warnings)
+}
+
+fn add_composable_app_template(
+    templates: &mut ComposableAppTemplates,
+    template: Template,
+) -> anyhow::Result<()> {
     fn app_templates<'a>(
-        templates: &'a mut BTreeMap<
-            GuestLanguage,
-            BTreeMap<ComposableAppGroupName, ComposableAppTemplate>,
-        >,
+        templates: &'a mut ComposableAppTemplates,
         language: GuestLanguage,
         group: &ComposableAppGroupName,
     ) -> &'a mut ComposableAppTemplate {
@@ -103,31 +270,42 @@ pub fn all_composable_app_templates(
         groups.get_mut(group).unwrap()
     }
 
-    for template in all_templates() {
-        match &template.kind {
-            TemplateKind::Standalone => continue,
-            TemplateKind::ComposableAppCommon { group, .. } => {
-                let common = &mut app_templates(&mut templates, template.language, group).common;
-                if let Some(common) = common {
-                    panic!(
-                        "Multiple common templates were found for {} - {}, template paths: {}, {}",
-                        template.language,
-                        group,
-                        common.template_path.display(),
-                        template.template_path.display()
-                    );
-                }
-                *common = Some(template);
+    match &template.kind {
+        TemplateKind::Standalone => {}
+        TemplateKind::ComposableAppCommon { group, .. } => {
+            let common = &mut app_templates(templates, template.language, group).common;
+            if let Some(common) = common {
+                bail!(
+                    "Multiple common templates were found for {} - {}, template paths: {} ({}), {} ({})",
+                    template.language,
+                    group,
+                    common.template_path.display(),
+                    common.source,
+                    template.template_path.display(),
+                    template.source,
+                );
             }
-            TemplateKind::ComposableAppComponent { group } => {
-                app_templates(&mut templates, template.language, group)
-                    .components
-                    .insert(template.name.clone(), template);
+            *common = Some(template);
+        }
+        TemplateKind::ComposableAppComponent { group } => {
+            let components = &mut app_templates(templates, template.language, group).components;
+            if let Some(existing) = components.get(&template.name) {
+                bail!(
+                    "Multiple component templates were found for {} - {} - {}, template paths: {} ({}), {} ({})",
+                    template.language,
+                    group,
+                    template.name,
+                    existing.template_path.display(),
+                    existing.source,
+                    template.template_path.display(),
+                    template.source,
+                );
             }
+            components.insert(template.name.clone(), template);
         }
     }
 
-    templates
+    Ok(())
 }
 
 pub fn instantiate_template(
@@ -136,7 +314,7 @@ pub fn instantiate_template(
     resolve_mode: TargetExistsResolveMode,
 ) -> io::Result<String> {
     instantiate_directory(
-        &TEMPLATES,
+        &Catalog::of_template(template),
         &template.template_path,
         &parameters.target_path,
         template,
@@ -156,7 +334,7 @@ pub fn instantiate_template(
 
         fs::create_dir_all(&adapter_dir)?;
         copy(
-            &ADAPTERS,
+            &Catalog::Builtin(&ADAPTERS),
             adapter_path,
             &adapter_dir.join(adapter_path.file_name().unwrap().to_str().unwrap()),
             TargetExistsResolveMode::MergeOrSkip,
@@ -174,7 +352,12 @@ pub fn instantiate_template(
     for wit_dep in &template.wit_deps {
         for target_wit_deps in &wit_deps_targets {
             let target = target_wit_deps.join(wit_dep.file_name().unwrap().to_str().unwrap());
-            copy_all(&WIT, wit_dep, &target, TargetExistsResolveMode::MergeOrSkip)?;
+            copy_all(
+                &Catalog::Builtin(&WIT),
+                wit_dep,
+                &target,
+                TargetExistsResolveMode::MergeOrSkip,
+            )?;
         }
     }
     Ok(render_template_instructions(template, parameters))
@@ -241,7 +424,7 @@ pub fn render_template_instructions(
 }
 
 fn instantiate_directory(
-    catalog: &Dir<'_>,
+    catalog: &Catalog<'_>,
     source: &Path,
     target: &Path,
     template: &Template,
@@ -249,29 +432,25 @@ fn instantiate_directory(
     resolve_mode: TargetExistsResolveMode,
 ) -> io::Result<()> {
     fs::create_dir_all(target)?;
-    for entry in catalog
-        .get_dir(source)
-        .unwrap_or_else(|| panic!("Could not find entry {source:?}"))
-        .entries()
-    {
-        let name = entry.path().file_name().unwrap().to_str().unwrap();
+    for entry in catalog.entries(source)? {
+        let name = entry_name(entry.path());
         if !template.exclude.contains(name) && (name != "metadata.json") {
             let name = file_name_transform(name, parameters);
-            match entry {
-                DirEntry::Dir(dir) => {
+            match &entry {
+                CatalogEntry::Dir(dir) => {
                     instantiate_directory(
                         catalog,
-                        dir.path(),
+                        dir,
                         &target.join(&name),
                         template,
                         parameters,
                         resolve_mode,
                     )?;
                 }
-                DirEntry::File(file) => {
+                CatalogEntry::File(file) => {
                     instantiate_file(
                         catalog,
-                        file.path(),
+                        file,
                         &target.join(&name),
                         parameters,
                         template.transform && !template.transform_exclude.contains(&name),
@@ -285,7 +464,7 @@ fn instantiate_directory(
 }
 
 fn instantiate_file(
-    catalog: &Dir<'_>,
+    catalog: &Catalog<'_>,
     source: &Path,
     target: &Path,
     parameters: &TemplateParameters,
@@ -317,7 +496,7 @@ fn instantiate_file(
 }
 
 fn copy(
-    catalog: &Dir<'_>,
+    catalog: &Catalog<'_>,
     source: &Path,
     target: &Path,
     resolve_mode: TargetExistsResolveMode,
@@ -329,12 +508,12 @@ fn copy(
 }
 
 fn copy_all(
-    catalog: &Dir<'_>,
+    catalog: &Catalog<'_>,
     source_path: &Path,
     target_path: &Path,
     resolve_mode: TargetExistsResolveMode,
 ) -> io::Result<()> {
-    let source_dir = catalog.get_dir(source_path).ok_or_else(|| {
+    let source_entries = catalog.entries(source_path).map_err(|_| {
         io::Error::other(format!(
             "Could not find dir {} in catalog",
             source_path.display()
@@ -343,13 +522,15 @@ fn copy_all(
 
     fs::create_dir_all(target_path)?;
 
-    for file in source_dir.files() {
-        copy(
-            catalog,
-            file.path(),
-            &target_path.join(file.path().file_name().unwrap().to_str().unwrap()),
-            resolve_mode,
-        )?;
+    for entry in source_entries {
+        if let CatalogEntry::File(file) = entry {
+            copy(
+                catalog,
+                &file,
+                &target_path.join(entry_name(&file)),
+                resolve_mode,
+            )?;
+        }
     }
 
     Ok(())
@@ -461,41 +642,35 @@ fn check_target(
     }
 }
 
-fn get_contents<'a>(catalog: &Dir<'a>, source: &'a Path) -> io::Result<&'a [u8]> {
-    Ok(catalog
-        .get_file(source)
-        .ok_or_else(|| io::Error::other(format!("Could not find entry {}", source.display())))?
-        .contents())
-}
-
 fn get_resolved_contents<'a>(
-    catalog: &Dir<'a>,
-    source: &'a Path,
-    target: &'a Path,
+    catalog: &Catalog<'a>,
+    source: &Path,
+    target: &Path,
     resolve_mode: TargetExistsResolveMode,
 ) -> io::Result<Option<Cow<'a, [u8]>>> {
     match check_target(target, resolve_mode)? {
-        None => Ok(Some(Cow::Borrowed(get_contents(catalog, source)?))),
+        None => Ok(Some(catalog.contents(source)?)),
         Some(TargetExistsResolveDecision::Skip) => Ok(None),
         Some(TargetExistsResolveDecision::Merge(merge)) => {
-            Ok(Some(Cow::Owned(merge(get_contents(catalog, source)?)?)))
+            Ok(Some(Cow::Owned(merge(&catalog.contents(source)?)?)))
         }
     }
 }
 
 fn parse_template(
+    catalog: &Catalog<'_>,
+    source: &TemplateSource,
     lang: GuestLanguage,
     lang_path: &Path,
     default_instructions_file_name: &Path,
     adapters_path: &Path,
     template_root: &Path,
-) -> Template {
-    let raw_metadata = TEMPLATES
-        .get_file(template_root.join("metadata.json"))
-        .expect("Failed to read metadata JSON")
-        .contents();
-    let metadata = serde_json::from_slice::<TemplateMetadata>(raw_metadata)
-        .expect("Failed to parse metadata JSON");
+) -> anyhow::Result<Template> {
+    let raw_metadata = catalog
+        .contents(&template_root.join("metadata.json"))
+        .context("Failed to read metadata JSON")?;
+    let metadata = serde_json::from_slice::<TemplateMetadata>(&raw_metadata)
+        .context("Failed to parse metadata JSON")?;
 
     let kind = match (metadata.app_common_group, metadata.app_component_group) {
         (None, None) => TemplateKind::Standalone,
@@ -506,7 +681,7 @@ fn parse_template(
         (None, Some(group)) => TemplateKind::ComposableAppComponent {
             group: group.into(),
         },
-        (Some(_), Some(_)) => panic!(
+        (Some(_), Some(_)) => bail!(
             "Only one of appCommonGroup and appComponentGroup can be specified, template root: {}",
             template_root.display()
         ),
@@ -519,24 +694,18 @@ fn parse_template(
                 None => lang_path.join(default_instructions_file_name),
             };
 
-            let raw_instructions = TEMPLATES
-                .get_file(instructions_path)
-                .expect("Failed to read instructions")
-                .contents();
+            let raw_instructions = catalog
+                .contents(&instructions_path)
+                .context("Failed to read instructions")?;
 
-            String::from_utf8(raw_instructions.to_vec()).expect("Failed to decode instructions")
+            String::from_utf8(raw_instructions.to_vec()).context("Failed to decode instructions")?
         }
         TemplateKind::ComposableAppCommon { .. } => "".to_string(),
         TemplateKind::ComposableAppComponent { .. } => "".to_string(),
     };
 
     let name: TemplateName = {
-        let name = template_root
-            .file_name()
-            .unwrap()
-            .to_str()
-            .unwrap()
-            .to_string();
+        let name = entry_name(template_root).to_string();
 
         // TODO: this is just a quickfix for hiding "<lang>-app-<component>" prefixes, let's decide later if we want
         //       reorganize the template directories directly
@@ -581,9 +750,10 @@ fn parse_template(
         .requires_adapter
         .unwrap_or(metadata.adapter_target.is_some());
 
-    Template {
+    Ok(Template {
         name,
         kind,
+        source: source.clone(),
         language: lang,
         description: metadata.description,
         template_path: template_root.to_path_buf(),
@@ -611,5 +781,90 @@ fn parse_template(
             .map(|te| te.iter().cloned().collect())
             .unwrap_or_default(),
         transform: metadata.transform.unwrap_or(true),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{ComposableAppGroupName, GuestLanguage, TemplateName, TemplateSource};
+    use crate::{add_local_composable_app_templates, local_templates, ComposableAppTemplates};
+    use assert2::{assert, check, let_assert};
+    use std::fs;
+    use std::path::Path;
+    use test_r::test;
+
+    fn write_metadata(root: &Path, template_dir: &str, metadata: &str) {
+        let template_dir = root.join(template_dir);
+        fs::create_dir_all(&template_dir).unwrap();
+        fs::write(template_dir.join("metadata.json"), metadata).unwrap();
+    }
+
+    fn template_repository() -> tempfile::TempDir {
+        let root = tempfile::tempdir().unwrap();
+        write_metadata(
+            root.path(),
+            "rust/rust-app-common",
+            r#"{"description": "Common", "appCommonGroup": "custom"}"#,
+        );
+        write_metadata(
+            root.path(),
+            "rust/rust-app-component-counter",
+            r#"{"description": "Counter", "appComponentGroup": "custom"}"#,
+        );
+        fs::create_dir_all(root.path().join("docs")).unwrap();
+        fs::create_dir_all(root.path().join(".git")).unwrap();
+        fs::write(root.path().join("README.md"), "Templates").unwrap();
+        root
+    }
+
+    #[test]
+    fn local_templates_skip_unknown_directories() {
+        let root = template_repository();
+
+        let (templates, warnings) = local_templates("my-templates", root.path()).unwrap();
+
+        check!(
+            templates
+                .iter()
+                .map(|template| template.name.as_str())
+                .collect::<Vec<_>>()
+                == vec!["common", "counter"]
+        );
+        for template in &templates {
+            check!(template.language == GuestLanguage::Rust);
+            let_assert!(TemplateSource::Local { name, .. } = &template.source);
+            check!(name == "my-templates");
+        }
+        check!(warnings == vec!["Skipped directory docs, it is not a guest language name"]);
+    }
+
+    #[test]
+    fn local_templates_of_missing_directory() {
+        let root = tempfile::tempdir().unwrap();
+        check!(local_templates("my-templates", &root.path().join("missing")).is_err());
+    }
+
+    #[test]
+    fn add_local_composable_app_templates_skips_conflicts() {
+        let root = template_repository();
+        let mut templates = ComposableAppTemplates::new();
+
+        let warnings =
+            add_local_composable_app_templates(&mut templates, "my-templates", root.path())
+                .unwrap();
+        check!(warnings.len() == 1);
+
+        let group = &templates[&GuestLanguage::Rust][&ComposableAppGroupName::from("custom")];
+        assert!(let Some(common) = &group.common);
+        check!(common.description == "Common");
+        check!(group.components.keys().collect::<Vec<_>>() == vec![&TemplateName::from("counter")]);
+
+        // Adding the same templates again conflicts with the already added ones
+        let warnings =
+            add_local_composable_app_templates(&mut templates, "other-templates", root.path())
+                .unwrap();
+        check!(warnings.len() == 3);
+        check!(warnings[1].starts_with("Multiple common templates were found"));
+        check!(warnings[2].starts_with("Multiple component templates were found"));
     }
 }
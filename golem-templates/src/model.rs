@@ -409,10 +409,35 @@ pub enum TargetExistsResolveDecision {
     Merge(MergeContents),
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TemplateSource {
+    /// Templates embedded into the binary
+    Builtin,
+    /// Local template repository directory, e.g. a git checkout, using the same layout
+    /// as the builtin templates
+    Local { name: String, root: PathBuf },
+}
+
+impl TemplateSource {
+    pub fn label(&self) -> &str {
+        match self {
+            TemplateSource::Builtin => "builtin",
+            TemplateSource::Local { name, .. } => name,
+        }
+    }
+}
+
+impl fmt::Display for TemplateSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.label())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Template {
     pub name: TemplateName,
     pub kind: TemplateKind,
+    pub source: TemplateSource,
     pub language: GuestLanguage,
     pub description: String,
     pub template_path: PathBuf,